@@ -487,6 +487,14 @@ struct TicklyWidgetEntryView: View {
                 .frame(maxWidth: .infinity, alignment: .leading)
                 .foregroundColor(primaryTextColor)
 
+            if let progressLabel = item.progressLabel {
+                Text(progressLabel)
+                    .font(.caption2)
+                    .monospacedDigit()
+                    .foregroundColor(secondaryTextColor)
+                    .fixedSize(horizontal: true, vertical: false)
+            }
+
             if showTag && !itemTagLabel.isEmpty {
                 Text(itemTagLabel)
                     .font(.caption2)
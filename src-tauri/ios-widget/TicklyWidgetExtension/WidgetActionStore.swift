@@ -44,6 +44,20 @@ enum WidgetActionStore {
         }
 
         var category = snapshot.categories[categoryIndex]
+
+        // A quantity item short of its target only advances its count
+        if let pendingIndex = category.pendingItems.firstIndex(where: { $0.id == itemId }) {
+            let item = category.pendingItems[pendingIndex]
+            if item.quantityProgress + 1 < item.quantityTarget {
+                category.pendingItems[pendingIndex].quantityProgress += 1
+                if let itemIndex = snapshot.items.firstIndex(where: { $0.id == itemId }) {
+                    snapshot.items[itemIndex].quantityProgress = item.quantityProgress + 1
+                }
+                snapshot.categories[categoryIndex] = category
+                return true
+            }
+        }
+
         var changed = false
         let hadPendingItem = category.pendingItemIds.contains(itemId)
 
@@ -95,7 +109,10 @@ enum WidgetActionStore {
             return
         }
 
-        let queuedAt = ISO8601DateFormatter().string(from: Date())
+        // Fractional seconds keep quick taps apart; the app tells them apart by time
+        let formatter = ISO8601DateFormatter()
+        formatter.formatOptions = [.withInternetDateTime, .withFractionalSeconds]
+        let queuedAt = formatter.string(from: Date())
         let action = WidgetQueuedAction(itemId: itemId, queuedAt: queuedAt)
         var queue = readQueue(from: queueURL)
         queue.append(action)
@@ -8,6 +8,8 @@ struct WidgetTodoItem: Codable, Identifiable {
     let categoryName: String?
     let displayOrder: Int64
     let reminderAt: String?
+    var quantityTarget: Int?
+    var quantityProgress: Int?
     let updatedAt: String?
 
     enum CodingKeys: String, CodingKey {
@@ -18,6 +20,8 @@ struct WidgetTodoItem: Codable, Identifiable {
         case categoryName = "category_name"
         case displayOrder = "display_order"
         case reminderAt = "reminder_at"
+        case quantityTarget = "quantity_target"
+        case quantityProgress = "quantity_progress"
         case updatedAt = "updated_at"
     }
 }
@@ -94,19 +98,37 @@ struct WidgetCategoryPendingItem: Codable, Identifiable {
     let text: String
     let displayOrder: Int64
     let tags: [String]
+    let quantityTarget: Int
+    var quantityProgress: Int
+
+    /// "3/8" for items with a quantity target
+    var progressLabel: String? {
+        quantityTarget > 0 ? "\(quantityProgress)/\(quantityTarget)" : nil
+    }
 
     enum CodingKeys: String, CodingKey {
         case id
         case text
         case displayOrder = "display_order"
         case tags
+        case quantityTarget = "quantity_target"
+        case quantityProgress = "quantity_progress"
     }
 
-    init(id: Int64, text: String, displayOrder: Int64, tags: [String] = []) {
+    init(
+        id: Int64,
+        text: String,
+        displayOrder: Int64,
+        tags: [String] = [],
+        quantityTarget: Int = 0,
+        quantityProgress: Int = 0
+    ) {
         self.id = id
         self.text = text
         self.displayOrder = displayOrder
         self.tags = tags
+        self.quantityTarget = quantityTarget
+        self.quantityProgress = quantityProgress
     }
 
     init(from decoder: Decoder) throws {
@@ -115,6 +137,8 @@ struct WidgetCategoryPendingItem: Codable, Identifiable {
         text = try container.decode(String.self, forKey: .text)
         displayOrder = try container.decode(Int64.self, forKey: .displayOrder)
         tags = try container.decodeIfPresent([String].self, forKey: .tags) ?? []
+        quantityTarget = try container.decodeIfPresent(Int.self, forKey: .quantityTarget) ?? 0
+        quantityProgress = try container.decodeIfPresent(Int.self, forKey: .quantityProgress) ?? 0
     }
 
     func encode(to encoder: Encoder) throws {
@@ -123,6 +147,8 @@ struct WidgetCategoryPendingItem: Codable, Identifiable {
         try container.encode(text, forKey: .text)
         try container.encode(displayOrder, forKey: .displayOrder)
         try container.encode(tags, forKey: .tags)
+        try container.encode(quantityTarget, forKey: .quantityTarget)
+        try container.encode(quantityProgress, forKey: .quantityProgress)
     }
 }
 
@@ -1,8 +1,8 @@
 use tauri::State;
 
 use super::with_db;
-use crate::models::Category;
-use crate::service::CategoryService;
+use crate::models::{Category, ResetPolicy};
+use crate::service::{CategoryService, JournalScope, JournalService};
 use crate::AppState;
 
 #[tauri::command]
@@ -17,15 +17,47 @@ pub fn add_category(name: String, state: State<AppState>) -> Result<Category, St
 
 #[tauri::command]
 pub fn edit_category(id: i64, name: String, state: State<AppState>) -> Result<(), String> {
-    with_db(&state, |db| CategoryService::update(db, id, &name))
+    with_db(&state, |db| {
+        JournalService::record(db, "edit", JournalScope::Categories(vec![id]), |db| {
+            CategoryService::update(db, id, &name)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn update_category_reset_policy(
+    id: i64,
+    reset_policy: String,
+    reset_times: Option<Vec<String>>,
+    reset_weekdays: Option<Vec<u32>>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    with_db(&state, |db| {
+        CategoryService::update_reset_policy(
+            db,
+            id,
+            ResetPolicy::from_str(&reset_policy),
+            &reset_times.unwrap_or_default(),
+            &reset_weekdays.unwrap_or_default(),
+        )
+    })
 }
 
 #[tauri::command]
 pub fn delete_category(id: i64, state: State<AppState>) -> Result<(), String> {
-    with_db(&state, |db| CategoryService::delete(db, id))
+    with_db(&state, |db| {
+        JournalService::record(db, "delete", JournalScope::Category(id), |db| {
+            CategoryService::delete(db, id)
+        })
+    })
 }
 
 #[tauri::command]
 pub fn reorder_categories(category_ids: Vec<i64>, state: State<AppState>) -> Result<(), String> {
-    with_db(&state, |db| CategoryService::reorder(db, &category_ids))
+    with_db(&state, |db| {
+        let scope = JournalScope::Categories(category_ids.clone());
+        JournalService::record(db, "reorder", scope, |db| {
+            CategoryService::reorder(db, &category_ids)
+        })
+    })
 }
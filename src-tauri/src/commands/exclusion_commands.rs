@@ -0,0 +1,78 @@
+use tauri::State;
+
+use super::with_db;
+use crate::models::{ExclusionCalendar, ExclusionRange};
+use crate::service::{ExclusionService, TodoService};
+use crate::AppState;
+
+#[tauri::command]
+pub fn get_exclusion_calendars(state: State<AppState>) -> Result<Vec<ExclusionCalendar>, String> {
+    with_db(&state, ExclusionService::get_calendars)
+}
+
+#[tauri::command]
+pub fn add_exclusion_calendar(
+    name: String,
+    state: State<AppState>,
+) -> Result<ExclusionCalendar, String> {
+    with_db(&state, |db| ExclusionService::create_calendar(db, &name))
+}
+
+#[tauri::command]
+pub fn delete_exclusion_calendar(id: i64, state: State<AppState>) -> Result<(), String> {
+    with_db(&state, |db| ExclusionService::delete_calendar(db, id))
+}
+
+#[tauri::command]
+pub fn get_exclusion_ranges(
+    calendar_id: i64,
+    state: State<AppState>,
+) -> Result<Vec<ExclusionRange>, String> {
+    with_db(&state, |db| ExclusionService::get_ranges(db, calendar_id))
+}
+
+#[tauri::command]
+pub fn add_exclusion_range(
+    calendar_id: i64,
+    start_date: String,
+    end_date: Option<String>,
+    label: Option<String>,
+    state: State<AppState>,
+) -> Result<ExclusionRange, String> {
+    with_db(&state, |db| {
+        ExclusionService::add_range(
+            db,
+            calendar_id,
+            &start_date,
+            end_date.as_deref(),
+            label.as_deref(),
+        )
+    })
+}
+
+#[tauri::command]
+pub fn delete_exclusion_range(id: i64, state: State<AppState>) -> Result<(), String> {
+    with_db(&state, |db| ExclusionService::delete_range(db, id))
+}
+
+#[tauri::command]
+pub fn import_exclusion_ics(
+    calendar_id: i64,
+    path: String,
+    state: State<AppState>,
+) -> Result<usize, String> {
+    with_db(&state, |db| {
+        ExclusionService::import_ics(db, calendar_id, &path)
+    })
+}
+
+#[tauri::command]
+pub fn update_item_exclusion_calendar(
+    id: i64,
+    calendar_id: Option<i64>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    with_db(&state, |db| {
+        TodoService::update_exclusion_calendar(db, id, calendar_id)
+    })
+}
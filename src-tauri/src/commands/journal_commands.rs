@@ -0,0 +1,33 @@
+use tauri::{AppHandle, State};
+
+use super::with_db;
+use crate::models::UndoState;
+use crate::service::{JournalService, WidgetService};
+use crate::AppState;
+
+#[tauri::command]
+pub fn undo(app: AppHandle, state: State<AppState>) -> Result<UndoState, String> {
+    with_db(&state, |db| {
+        let undo_state = JournalService::undo(db).map_err(|e| e.to_string())?;
+        if let Err(error) = WidgetService::refresh_cache(db, &app, None) {
+            log::error!("Failed to refresh widget cache after undo: {}", error);
+        }
+        Ok::<UndoState, String>(undo_state)
+    })
+}
+
+#[tauri::command]
+pub fn redo(app: AppHandle, state: State<AppState>) -> Result<UndoState, String> {
+    with_db(&state, |db| {
+        let undo_state = JournalService::redo(db).map_err(|e| e.to_string())?;
+        if let Err(error) = WidgetService::refresh_cache(db, &app, None) {
+            log::error!("Failed to refresh widget cache after redo: {}", error);
+        }
+        Ok::<UndoState, String>(undo_state)
+    })
+}
+
+#[tauri::command]
+pub fn get_undo_state(state: State<AppState>) -> Result<UndoState, String> {
+    with_db(&state, JournalService::get_state)
+}
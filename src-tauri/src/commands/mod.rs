@@ -5,13 +5,18 @@ use crate::AppState;
 
 mod auth_commands;
 mod category_commands;
+mod exclusion_commands;
 mod graph_commands;
+mod journal_commands;
 mod realtime_commands;
 mod settings_commands;
+mod stats_commands;
 mod streak_commands;
 mod sync_commands;
 mod tag_commands;
+mod template_commands;
 mod todo_commands;
+mod trash_commands;
 mod widget_commands;
 
 pub(super) fn with_db<T, E, F>(state: &State<'_, AppState>, action: F) -> Result<T, String>
@@ -25,11 +30,16 @@ where
 
 pub use auth_commands::*;
 pub use category_commands::*;
+pub use exclusion_commands::*;
 pub use graph_commands::*;
+pub use journal_commands::*;
 pub use realtime_commands::*;
 pub use settings_commands::*;
+pub use stats_commands::*;
 pub use streak_commands::*;
 pub use sync_commands::*;
 pub use tag_commands::*;
+pub use template_commands::*;
 pub use todo_commands::*;
+pub use trash_commands::*;
 pub use widget_commands::*;
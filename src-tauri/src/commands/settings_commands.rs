@@ -1,7 +1,9 @@
 use tauri::State;
 
 use super::with_db;
+use crate::models::TimeZoneStatus;
 use crate::repository::SettingsRepository;
+use crate::service::TimeZoneService;
 use crate::AppState;
 
 #[tauri::command]
@@ -11,5 +13,19 @@ pub fn get_setting(key: String, state: State<AppState>) -> Result<Option<String>
 
 #[tauri::command]
 pub fn set_setting(key: String, value: String, state: State<AppState>) -> Result<(), String> {
+    // The time zone is validated and synced, so it has its own path
+    if key == TimeZoneService::SETTING_KEY {
+        return with_db(&state, |db| TimeZoneService::set_time_zone(db, &value));
+    }
     with_db(&state, |db| SettingsRepository::set(db, &key, &value))
 }
+
+#[tauri::command]
+pub fn get_time_zone_status(state: State<AppState>) -> Result<TimeZoneStatus, String> {
+    with_db(&state, TimeZoneService::check_device_time_zone)
+}
+
+#[tauri::command]
+pub fn set_time_zone(time_zone: String, state: State<AppState>) -> Result<(), String> {
+    with_db(&state, |db| TimeZoneService::set_time_zone(db, &time_zone))
+}
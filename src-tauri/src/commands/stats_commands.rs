@@ -0,0 +1,40 @@
+use tauri::State;
+
+use super::with_db;
+use crate::models::{CategoryTrend, CompletionHourStats, MissedItem, WeekdayStats};
+use crate::service::StatsService;
+use crate::AppState;
+
+#[tauri::command]
+pub fn get_weekday_stats(
+    days: Option<i32>,
+    state: State<AppState>,
+) -> Result<Vec<WeekdayStats>, String> {
+    with_db(&state, |db| StatsService::get_weekday_stats(db, days))
+}
+
+#[tauri::command]
+pub fn get_completion_hour_stats(
+    days: Option<i32>,
+    state: State<AppState>,
+) -> Result<CompletionHourStats, String> {
+    with_db(&state, |db| {
+        StatsService::get_completion_hour_stats(db, days)
+    })
+}
+
+#[tauri::command]
+pub fn get_category_trends(state: State<AppState>) -> Result<Vec<CategoryTrend>, String> {
+    with_db(&state, StatsService::get_category_trends)
+}
+
+#[tauri::command]
+pub fn get_most_missed_items(
+    days: Option<i32>,
+    limit: Option<i64>,
+    state: State<AppState>,
+) -> Result<Vec<MissedItem>, String> {
+    with_db(&state, |db| {
+        StatsService::get_most_missed_items(db, days, limit)
+    })
+}
@@ -1,8 +1,10 @@
 use tauri::State;
 
 use super::with_db;
-use crate::models::{HeatmapData, TrackedItem};
-use crate::service::StreakService;
+use crate::models::{
+    AggregateHeatmapData, HeatmapData, MeasurementSeries, Occurrence, TrackedItem,
+};
+use crate::service::{MeasurementService, RepeatService, StreakService};
 use crate::AppState;
 
 #[tauri::command]
@@ -18,6 +20,38 @@ pub fn get_item_heatmap_data(
     with_db(&state, |db| StreakService::get_item_heatmap_data(db, item_id))
 }
 
+#[tauri::command]
+pub fn get_category_heatmap_data(
+    category_id: i64,
+    state: State<AppState>,
+) -> Result<AggregateHeatmapData, String> {
+    with_db(&state, |db| StreakService::get_category_heatmap_data(db, category_id))
+}
+
+#[tauri::command]
+pub fn get_tag_heatmap_data(
+    tag_id: i64,
+    state: State<AppState>,
+) -> Result<AggregateHeatmapData, String> {
+    with_db(&state, |db| StreakService::get_tag_heatmap_data(db, tag_id))
+}
+
+#[tauri::command]
+pub fn get_all_heatmap_data(state: State<AppState>) -> Result<AggregateHeatmapData, String> {
+    with_db(&state, StreakService::get_all_heatmap_data)
+}
+
+#[tauri::command]
+pub fn get_item_occurrences(
+    item_id: i64,
+    days: Option<i32>,
+    state: State<AppState>,
+) -> Result<Vec<Occurrence>, String> {
+    with_db(&state, |db| {
+        RepeatService::get_occurrences(db, item_id, days.unwrap_or(365))
+    })
+}
+
 #[tauri::command]
 pub fn update_track_streak(
     id: i64,
@@ -26,3 +60,60 @@ pub fn update_track_streak(
 ) -> Result<(), String> {
     with_db(&state, |db| StreakService::update_track_streak(db, id, track_streak))
 }
+
+#[tauri::command]
+pub fn update_streak_grace(
+    id: i64,
+    freezes_per_month: i32,
+    grace_per_week: i32,
+    state: State<AppState>,
+) -> Result<(), String> {
+    with_db(&state, |db| {
+        StreakService::update_streak_grace(db, id, freezes_per_month, grace_per_week)
+    })
+}
+
+#[tauri::command]
+pub fn update_goal(
+    id: i64,
+    goal_period: String,
+    goal_target: i32,
+    state: State<AppState>,
+) -> Result<(), String> {
+    with_db(&state, |db| {
+        StreakService::update_goal(db, id, &goal_period, goal_target)
+    })
+}
+
+#[tauri::command]
+pub fn get_measurement_series(
+    item_id: i64,
+    days: Option<i32>,
+    state: State<AppState>,
+) -> Result<MeasurementSeries, String> {
+    with_db(&state, |db| {
+        MeasurementService::get_series(db, item_id, days)
+    })
+}
+
+#[tauri::command]
+pub fn backfill_completion(
+    item_id: i64,
+    date: String,
+    state: State<AppState>,
+) -> Result<Option<HeatmapData>, String> {
+    with_db(&state, |db| {
+        StreakService::backfill_completion(db, item_id, &date)
+    })
+}
+
+#[tauri::command]
+pub fn remove_past_completion(
+    item_id: i64,
+    date: String,
+    state: State<AppState>,
+) -> Result<Option<HeatmapData>, String> {
+    with_db(&state, |db| {
+        StreakService::remove_past_completion(db, item_id, &date)
+    })
+}
@@ -68,10 +68,27 @@ pub fn force_pull(state: State<'_, AppState>) -> Result<(), String> {
         // Order matters due to foreign key constraints
         conn.execute("DELETE FROM completion_logs", [])
             .map_err(|e| e.to_string())?;
+        conn.execute("DELETE FROM measurements", [])
+            .map_err(|e| e.to_string())?;
+        conn.execute("DELETE FROM skip_logs", [])
+            .map_err(|e| e.to_string())?;
+        conn.execute("DELETE FROM streak_freezes", [])
+            .map_err(|e| e.to_string())?;
+        conn.execute("DELETE FROM schedule_versions", [])
+            .map_err(|e| e.to_string())?;
+        conn.execute("DELETE FROM occurrences", [])
+            .map_err(|e| e.to_string())?;
         conn.execute("DELETE FROM todos", [])
             .map_err(|e| e.to_string())?;
         conn.execute("DELETE FROM categories", [])
             .map_err(|e| e.to_string())?;
+        conn.execute("DELETE FROM checklist_template_items", [])
+            .map_err(|e| e.to_string())?;
+        conn.execute("DELETE FROM checklist_templates", [])
+            .map_err(|e| e.to_string())?;
+        // Journal images point at the local ids being replaced
+        conn.execute("DELETE FROM operation_journal", [])
+            .map_err(|e| e.to_string())?;
 
         Ok::<(), String>(())
     })
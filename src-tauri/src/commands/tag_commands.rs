@@ -2,7 +2,7 @@ use tauri::State;
 
 use super::with_db;
 use crate::models::{Tag, TodoItem};
-use crate::service::TagService;
+use crate::service::{JournalScope, JournalService, TagService};
 use crate::AppState;
 
 #[tauri::command]
@@ -17,7 +17,11 @@ pub fn create_tag(name: String, state: State<AppState>) -> Result<Tag, String> {
 
 #[tauri::command]
 pub fn delete_tag(id: i64, state: State<AppState>) -> Result<(), String> {
-    with_db(&state, |db| TagService::delete_tag(db, id))
+    with_db(&state, |db| {
+        JournalService::record(db, "delete", JournalScope::Tag(id), |db| {
+            TagService::delete_tag(db, id)
+        })
+    })
 }
 
 #[tauri::command]
@@ -26,7 +30,11 @@ pub fn add_tag_to_item(
     tag_name: String,
     state: State<AppState>,
 ) -> Result<Tag, String> {
-    with_db(&state, |db| TagService::add_tag_to_item(db, item_id, &tag_name))
+    with_db(&state, |db| {
+        JournalService::record(db, "tag", JournalScope::Items(vec![item_id]), |db| {
+            TagService::add_tag_to_item(db, item_id, &tag_name)
+        })
+    })
 }
 
 #[tauri::command]
@@ -35,7 +43,11 @@ pub fn remove_tag_from_item(
     tag_id: i64,
     state: State<AppState>,
 ) -> Result<(), String> {
-    with_db(&state, |db| TagService::remove_tag_from_item(db, item_id, tag_id))
+    with_db(&state, |db| {
+        JournalService::record(db, "tag", JournalScope::Items(vec![item_id]), |db| {
+            TagService::remove_tag_from_item(db, item_id, tag_id)
+        })
+    })
 }
 
 #[tauri::command]
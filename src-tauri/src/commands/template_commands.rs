@@ -0,0 +1,41 @@
+use tauri::State;
+
+use super::with_db;
+use crate::models::ChecklistTemplate;
+use crate::service::{JournalScope, JournalService, TemplateService};
+use crate::AppState;
+
+#[tauri::command]
+pub fn get_templates(state: State<AppState>) -> Result<Vec<ChecklistTemplate>, String> {
+    with_db(&state, TemplateService::get_templates)
+}
+
+#[tauri::command]
+pub fn create_template_from_category(
+    category_id: i64,
+    name: String,
+    state: State<AppState>,
+) -> Result<ChecklistTemplate, String> {
+    with_db(&state, |db| {
+        TemplateService::create_from_category(db, category_id, &name)
+    })
+}
+
+#[tauri::command]
+pub fn instantiate_template(
+    template_id: i64,
+    category_id: Option<i64>,
+    new_category_name: Option<String>,
+    state: State<AppState>,
+) -> Result<i64, String> {
+    with_db(&state, |db| {
+        JournalService::record(db, "template", JournalScope::Created(Vec::new()), |db| {
+            TemplateService::instantiate(db, template_id, category_id, new_category_name.as_deref())
+        })
+    })
+}
+
+#[tauri::command]
+pub fn delete_template(id: i64, state: State<AppState>) -> Result<(), String> {
+    with_db(&state, |db| TemplateService::delete_template(db, id))
+}
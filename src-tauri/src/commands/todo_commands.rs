@@ -1,33 +1,44 @@
 use tauri::{AppHandle, State};
 
 use super::with_db;
-use crate::models::{RepeatType, TodoItem};
-use crate::service::{RepeatService, ResetService, TodoService, WidgetService};
+use crate::models::{RepeatSchedule, RepeatType, ScheduleVersion, TodoItem};
+use crate::service::{
+    JournalScope, JournalService, MeasurementService, RepeatService, ResetService, TodoService,
+    WidgetService,
+};
 use crate::AppState;
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub fn add_item(
     text: String,
     category_id: Option<i64>,
     repeat_type: Option<String>,
     repeat_detail: Option<String>,
+    repeat_interval: Option<i32>,
+    repeat_until: Option<String>,
+    repeat_count: Option<i32>,
     track_streak: Option<bool>,
     reminder_at: Option<String>,
     state: State<AppState>,
 ) -> Result<TodoItem, String> {
-    let repeat = repeat_type
+    let repeat_type = repeat_type
         .map(|s| RepeatType::from_str(&s))
         .unwrap_or(RepeatType::None);
+    let repeat = RepeatSchedule::new(repeat_type, repeat_detail.as_deref())
+        .with_interval(repeat_interval.unwrap_or(1))
+        .with_end(repeat_until.as_deref(), repeat_count);
     with_db(&state, |db| {
-        TodoService::create_item(
-            db,
-            &text,
-            category_id,
-            &repeat,
-            repeat_detail.as_deref(),
-            track_streak.unwrap_or(false),
-            reminder_at.as_deref(),
-        )
+        JournalService::record(db, "add", JournalScope::Created(Vec::new()), |db| {
+            TodoService::create_item(
+                db,
+                &text,
+                category_id,
+                repeat,
+                track_streak.unwrap_or(false),
+                reminder_at.as_deref(),
+            )
+        })
     })
 }
 
@@ -37,7 +48,25 @@ pub fn update_item_reminder(
     reminder_at: Option<String>,
     state: State<AppState>,
 ) -> Result<(), String> {
-    with_db(&state, |db| TodoService::update_reminder(db, id, reminder_at.as_deref()))
+    with_db(&state, |db| {
+        JournalService::record(db, "edit", JournalScope::Items(vec![id]), |db| {
+            TodoService::update_reminder(db, id, reminder_at.as_deref())
+        })
+    })
+}
+
+#[tauri::command]
+pub fn update_item_due(
+    id: i64,
+    due_at: Option<String>,
+    due_times: Option<Vec<String>>,
+    state: State<AppState>,
+) -> Result<Option<TodoItem>, String> {
+    with_db(&state, |db| {
+        JournalService::record(db, "edit", JournalScope::Items(vec![id]), |db| {
+            TodoService::update_due(db, id, due_at.as_deref(), &due_times.unwrap_or_default())
+        })
+    })
 }
 
 #[tauri::command]
@@ -46,7 +75,11 @@ pub fn update_item_linked_app(
     linked_app: Option<String>,
     state: State<AppState>,
 ) -> Result<(), String> {
-    with_db(&state, |db| TodoService::update_linked_app(db, id, linked_app.as_deref()))
+    with_db(&state, |db| {
+        JournalService::record(db, "edit", JournalScope::Items(vec![id]), |db| {
+            TodoService::update_linked_app(db, id, linked_app.as_deref())
+        })
+    })
 }
 
 #[tauri::command]
@@ -68,17 +101,147 @@ pub fn get_items(
 
 #[tauri::command]
 pub fn toggle_item(id: i64, state: State<AppState>) -> Result<Option<TodoItem>, String> {
-    with_db(&state, |db| TodoService::toggle_item(db, id))
+    with_db(&state, |db| {
+        JournalService::record(db, "toggle", JournalScope::Items(vec![id]), |db| {
+            TodoService::toggle_item(db, id)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn increment_item_progress(
+    id: i64,
+    state: State<AppState>,
+) -> Result<Option<TodoItem>, String> {
+    with_db(&state, |db| {
+        JournalService::record(db, "toggle", JournalScope::Items(vec![id]), |db| {
+            TodoService::increment_progress(db, id)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn complete_item_with_value(
+    id: i64,
+    value: f64,
+    unit: Option<String>,
+    state: State<AppState>,
+) -> Result<Option<TodoItem>, String> {
+    with_db(&state, |db| {
+        JournalService::record(db, "toggle", JournalScope::Items(vec![id]), |db| {
+            MeasurementService::complete_with_value(db, id, value, unit.as_deref())
+        })
+    })
+}
+
+#[tauri::command]
+pub fn update_item_quantity_target(
+    id: i64,
+    quantity_target: i32,
+    state: State<AppState>,
+) -> Result<(), String> {
+    with_db(&state, |db| {
+        JournalService::record(db, "edit", JournalScope::Items(vec![id]), |db| {
+            TodoService::update_quantity_target(db, id, quantity_target)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn create_subtask(
+    parent_id: i64,
+    text: String,
+    state: State<AppState>,
+) -> Result<TodoItem, String> {
+    with_db(&state, |db| {
+        let scope = JournalScope::Created(vec![parent_id]);
+        JournalService::record(db, "add", scope, |db| {
+            TodoService::create_subtask(db, parent_id, &text)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn get_subtasks(parent_id: i64, state: State<AppState>) -> Result<Vec<TodoItem>, String> {
+    with_db(&state, |db| TodoService::get_subtasks(db, parent_id))
+}
+
+#[tauri::command]
+pub fn set_item_parent(
+    id: i64,
+    parent_id: Option<i64>,
+    state: State<AppState>,
+) -> Result<(), String> {
+    // The old parent comes along with the item, the new one is added
+    let ids = std::iter::once(id).chain(parent_id).collect();
+    with_db(&state, |db| {
+        JournalService::record(db, "move", JournalScope::Items(ids), |db| {
+            TodoService::set_parent(db, id, parent_id)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn update_item_auto_complete(
+    id: i64,
+    auto_complete: bool,
+    state: State<AppState>,
+) -> Result<(), String> {
+    with_db(&state, |db| {
+        JournalService::record(db, "edit", JournalScope::Items(vec![id]), |db| {
+            TodoService::update_auto_complete(db, id, auto_complete)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn get_item_schedule_versions(
+    id: i64,
+    state: State<AppState>,
+) -> Result<Vec<ScheduleVersion>, String> {
+    with_db(&state, |db| TodoService::get_schedule_versions(db, id))
+}
+
+#[tauri::command]
+pub fn skip_item_occurrence(id: i64, state: State<AppState>) -> Result<Option<TodoItem>, String> {
+    with_db(&state, |db| {
+        JournalService::record(db, "skip", JournalScope::Items(vec![id]), |db| {
+            RepeatService::skip_occurrence(db, id)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn snooze_item(
+    id: i64,
+    until: String,
+    state: State<AppState>,
+) -> Result<Option<TodoItem>, String> {
+    let until = chrono::NaiveDate::parse_from_str(&until, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid snooze date: {}", until))?;
+    with_db(&state, |db| {
+        JournalService::record(db, "snooze", JournalScope::Items(vec![id]), |db| {
+            RepeatService::snooze_occurrence(db, id, until)
+        })
+    })
 }
 
 #[tauri::command]
 pub fn delete_item(id: i64, state: State<AppState>) -> Result<(), String> {
-    with_db(&state, |db| TodoService::delete_item(db, id))
+    with_db(&state, |db| {
+        JournalService::record(db, "delete", JournalScope::Items(vec![id]), |db| {
+            TodoService::delete_item(db, id)
+        })
+    })
 }
 
 #[tauri::command]
 pub fn edit_item(id: i64, text: String, state: State<AppState>) -> Result<(), String> {
-    with_db(&state, |db| TodoService::update_text(db, id, &text))
+    with_db(&state, |db| {
+        JournalService::record(db, "edit", JournalScope::Items(vec![id]), |db| {
+            TodoService::update_text(db, id, &text)
+        })
+    })
 }
 
 #[tauri::command]
@@ -87,7 +250,11 @@ pub fn update_item_memo(
     memo: Option<String>,
     state: State<AppState>,
 ) -> Result<(), String> {
-    with_db(&state, |db| TodoService::update_memo(db, id, memo.as_deref()))
+    with_db(&state, |db| {
+        JournalService::record(db, "edit", JournalScope::Items(vec![id]), |db| {
+            TodoService::update_memo(db, id, memo.as_deref())
+        })
+    })
 }
 
 #[tauri::command]
@@ -95,20 +262,101 @@ pub fn update_item_repeat(
     id: i64,
     repeat_type: String,
     repeat_detail: Option<String>,
+    repeat_interval: Option<i32>,
+    repeat_until: Option<String>,
+    repeat_count: Option<i32>,
     state: State<AppState>,
 ) -> Result<(), String> {
-    let repeat = RepeatType::from_str(&repeat_type);
-    with_db(&state, |db| TodoService::update_repeat(db, id, &repeat, repeat_detail.as_deref()))
+    let repeat = RepeatSchedule::new(RepeatType::from_str(&repeat_type), repeat_detail.as_deref())
+        .with_interval(repeat_interval.unwrap_or(1))
+        .with_end(repeat_until.as_deref(), repeat_count);
+    with_db(&state, |db| {
+        JournalService::record(db, "edit", JournalScope::Items(vec![id]), |db| {
+            TodoService::update_repeat(db, id, repeat)
+        })
+    })
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn preview_repeat_occurrences(
+    repeat_type: String,
+    repeat_detail: Option<String>,
+    repeat_interval: Option<i32>,
+    repeat_until: Option<String>,
+    repeat_count: Option<i32>,
+    start_date: Option<String>,
+    count: Option<usize>,
+    exclusion_calendar_id: Option<i64>,
+    state: State<AppState>,
+) -> Result<Vec<String>, String> {
+    let parsed_type = RepeatType::from_str(&repeat_type);
+    if parsed_type.to_str() != repeat_type {
+        return Err(format!("Unknown repeat type: {}", repeat_type));
+    }
+
+    let repeat = RepeatSchedule::new(parsed_type, repeat_detail.as_deref())
+        .with_interval(repeat_interval.unwrap_or(1))
+        .with_end(repeat_until.as_deref(), repeat_count);
+    with_db(&state, |db| {
+        RepeatService::preview_schedule(
+            db,
+            repeat,
+            start_date.as_deref(),
+            count.unwrap_or(10),
+            exclusion_calendar_id,
+        )
+    })
 }
 
 #[tauri::command]
 pub fn reorder_items(item_ids: Vec<i64>, state: State<AppState>) -> Result<(), String> {
-    with_db(&state, |db| TodoService::reorder_items(db, &item_ids))
+    with_db(&state, |db| {
+        JournalService::record(db, "reorder", JournalScope::Items(item_ids.clone()), |db| {
+            TodoService::reorder_items(db, &item_ids)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn move_item(
+    id: i64,
+    category_id: Option<i64>,
+    app: AppHandle,
+    state: State<AppState>,
+) -> Result<(), String> {
+    move_items(vec![id], category_id, app, state)
+}
+
+#[tauri::command]
+pub fn move_items(
+    item_ids: Vec<i64>,
+    category_id: Option<i64>,
+    app: AppHandle,
+    state: State<AppState>,
+) -> Result<(), String> {
+    with_db(&state, |db| {
+        JournalService::record(db, "move", JournalScope::Items(item_ids.clone()), |db| {
+            TodoService::move_items(db, &item_ids, category_id)
+        })?;
+        if let Err(error) = WidgetService::refresh_cache(db, &app, None) {
+            log::error!(
+                "Failed to refresh widget cache after moving items: {}",
+                error
+            );
+        }
+        Ok::<(), String>(())
+    })
 }
 
 #[tauri::command]
 pub fn reset_all_items(category_id: Option<i64>, state: State<AppState>) -> Result<(), String> {
-    with_db(&state, |db| ResetService::reset_items(db, category_id))
+    with_db(&state, |db| {
+        let scope = JournalScope::CategoryItems(category_id);
+        JournalService::record(db, "reset", scope, |db| {
+            ResetService::reset_items(db, category_id)
+        })
+    })
 }
 
 #[tauri::command]
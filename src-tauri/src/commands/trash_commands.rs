@@ -0,0 +1,44 @@
+use tauri::State;
+
+use super::with_db;
+use crate::models::{TrashEntry, TrashKind};
+use crate::service::{JournalScope, JournalService, TrashService};
+use crate::AppState;
+
+#[tauri::command]
+pub fn get_trash(state: State<AppState>) -> Result<Vec<TrashEntry>, String> {
+    with_db(&state, TrashService::get_trash)
+}
+
+#[tauri::command]
+pub fn restore_item(id: i64, state: State<AppState>) -> Result<(), String> {
+    with_db(&state, |db| {
+        let scope = JournalScope::Trashed(TrashKind::Item, id);
+        JournalService::record(db, "restore", scope, |db| {
+            TrashService::restore_item(db, id)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn restore_category(id: i64, state: State<AppState>) -> Result<(), String> {
+    with_db(&state, |db| {
+        let scope = JournalScope::Trashed(TrashKind::Category, id);
+        JournalService::record(db, "restore", scope, |db| {
+            TrashService::restore_category(db, id)
+        })
+    })
+}
+
+#[tauri::command]
+pub fn restore_tag(id: i64, state: State<AppState>) -> Result<(), String> {
+    with_db(&state, |db| {
+        let scope = JournalScope::Trashed(TrashKind::Tag, id);
+        JournalService::record(db, "restore", scope, |db| TrashService::restore_tag(db, id))
+    })
+}
+
+#[tauri::command]
+pub fn empty_trash(state: State<AppState>) -> Result<usize, String> {
+    with_db(&state, TrashService::empty_trash)
+}
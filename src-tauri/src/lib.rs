@@ -6,7 +6,7 @@ mod service;
 use commands::{OAuthStateStore, RealtimeState, *};
 use repository::init_database;
 use rusqlite::Connection;
-use service::{SupabaseClient, SupabaseConfig, WidgetService};
+use service::{SchedulerService, SupabaseClient, SupabaseConfig, WidgetService};
 use std::sync::Mutex;
 use tauri::Manager;
 
@@ -58,6 +58,9 @@ pub fn run() {
             // Initialize Realtime state
             app.manage(RealtimeState::new());
 
+            // Keep resets, repeats and reminders current while the app stays open
+            SchedulerService::start(app.handle().clone());
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -65,15 +68,29 @@ pub fn run() {
             add_item,
             get_items,
             toggle_item,
+            increment_item_progress,
+            complete_item_with_value,
+            update_item_quantity_target,
+            create_subtask,
+            get_subtasks,
+            set_item_parent,
+            update_item_auto_complete,
+            get_item_schedule_versions,
+            skip_item_occurrence,
+            snooze_item,
             delete_item,
             edit_item,
             update_item_memo,
             update_item_repeat,
+            preview_repeat_occurrences,
             reorder_items,
+            move_item,
+            move_items,
             reset_all_items,
             check_and_auto_reset,
             process_repeats,
             update_item_reminder,
+            update_item_due,
             update_item_linked_app,
             // Widget commands
             get_widget_snapshot,
@@ -88,15 +105,40 @@ pub fn run() {
             get_categories,
             add_category,
             edit_category,
+            update_category_reset_policy,
             delete_category,
             reorder_categories,
+            // Exclusion calendar commands
+            get_exclusion_calendars,
+            add_exclusion_calendar,
+            delete_exclusion_calendar,
+            get_exclusion_ranges,
+            add_exclusion_range,
+            delete_exclusion_range,
+            import_exclusion_ics,
+            update_item_exclusion_calendar,
             // Settings commands
             get_setting,
             set_setting,
+            get_time_zone_status,
+            set_time_zone,
             // Streak commands
             get_tracked_items,
             get_item_heatmap_data,
+            get_category_heatmap_data,
+            get_tag_heatmap_data,
+            get_all_heatmap_data,
+            get_item_occurrences,
             update_track_streak,
+            update_streak_grace,
+            update_goal,
+            get_measurement_series,
+            get_weekday_stats,
+            get_completion_hour_stats,
+            get_category_trends,
+            get_most_missed_items,
+            backfill_completion,
+            remove_past_completion,
             // Auth commands
             sign_in_with_apple,
             sign_in_with_google,
@@ -126,6 +168,21 @@ pub fn run() {
             remove_tag_from_item,
             get_tags_for_item,
             get_items_by_tag,
+            // Template commands
+            get_templates,
+            create_template_from_category,
+            instantiate_template,
+            delete_template,
+            // Trash commands
+            get_trash,
+            restore_item,
+            restore_category,
+            restore_tag,
+            empty_trash,
+            // Undo commands
+            undo,
+            redo,
+            get_undo_state,
             // Graph commands
             get_graph_data,
             // Realtime commands
@@ -2,11 +2,56 @@ use serde::{Deserialize, Serialize};
 
 use super::SyncStatus;
 
+/// When the items of a category are unchecked again
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum ResetPolicy {
+    /// Daily at the global `reset_time` setting
+    #[default]
+    #[serde(rename = "global")]
+    Global,
+    /// Daily at each time in `reset_times`
+    #[serde(rename = "daily")]
+    Daily,
+    /// On the days in `reset_weekdays`, at each time in `reset_times`
+    #[serde(rename = "weekdays")]
+    Weekdays,
+    /// Manual reset only
+    #[serde(rename = "never")]
+    Never,
+}
+
+impl ResetPolicy {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "daily" => ResetPolicy::Daily,
+            "weekdays" => ResetPolicy::Weekdays,
+            "never" => ResetPolicy::Never,
+            _ => ResetPolicy::Global,
+        }
+    }
+
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            ResetPolicy::Global => "global",
+            ResetPolicy::Daily => "daily",
+            ResetPolicy::Weekdays => "weekdays",
+            ResetPolicy::Never => "never",
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Category {
     pub id: i64,
     pub name: String,
     pub display_order: i64,
+    pub reset_policy: ResetPolicy,
+    /// JSON array of "HH:MM" times in the user's time zone
+    pub reset_times: Option<String>,
+    /// JSON array of weekdays [0-6], 0=Sunday
+    pub reset_weekdays: Option<String>,
+    /// Latest reset boundary applied on this device (YYYY-MM-DDTHH:MM, not synced)
+    pub last_reset_at: Option<String>,
     // Sync fields
     pub sync_id: Option<String>,
     pub created_at: Option<String>,
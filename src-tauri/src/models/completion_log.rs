@@ -1,10 +1,61 @@
 use serde::{Deserialize, Serialize};
 
+use super::{GoalPeriod, SyncStatus};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompletionLog {
     pub item_id: i64,
     pub completed_on: String,
     pub completed_count: i32,
+    /// Local time of the day's latest completion, if known
+    pub completed_at: Option<String>,
+}
+
+/// An occurrence of a repeating item that was skipped or snoozed past
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkipLog {
+    pub item_id: i64,
+    pub skipped_on: String,
+    /// Deleted rows are kept until the remote delete went out
+    pub sync_status: SyncStatus,
+}
+
+/// How a missed slot was bridged without breaking the streak
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub enum FreezeKind {
+    /// One of the item's monthly streak freezes
+    #[default]
+    #[serde(rename = "freeze")]
+    Freeze,
+    /// One of the misses tolerated per week
+    #[serde(rename = "grace")]
+    Grace,
+}
+
+impl FreezeKind {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "grace" => FreezeKind::Grace,
+            _ => FreezeKind::Freeze,
+        }
+    }
+
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            FreezeKind::Freeze => "freeze",
+            FreezeKind::Grace => "grace",
+        }
+    }
+}
+
+/// A missed slot of a tracked item that a freeze or grace day covered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreakFreeze {
+    pub item_id: i64,
+    pub frozen_on: String,
+    pub kind: FreezeKind,
+    /// Pending until the freeze was pushed
+    pub sync_status: SyncStatus,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +69,44 @@ pub struct HeatmapData {
     pub longest_streak: i32,
     pub current_streak_dates: Vec<String>,
     pub longest_streak_dates: Vec<String>,
+    pub skipped_dates: Vec<String>,
+    /// Missed slots covered by a freeze or grace day
+    pub frozen_dates: Vec<String>,
+    /// Frequency goal the streaks count periods of, if any
+    pub goal_period: GoalPeriod,
+    pub goal_target: i32,
+    pub goal_progress: Vec<GoalPeriodProgress>,
+}
+
+/// Completions within one week or month of a frequency goal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalPeriodProgress {
+    pub period_start: String,
+    pub period_end: String,
+    pub completed: i32,
+    pub target: i32,
+    pub met: bool,
+}
+
+/// Completed share of the scheduled slots on one day, across several items
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeatmapDayRatio {
+    pub date: String,
+    pub completed: i32,
+    pub scheduled: i32,
+    pub ratio: f64,
+}
+
+/// Heatmap over a category, a tag or every tracked item. Streaks count
+/// perfect days: every scheduled slot of the day completed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateHeatmapData {
+    pub item_ids: Vec<i64>,
+    pub days: Vec<HeatmapDayRatio>,
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    pub current_streak_dates: Vec<String>,
+    pub longest_streak_dates: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +115,11 @@ pub struct HeatmapIntensity {
     pub level: i32,
 }
 
+impl HeatmapIntensity {
+    /// Level of a frozen day, apart from the 1-10 combo scale
+    pub const FROZEN_LEVEL: i32 = -1;
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrackedItem {
     pub id: i64,
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Named list of days on which repeating items are not scheduled
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExclusionCalendar {
+    pub id: i64,
+    pub name: String,
+    pub created_at: Option<String>,
+}
+
+/// Excluded day or inclusive date range (YYYY-MM-DD) of a calendar
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ExclusionRange {
+    pub id: i64,
+    pub calendar_id: i64,
+    pub start_date: String,
+    pub end_date: String,
+    pub label: Option<String>,
+}
+
+impl ExclusionRange {
+    pub fn contains(&self, date: &str) -> bool {
+        self.start_date.as_str() <= date && date <= self.end_date.as_str()
+    }
+}
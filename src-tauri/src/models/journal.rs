@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Rows of one table whose `column` is in `ids`, as column-name to value
+/// maps. Writing the image back replaces exactly those rows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableImage {
+    pub table: String,
+    pub column: String,
+    pub ids: Vec<i64>,
+    pub rows: Vec<Map<String, Value>>,
+}
+
+/// A recorded operation with the rows it touched before and after it ran
+#[derive(Debug, Clone)]
+pub struct JournalEntry {
+    pub id: i64,
+    pub label: String,
+    pub before: Vec<TableImage>,
+    pub after: Vec<TableImage>,
+}
+
+/// What undo and redo would act on next
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UndoState {
+    pub undo_label: Option<String>,
+    pub redo_label: Option<String>,
+    /// Operation dropped instead of undone or redone, because its rows
+    /// changed since it was recorded
+    pub discarded_label: Option<String>,
+}
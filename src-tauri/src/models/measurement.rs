@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use super::SyncStatus;
+
+/// Value recorded with one completion of an item (72.4 kg)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Measurement {
+    pub id: i64,
+    pub item_id: i64,
+    /// Logical date of the completion
+    pub recorded_on: String,
+    /// Local time of the completion in the user zone
+    pub recorded_at: String,
+    pub value: f64,
+    pub unit: Option<String>,
+    pub sync_id: Option<String>,
+    pub sync_status: SyncStatus,
+}
+
+/// One recorded value in a series
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeasurementPoint {
+    pub date: String,
+    pub value: f64,
+    pub unit: Option<String>,
+}
+
+/// Recorded values of an item over time with summary stats.
+/// The stats are None while there are no values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeasurementSeries {
+    pub item_id: i64,
+    /// Unit of the latest value
+    pub unit: Option<String>,
+    pub points: Vec<MeasurementPoint>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub avg: Option<f64>,
+    pub latest: Option<f64>,
+    /// Least-squares slope in units per day; needs values on two days
+    pub trend_per_day: Option<f64>,
+}
@@ -1,21 +1,42 @@
 mod category;
 mod completion_log;
+mod exclusion;
 pub mod graph;
+mod journal;
+mod measurement;
+mod occurrence;
 mod realtime;
+mod scheduler;
+mod stats;
 mod sync;
 mod tag;
+mod template;
+mod time_zone;
 mod todo_item;
+mod trash;
 mod widget;
 
-pub use category::Category;
-pub use completion_log::{CompletionLog, HeatmapData, HeatmapIntensity, TrackedItem};
+pub use category::{Category, ResetPolicy};
+pub use completion_log::{
+    AggregateHeatmapData, CompletionLog, FreezeKind, GoalPeriodProgress, HeatmapData,
+    HeatmapDayRatio, HeatmapIntensity, SkipLog, StreakFreeze, TrackedItem,
+};
+pub use exclusion::{ExclusionCalendar, ExclusionRange};
+pub use journal::{JournalEntry, TableImage, UndoState};
+pub use measurement::{Measurement, MeasurementPoint, MeasurementSeries};
+pub use occurrence::{Occurrence, OccurrenceSource, OccurrenceStatus};
 pub use realtime::{
     DataChangeType, DataChangedEvent, RealtimeConnectionState, RealtimeEvent, RealtimeEventType,
     RealtimeStatus,
 };
+pub use scheduler::SchedulerEvent;
+pub use stats::{CategoryTrend, CompletionHourStats, MissedItem, WeekdayStats};
 pub use sync::{AuthProvider, AuthSession, SyncResult, SyncStatus, SyncStatusInfo, UserProfile};
 pub use tag::{Tag, TodoTag};
-pub use todo_item::{RepeatType, TodoItem};
+pub use template::{ChecklistTemplate, TemplateItem};
+pub use time_zone::TimeZoneStatus;
+pub use todo_item::{GoalPeriod, RepeatSchedule, RepeatType, ScheduleVersion, TodoItem};
+pub use trash::{TrashEntry, TrashKind};
 pub use widget::{
     WidgetCategoryPendingItem, WidgetCategorySummary, WidgetSnapshot, WidgetTheme, WidgetTodoItem,
 };
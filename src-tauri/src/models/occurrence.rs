@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OccurrenceStatus {
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "missed")]
+    Missed,
+    #[serde(rename = "skipped")]
+    Skipped,
+}
+
+impl OccurrenceStatus {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "completed" => OccurrenceStatus::Completed,
+            "skipped" => OccurrenceStatus::Skipped,
+            _ => OccurrenceStatus::Missed,
+        }
+    }
+
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            OccurrenceStatus::Completed => "completed",
+            OccurrenceStatus::Missed => "missed",
+            OccurrenceStatus::Skipped => "skipped",
+        }
+    }
+}
+
+/// Where a completion or skip came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OccurrenceSource {
+    #[serde(rename = "app")]
+    App,
+    #[serde(rename = "widget")]
+    Widget,
+}
+
+impl OccurrenceSource {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "widget" => OccurrenceSource::Widget,
+            _ => OccurrenceSource::App,
+        }
+    }
+
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            OccurrenceSource::App => "app",
+            OccurrenceSource::Widget => "widget",
+        }
+    }
+}
+
+/// One scheduled slot of a repeating item and what happened to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Occurrence {
+    pub id: i64,
+    pub item_id: i64,
+    /// Slot date (YYYY-MM-DD), or slot datetime (YYYY-MM-DDTHH:MM) for items with due times
+    pub scheduled_on: String,
+    pub status: OccurrenceStatus,
+    /// RFC 3339 timestamp of the completion
+    pub completed_at: Option<String>,
+    pub source: Option<OccurrenceSource>,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Event sent to every window when the background scheduler changed data
+/// or a reminder came due
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SchedulerEvent {
+    /// Some category (or the uncategorized items) was auto-reset
+    pub reset: bool,
+    /// Number of repeating items that came back
+    pub reactivated: i32,
+    /// Open items whose reminder or due time arrived since the last run
+    pub due_item_ids: Vec<i64>,
+}
+
+impl SchedulerEvent {
+    pub fn is_empty(&self) -> bool {
+        !self.reset && self.reactivated == 0 && self.due_item_ids.is_empty()
+    }
+}
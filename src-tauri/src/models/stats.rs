@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// Completed and missed slots on one weekday (0 = Sunday)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeekdayStats {
+    pub weekday: u32,
+    pub completed: i64,
+    pub missed: i64,
+    /// Share of completed slots; None when nothing was due that weekday
+    pub rate: Option<f64>,
+}
+
+/// When in the day completions happen, in the zone they were made in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionHourStats {
+    /// Completions per hour of day, index 0 to 23
+    pub hour_counts: Vec<i64>,
+    pub total: i64,
+    /// Hour with the most completions
+    pub typical_hour: Option<u32>,
+}
+
+/// Completions of a category in the last 30 and 90 days against the
+/// periods of the same length before them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryTrend {
+    pub category_id: Option<i64>,
+    pub category_name: Option<String>,
+    pub last_30_days: i64,
+    pub previous_30_days: i64,
+    /// Relative change, 0.25 = 25% more; None without earlier completions
+    pub change_30_days: Option<f64>,
+    pub last_90_days: i64,
+    pub previous_90_days: i64,
+    pub change_90_days: Option<f64>,
+}
+
+/// An item with how many of its slots were missed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissedItem {
+    pub item_id: i64,
+    pub text: String,
+    pub category_id: Option<i64>,
+    pub missed: i64,
+    pub completed: i64,
+    pub miss_rate: f64,
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use super::{RepeatType, SyncStatus};
+
+/// Saved snapshot of a category's items that can be turned into items again
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ChecklistTemplate {
+    pub id: i64,
+    pub name: String,
+    pub items: Vec<TemplateItem>,
+    pub sync_id: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub sync_status: SyncStatus,
+}
+
+/// One item of a template, in template order
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TemplateItem {
+    pub text: String,
+    pub memo: Option<String>,
+    pub repeat_type: RepeatType,
+    pub repeat_detail: Option<String>,
+    pub repeat_interval: i32,
+    #[serde(default)]
+    pub repeat_start: Option<String>,
+    #[serde(default)]
+    pub repeat_until: Option<String>,
+    #[serde(default)]
+    pub repeat_count: Option<i32>,
+    /// JSON array of "HH:MM" slots, as on the item
+    #[serde(default)]
+    pub due_times: Option<String>,
+    pub reminder_at: Option<String>,
+    /// Tag names, created on instantiation when missing
+    pub tags: Vec<String>,
+    /// Position of the parent item in the template, for sub-items
+    pub parent_position: Option<usize>,
+}
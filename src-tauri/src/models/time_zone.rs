@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeZoneStatus {
+    /// IANA zone logical dates are computed in
+    pub time_zone: String,
+    /// IANA zone the device currently reports
+    pub device_time_zone: String,
+    /// The device zone differs from the one seen at the previous check
+    pub device_changed: bool,
+}
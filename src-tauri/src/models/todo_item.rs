@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::SyncStatus;
+use super::{ExclusionRange, SyncStatus};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum RepeatType {
@@ -12,6 +12,14 @@ pub enum RepeatType {
     Weekly,
     #[serde(rename = "monthly")]
     Monthly,
+    #[serde(rename = "yearly")]
+    Yearly,
+    /// Due `repeat_interval` days after the last completion
+    #[serde(rename = "after_completion")]
+    AfterCompletion,
+    /// RFC 5545 recurrence rule stored in `repeat_detail`
+    #[serde(rename = "rule")]
+    Rule,
 }
 
 impl Default for RepeatType {
@@ -26,6 +34,9 @@ impl RepeatType {
             "daily" => RepeatType::Daily,
             "weekly" => RepeatType::Weekly,
             "monthly" => RepeatType::Monthly,
+            "yearly" => RepeatType::Yearly,
+            "after_completion" => RepeatType::AfterCompletion,
+            "rule" => RepeatType::Rule,
             _ => RepeatType::None,
         }
     }
@@ -36,6 +47,40 @@ impl RepeatType {
             RepeatType::Daily => "daily",
             RepeatType::Weekly => "weekly",
             RepeatType::Monthly => "monthly",
+            RepeatType::Yearly => "yearly",
+            RepeatType::AfterCompletion => "after_completion",
+            RepeatType::Rule => "rule",
+        }
+    }
+}
+
+/// Period of a frequency goal ("3 times per week")
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum GoalPeriod {
+    #[default]
+    #[serde(rename = "none")]
+    None,
+    /// Weeks starting on the `week_start` setting, Monday by default
+    #[serde(rename = "week")]
+    Week,
+    #[serde(rename = "month")]
+    Month,
+}
+
+impl GoalPeriod {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "week" => GoalPeriod::Week,
+            "month" => GoalPeriod::Month,
+            _ => GoalPeriod::None,
+        }
+    }
+
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            GoalPeriod::None => "none",
+            GoalPeriod::Week => "week",
+            GoalPeriod::Month => "month",
         }
     }
 }
@@ -50,6 +95,38 @@ pub struct TodoItem {
     pub memo: Option<String>,
     pub repeat_type: RepeatType,
     pub repeat_detail: Option<String>,
+    pub repeat_interval: i32,
+    pub repeat_start: Option<String>,
+    /// Last date (YYYY-MM-DD) an occurrence may fall on
+    pub repeat_until: Option<String>,
+    /// Number of completions after which the series ends
+    pub repeat_count: Option<i32>,
+    /// Completions counted towards `repeat_count`
+    pub repeat_completed: i32,
+    /// The series ran past `repeat_until` or used up `repeat_count`; the
+    /// item stays done through resets
+    pub repeat_ended: bool,
+    /// Exclusion calendar whose days are skipped by the repeat
+    pub exclusion_calendar_id: Option<i64>,
+    /// Due datetime of the current or upcoming slot (YYYY-MM-DDTHH:MM, user time zone)
+    pub due_at: Option<String>,
+    /// JSON array of "HH:MM" slots on each scheduled day of a repeating item
+    pub due_times: Option<String>,
+    /// Missed slots per calendar month a freeze may cover (0 = none)
+    pub streak_freezes_per_month: i32,
+    /// Missed slots per week the streak tolerates (0 = none)
+    pub streak_grace_per_week: i32,
+    /// Frequency goal: completions wanted per goal_period
+    pub goal_period: GoalPeriod,
+    pub goal_target: i32,
+    /// Completions needed before the item counts as done (0 = a plain item)
+    pub quantity_target: i32,
+    /// Progress towards quantity_target in the current slot
+    pub quantity_progress: i32,
+    /// Item this one is a sub-item of; sub-items share their parent's category
+    pub parent_id: Option<i64>,
+    /// Complete this item once all its sub-items are done
+    pub auto_complete: bool,
     pub next_due_at: Option<String>,
     pub last_completed_at: Option<String>,
     pub track_streak: bool,
@@ -61,3 +138,91 @@ pub struct TodoItem {
     pub updated_at: Option<String>,
     pub sync_status: SyncStatus,
 }
+
+/// Repeat settings of a todo, passed around as one unit so every
+/// schedule calculation sees the same type, detail and interval.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepeatSchedule {
+    pub repeat_type: RepeatType,
+    pub repeat_detail: Option<String>,
+    pub repeat_interval: i32,
+    pub repeat_start: Option<String>,
+    pub repeat_until: Option<String>,
+    pub repeat_count: Option<i32>,
+    /// Days the schedule never lands on, loaded from the item's calendar
+    pub exclusions: Vec<ExclusionRange>,
+}
+
+impl RepeatSchedule {
+    pub fn new(repeat_type: RepeatType, repeat_detail: Option<&str>) -> Self {
+        Self {
+            repeat_type,
+            repeat_detail: repeat_detail.map(|s| s.to_string()),
+            repeat_interval: 1,
+            repeat_start: None,
+            repeat_until: None,
+            repeat_count: None,
+            exclusions: Vec::new(),
+        }
+    }
+
+    pub fn with_interval(mut self, repeat_interval: i32) -> Self {
+        self.repeat_interval = repeat_interval;
+        self
+    }
+
+    pub fn with_end(mut self, repeat_until: Option<&str>, repeat_count: Option<i32>) -> Self {
+        self.repeat_until = repeat_until.map(|s| s.to_string());
+        self.repeat_count = repeat_count;
+        self
+    }
+
+    pub fn from_item(item: &TodoItem) -> Self {
+        Self {
+            repeat_type: item.repeat_type.clone(),
+            repeat_detail: item.repeat_detail.clone(),
+            repeat_interval: item.repeat_interval.max(1),
+            repeat_start: item.repeat_start.clone(),
+            repeat_until: item.repeat_until.clone(),
+            repeat_count: item.repeat_count,
+            exclusions: Vec::new(),
+        }
+    }
+
+    pub fn with_exclusions(mut self, exclusions: Vec<ExclusionRange>) -> Self {
+        self.exclusions = exclusions;
+        self
+    }
+
+    pub fn is_excluded(&self, date: &str) -> bool {
+        self.exclusions.iter().any(|range| range.contains(date))
+    }
+}
+
+/// Repeat settings an item followed from `effective_from` (YYYY-MM-DD)
+/// until the next version took over
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ScheduleVersion {
+    pub item_id: i64,
+    pub effective_from: String,
+    pub repeat_type: RepeatType,
+    pub repeat_detail: Option<String>,
+    pub repeat_interval: i32,
+    pub repeat_start: Option<String>,
+    pub repeat_until: Option<String>,
+    pub repeat_count: Option<i32>,
+}
+
+impl ScheduleVersion {
+    pub fn schedule(&self) -> RepeatSchedule {
+        RepeatSchedule {
+            repeat_type: self.repeat_type.clone(),
+            repeat_detail: self.repeat_detail.clone(),
+            repeat_interval: self.repeat_interval.max(1),
+            repeat_start: self.repeat_start.clone(),
+            repeat_until: self.repeat_until.clone(),
+            repeat_count: self.repeat_count,
+            exclusions: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrashKind {
+    #[serde(rename = "item")]
+    Item,
+    #[serde(rename = "category")]
+    Category,
+    #[serde(rename = "tag")]
+    Tag,
+}
+
+impl TrashKind {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "item" => Some(TrashKind::Item),
+            "category" => Some(TrashKind::Category),
+            "tag" => Some(TrashKind::Tag),
+            _ => None,
+        }
+    }
+
+    pub fn table(&self) -> &'static str {
+        match self {
+            TrashKind::Item => "todos",
+            TrashKind::Category => "categories",
+            TrashKind::Tag => "tags",
+        }
+    }
+}
+
+/// A deleted item, category or tag that can still be restored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub kind: TrashKind,
+    pub id: i64,
+    /// Item text, or category or tag name
+    pub name: String,
+    /// Category an item was in
+    pub category_id: Option<i64>,
+    pub deleted_at: String,
+    /// When the entry is deleted for good
+    pub purge_at: String,
+}
@@ -9,6 +9,8 @@ pub struct WidgetTodoItem {
     pub category_name: Option<String>,
     pub display_order: i64,
     pub reminder_at: Option<String>,
+    pub quantity_target: i32,
+    pub quantity_progress: i32,
     pub updated_at: Option<String>,
 }
 
@@ -18,6 +20,8 @@ pub struct WidgetCategoryPendingItem {
     pub text: String,
     pub display_order: i64,
     pub tags: Vec<String>,
+    pub quantity_target: i32,
+    pub quantity_progress: i32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
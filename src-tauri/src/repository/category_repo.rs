@@ -1,12 +1,12 @@
 use rusqlite::{params, Connection};
 
-use crate::models::{Category, SyncStatus};
+use crate::models::{Category, ResetPolicy, SyncStatus};
 
 pub struct CategoryRepository;
 
 impl CategoryRepository {
     const SELECT_COLUMNS: &'static str =
-        "id, name, display_order, sync_id, created_at, updated_at, sync_status";
+        "id, name, display_order, sync_id, created_at, updated_at, sync_status, reset_policy, reset_times, reset_weekdays, last_reset_at";
 
     fn row_to_category(row: &rusqlite::Row) -> Result<Category, rusqlite::Error> {
         let sync_status_str: Option<String> = row.get(6)?;
@@ -14,6 +14,13 @@ impl CategoryRepository {
             id: row.get(0)?,
             name: row.get(1)?,
             display_order: row.get(2)?,
+            reset_policy: row
+                .get::<_, Option<String>>(7)?
+                .map(|s| ResetPolicy::from_str(&s))
+                .unwrap_or_default(),
+            reset_times: row.get(8)?,
+            reset_weekdays: row.get(9)?,
+            last_reset_at: row.get(10)?,
             sync_id: row.get(3)?,
             created_at: row.get(4)?,
             updated_at: row.get(5)?,
@@ -90,7 +97,7 @@ impl CategoryRepository {
 
     pub fn get_pending_sync(conn: &Connection) -> Result<Vec<Category>, rusqlite::Error> {
         let sql = format!(
-            "SELECT {} FROM categories WHERE sync_status = 'pending' OR sync_status = 'deleted' OR sync_status IS NULL",
+            "SELECT {} FROM categories WHERE sync_status = 'pending' OR (sync_status = 'deleted' AND deleted_at IS NULL) OR sync_status IS NULL",
             Self::SELECT_COLUMNS
         );
         let mut stmt = conn.prepare(&sql)?;
@@ -134,6 +141,10 @@ impl CategoryRepository {
             id,
             name: name.to_string(),
             display_order,
+            reset_policy: ResetPolicy::Global,
+            reset_times: None,
+            reset_weekdays: None,
+            last_reset_at: None,
             sync_id: None,
             created_at: Some(now.clone()),
             updated_at: Some(now),
@@ -150,6 +161,34 @@ impl CategoryRepository {
         Ok(())
     }
 
+    pub fn update_reset_policy(
+        conn: &Connection,
+        id: i64,
+        policy: &ResetPolicy,
+        reset_times: Option<&str>,
+        reset_weekdays: Option<&str>,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE categories SET reset_policy = ?1, reset_times = ?2, reset_weekdays = ?3 WHERE id = ?4",
+            params![policy.to_str(), reset_times, reset_weekdays, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    /// Record the latest reset boundary applied (device-local, not synced)
+    pub fn set_last_reset_at(
+        conn: &Connection,
+        id: i64,
+        last_reset_at: &str,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE categories SET last_reset_at = ?1 WHERE id = ?2",
+            params![last_reset_at, id],
+        )?;
+        Ok(())
+    }
+
     pub fn delete(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
         // Delete all todos in this category
         conn.execute("DELETE FROM todos WHERE category_id = ?1", params![id])?;
@@ -163,7 +202,7 @@ impl CategoryRepository {
     pub fn mark_deleted(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
         let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
         conn.execute(
-            "UPDATE categories SET sync_status = 'deleted', updated_at = ?1 WHERE id = ?2",
+            "UPDATE categories SET sync_status = 'deleted', deleted_at = NULL, updated_at = ?1 WHERE id = ?2",
             params![now, id],
         )?;
         Ok(())
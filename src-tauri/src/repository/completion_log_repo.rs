@@ -5,24 +5,51 @@ use crate::models::CompletionLog;
 pub struct CompletionLogRepository;
 
 impl CompletionLogRepository {
-    /// Increment the completion count for a given item and date
-    pub fn increment(conn: &Connection, item_id: i64, date: &str) -> Result<(), rusqlite::Error> {
+    fn row_to_log(row: &rusqlite::Row) -> Result<CompletionLog, rusqlite::Error> {
+        Ok(CompletionLog {
+            item_id: row.get(0)?,
+            completed_on: row.get(1)?,
+            completed_count: row.get(2)?,
+            completed_at: row.get(3)?,
+        })
+    }
+
+    fn now_iso() -> String {
+        chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    }
+
+    /// Increment the completion count for a given item and date.
+    /// `completed_at` is the local time of the completion, if known.
+    pub fn increment(
+        conn: &Connection,
+        item_id: i64,
+        date: &str,
+        completed_at: Option<&str>,
+    ) -> Result<(), rusqlite::Error> {
         conn.execute(
-            "INSERT INTO completion_logs (item_id, completed_on, completed_count)
-             VALUES (?1, ?2, 1)
-             ON CONFLICT(item_id, completed_on) DO UPDATE SET completed_count = completed_count + 1",
-            params![item_id, date],
+            "INSERT INTO completion_logs
+                (item_id, completed_on, completed_count, completed_at, updated_at, sync_status)
+             VALUES (?1, ?2, 1, ?3, ?4, 'pending')
+             ON CONFLICT(item_id, completed_on) DO UPDATE SET
+                completed_count = completed_count + 1,
+                completed_at = COALESCE(excluded.completed_at, completed_at),
+                updated_at = excluded.updated_at,
+                sync_status = 'pending'",
+            params![item_id, date, completed_at, Self::now_iso()],
         )?;
         Ok(())
     }
 
-    /// Decrement the completion count for a given item and date (minimum 0)
+    /// Decrement the completion count for a given item and date (minimum 0).
+    /// The emptied row stays so the removal reaches other devices.
     pub fn decrement(conn: &Connection, item_id: i64, date: &str) -> Result<(), rusqlite::Error> {
         conn.execute(
             "UPDATE completion_logs
-             SET completed_count = MAX(completed_count - 1, 0)
-             WHERE item_id = ?1 AND completed_on = ?2",
-            params![item_id, date],
+             SET completed_count = MAX(completed_count - 1, 0),
+                 updated_at = ?3,
+                 sync_status = 'pending'
+             WHERE item_id = ?1 AND completed_on = ?2 AND completed_count > 0",
+            params![item_id, date, Self::now_iso()],
         )?;
         Ok(())
     }
@@ -34,7 +61,7 @@ impl CompletionLogRepository {
         days: i32,
     ) -> Result<Vec<CompletionLog>, rusqlite::Error> {
         let mut stmt = conn.prepare(
-            "SELECT item_id, completed_on, completed_count FROM completion_logs
+            "SELECT item_id, completed_on, completed_count, completed_at FROM completion_logs
              WHERE item_id = ?1
              AND completed_on >= date('now', '-' || ?2 || ' days')
              AND completed_count > 0
@@ -42,13 +69,7 @@ impl CompletionLogRepository {
         )?;
 
         let logs = stmt
-            .query_map(params![item_id, days], |row| {
-                Ok(CompletionLog {
-                    item_id: row.get(0)?,
-                    completed_on: row.get(1)?,
-                    completed_count: row.get(2)?,
-                })
-            })?
+            .query_map(params![item_id, days], Self::row_to_log)?
             .filter_map(Result::ok)
             .collect();
 
@@ -61,41 +82,35 @@ impl CompletionLogRepository {
         item_id: i64,
     ) -> Result<Vec<CompletionLog>, rusqlite::Error> {
         let mut stmt = conn.prepare(
-            "SELECT item_id, completed_on, completed_count FROM completion_logs
+            "SELECT item_id, completed_on, completed_count, completed_at FROM completion_logs
              WHERE item_id = ?1
              AND completed_count > 0
              ORDER BY completed_on ASC",
         )?;
 
         let logs = stmt
-            .query_map(params![item_id], |row| {
-                Ok(CompletionLog {
-                    item_id: row.get(0)?,
-                    completed_on: row.get(1)?,
-                    completed_count: row.get(2)?,
-                })
-            })?
+            .query_map(params![item_id], Self::row_to_log)?
             .filter_map(Result::ok)
             .collect();
 
         Ok(logs)
     }
 
-    /// Get all completion logs (for sync push)
-    pub fn get_all(conn: &Connection) -> Result<Vec<CompletionLog>, rusqlite::Error> {
+    /// Logs changed since the last sync with their change time (for sync
+    /// push). Emptied days are included so removed completions reach the
+    /// server too.
+    pub fn get_pending(conn: &Connection) -> Result<Vec<(CompletionLog, String)>, rusqlite::Error> {
         let mut stmt = conn.prepare(
-            "SELECT item_id, completed_on, completed_count FROM completion_logs
-             WHERE completed_count > 0
+            "SELECT item_id, completed_on, completed_count, completed_at, updated_at
+             FROM completion_logs
+             WHERE sync_status = 'pending'
              ORDER BY item_id, completed_on",
         )?;
 
         let logs = stmt
             .query_map([], |row| {
-                Ok(CompletionLog {
-                    item_id: row.get(0)?,
-                    completed_on: row.get(1)?,
-                    completed_count: row.get(2)?,
-                })
+                let updated_at: Option<String> = row.get(4)?;
+                Ok((Self::row_to_log(row)?, updated_at.unwrap_or_default()))
             })?
             .filter_map(Result::ok)
             .collect();
@@ -103,19 +118,53 @@ impl CompletionLogRepository {
         Ok(logs)
     }
 
-    /// Upsert a completion log (for sync pull)
+    /// Mark a pushed log as synced unless it changed again meanwhile
+    pub fn mark_synced(
+        conn: &Connection,
+        item_id: i64,
+        completed_on: &str,
+        updated_at: &str,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE completion_logs SET sync_status = 'synced'
+             WHERE item_id = ?1 AND completed_on = ?2 AND IFNULL(updated_at, '') = ?3",
+            params![item_id, completed_on, updated_at],
+        )?;
+        Ok(())
+    }
+
+    /// Upsert a completion log (for sync pull). The later change wins; a
+    /// local change not pushed yet is kept. Logs from before change times
+    /// were recorded keep the higher count.
     pub fn upsert(
         conn: &Connection,
         item_id: i64,
         completed_on: &str,
         completed_count: i32,
+        completed_at: Option<&str>,
+        updated_at: Option<&str>,
     ) -> Result<(), rusqlite::Error> {
         conn.execute(
-            "INSERT INTO completion_logs (item_id, completed_on, completed_count)
-             VALUES (?1, ?2, ?3)
+            "INSERT INTO completion_logs
+                (item_id, completed_on, completed_count, completed_at, updated_at, sync_status)
+             VALUES (?1, ?2, ?3, ?4, ?5, 'synced')
              ON CONFLICT(item_id, completed_on) DO UPDATE SET
-                completed_count = MAX(completion_logs.completed_count, excluded.completed_count)",
-            params![item_id, completed_on, completed_count],
+                completed_count = CASE
+                    WHEN excluded.updated_at IS NULL
+                    THEN MAX(completion_logs.completed_count, excluded.completed_count)
+                    ELSE excluded.completed_count END,
+                completed_at = COALESCE(excluded.completed_at, completion_logs.completed_at),
+                updated_at = COALESCE(excluded.updated_at, completion_logs.updated_at)
+             WHERE completion_logs.sync_status != 'pending'
+               AND (completion_logs.updated_at IS NULL
+                    OR excluded.updated_at > completion_logs.updated_at)",
+            params![
+                item_id,
+                completed_on,
+                completed_count,
+                completed_at,
+                updated_at
+            ],
         )?;
         Ok(())
     }
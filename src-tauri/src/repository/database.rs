@@ -25,6 +25,16 @@ pub fn init_database(app: &AppHandle) -> Result<Connection, rusqlite::Error> {
     Ok(conn)
 }
 
+/// A fresh database in memory with the full schema, for tests
+#[cfg(test)]
+pub fn open_in_memory() -> Connection {
+    let conn = Connection::open_in_memory().expect("Failed to open in-memory database");
+    create_tables(&conn).expect("Failed to create tables");
+    migration::run_migrations(&conn).expect("Failed to run migrations");
+    create_default_category(&conn).expect("Failed to create default category");
+    conn
+}
+
 fn create_tables(conn: &Connection) -> Result<(), rusqlite::Error> {
     // Create categories table
     conn.execute(
@@ -0,0 +1,108 @@
+use rusqlite::{params, Connection};
+
+use crate::models::{ExclusionCalendar, ExclusionRange};
+
+pub struct ExclusionRepository;
+
+impl ExclusionRepository {
+    fn row_to_range(row: &rusqlite::Row) -> Result<ExclusionRange, rusqlite::Error> {
+        Ok(ExclusionRange {
+            id: row.get(0)?,
+            calendar_id: row.get(1)?,
+            start_date: row.get(2)?,
+            end_date: row.get(3)?,
+            label: row.get(4)?,
+        })
+    }
+
+    pub fn get_calendars(conn: &Connection) -> Result<Vec<ExclusionCalendar>, rusqlite::Error> {
+        let mut stmt =
+            conn.prepare("SELECT id, name, created_at FROM exclusion_calendars ORDER BY name ASC")?;
+
+        let calendars = stmt
+            .query_map([], |row| {
+                Ok(ExclusionCalendar {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    created_at: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(calendars)
+    }
+
+    pub fn create_calendar(
+        conn: &Connection,
+        name: &str,
+    ) -> Result<ExclusionCalendar, rusqlite::Error> {
+        let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        conn.execute(
+            "INSERT INTO exclusion_calendars (name, created_at) VALUES (?1, ?2)",
+            params![name, &now],
+        )?;
+
+        Ok(ExclusionCalendar {
+            id: conn.last_insert_rowid(),
+            name: name.to_string(),
+            created_at: Some(now),
+        })
+    }
+
+    /// Delete a calendar with its ranges and detach it from todos
+    pub fn delete_calendar(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET exclusion_calendar_id = NULL WHERE exclusion_calendar_id = ?1",
+            params![id],
+        )?;
+        conn.execute(
+            "DELETE FROM exclusion_ranges WHERE calendar_id = ?1",
+            params![id],
+        )?;
+        conn.execute("DELETE FROM exclusion_calendars WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    pub fn get_ranges(
+        conn: &Connection,
+        calendar_id: i64,
+    ) -> Result<Vec<ExclusionRange>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT id, calendar_id, start_date, end_date, label FROM exclusion_ranges
+             WHERE calendar_id = ?1
+             ORDER BY start_date ASC",
+        )?;
+
+        let ranges = stmt
+            .query_map(params![calendar_id], Self::row_to_range)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ranges)
+    }
+
+    pub fn add_range(
+        conn: &Connection,
+        calendar_id: i64,
+        start_date: &str,
+        end_date: &str,
+        label: Option<&str>,
+    ) -> Result<ExclusionRange, rusqlite::Error> {
+        conn.execute(
+            "INSERT INTO exclusion_ranges (calendar_id, start_date, end_date, label) VALUES (?1, ?2, ?3, ?4)",
+            params![calendar_id, start_date, end_date, label],
+        )?;
+
+        Ok(ExclusionRange {
+            id: conn.last_insert_rowid(),
+            calendar_id,
+            start_date: start_date.to_string(),
+            end_date: end_date.to_string(),
+            label: label.map(|s| s.to_string()),
+        })
+    }
+
+    pub fn delete_range(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        conn.execute("DELETE FROM exclusion_ranges WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+}
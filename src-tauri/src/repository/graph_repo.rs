@@ -45,11 +45,12 @@ impl GraphRepository {
 
         // Get all non-deleted items
         let mut item_stmt = conn.prepare(
-            "SELECT id, text, category_id, done FROM todos WHERE sync_status != 'deleted' OR sync_status IS NULL",
+            "SELECT id, text, category_id, done, parent_id FROM todos WHERE sync_status != 'deleted' OR sync_status IS NULL",
         )?;
         let item_nodes = item_stmt.query_map([], |row| {
             let id: i64 = row.get(0)?;
             let category_id: Option<i64> = row.get(2)?;
+            let parent_id: Option<i64> = row.get(4)?;
             Ok((
                 GraphNode {
                     id,
@@ -59,12 +60,19 @@ impl GraphRepository {
                     done: row.get(3)?,
                 },
                 category_id,
+                parent_id,
             ))
         })?;
         for result in item_nodes {
-            let (node, category_id) = result?;
-            // Add item→category edge
-            if let Some(cat_id) = category_id {
+            let (node, category_id, parent_id) = result?;
+            // Sub-items hang off their parent rather than the category
+            if let Some(parent_id) = parent_id {
+                edges.push(GraphEdge {
+                    source: format!("item-{}", node.id),
+                    target: format!("item-{}", parent_id),
+                });
+            } else if let Some(cat_id) = category_id {
+                // Add item→category edge
                 edges.push(GraphEdge {
                     source: format!("item-{}", node.id),
                     target: format!("category-{}", cat_id),
@@ -0,0 +1,518 @@
+use std::collections::HashMap;
+
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use serde_json::{Map, Number, Value};
+
+use crate::models::{JournalEntry, TableImage};
+
+/// How a synced table keeps a row that a restore removes, so the next
+/// sync sends the removal instead of pulling the row back
+#[derive(Clone, Copy)]
+enum Removal {
+    /// Kept with `sync_status = 'deleted'` until its remote delete
+    Tombstone,
+    /// Kept with a zero count, which syncs as the newer edit
+    EmptyCount,
+}
+
+struct SyncedTable {
+    name: &'static str,
+    has_updated_at: bool,
+    /// Rows without a sync id were never pushed and can simply go
+    has_sync_id: bool,
+    removal: Removal,
+}
+
+const SYNCED_TABLES: [SyncedTable; 8] = [
+    SyncedTable {
+        name: "todos",
+        has_updated_at: true,
+        has_sync_id: true,
+        removal: Removal::Tombstone,
+    },
+    SyncedTable {
+        name: "categories",
+        has_updated_at: true,
+        has_sync_id: true,
+        removal: Removal::Tombstone,
+    },
+    SyncedTable {
+        name: "tags",
+        has_updated_at: true,
+        has_sync_id: true,
+        removal: Removal::Tombstone,
+    },
+    SyncedTable {
+        name: "todo_tags",
+        has_updated_at: false,
+        has_sync_id: true,
+        removal: Removal::Tombstone,
+    },
+    SyncedTable {
+        name: "completion_logs",
+        has_updated_at: true,
+        has_sync_id: false,
+        removal: Removal::EmptyCount,
+    },
+    SyncedTable {
+        name: "measurements",
+        has_updated_at: false,
+        has_sync_id: true,
+        removal: Removal::Tombstone,
+    },
+    SyncedTable {
+        name: "skip_logs",
+        has_updated_at: false,
+        has_sync_id: false,
+        removal: Removal::Tombstone,
+    },
+    SyncedTable {
+        name: "schedule_versions",
+        has_updated_at: false,
+        has_sync_id: false,
+        removal: Removal::Tombstone,
+    },
+];
+
+/// Columns sync rewrites on its own; they don't make two rows differ
+const SYNC_COLUMNS: [&str; 3] = ["sync_id", "sync_status", "updated_at"];
+
+pub struct JournalRepository;
+
+impl JournalRepository {
+    /// Image of the rows of `table` whose `column` is in `ids`
+    pub fn capture(
+        conn: &Connection,
+        table: &str,
+        column: &str,
+        ids: &[i64],
+    ) -> Result<TableImage, rusqlite::Error> {
+        let mut image = TableImage {
+            table: table.to_string(),
+            column: column.to_string(),
+            ids: ids.to_vec(),
+            rows: Vec::new(),
+        };
+        if ids.is_empty() {
+            return Ok(image);
+        }
+
+        let sql = format!(
+            "SELECT * FROM {table} WHERE {column} IN ({})",
+            Self::placeholders(ids.len())
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let names: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+        image.rows = stmt
+            .query_map(params_from_iter(ids), |row| {
+                let mut values = Map::new();
+                for (index, name) in names.iter().enumerate() {
+                    values.insert(name.clone(), Self::to_json(row.get_ref(index)?));
+                }
+                Ok(values)
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(image)
+    }
+
+    /// Highest row id of `table`, 0 when it is empty
+    pub fn max_id(conn: &Connection, table: &str) -> Result<i64, rusqlite::Error> {
+        conn.query_row(
+            &format!("SELECT COALESCE(MAX(id), 0) FROM {table}"),
+            [],
+            |row| row.get(0),
+        )
+    }
+
+    /// Ids of the rows of `table` inserted after `id`
+    pub fn ids_after(conn: &Connection, table: &str, id: i64) -> Result<Vec<i64>, rusqlite::Error> {
+        let mut stmt =
+            conn.prepare(&format!("SELECT id FROM {table} WHERE id > ?1 ORDER BY id"))?;
+        let ids = stmt
+            .query_map(params![id], |row| row.get(0))?
+            .collect::<Result<Vec<i64>, _>>()?;
+        Ok(ids)
+    }
+
+    /// Replace the rows covered by `images` with the rows in them. Sync ids
+    /// assigned since the images were taken are kept, restored rows that
+    /// aren't deleted are marked pending so the change syncs, and synced
+    /// rows missing from the images stay behind as removals to push.
+    pub fn restore(conn: &Connection, images: &[TableImage]) -> Result<(), rusqlite::Error> {
+        let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        let mut restored = Vec::with_capacity(images.len());
+        let mut removed = Vec::new();
+        for image in images {
+            let current = Self::capture(conn, &image.table, &image.column, &image.ids)?;
+            let image = Self::keep_sync_ids(image, &current);
+            removed.push(Self::removed_rows(&image, &current, &now));
+            restored.push(image);
+        }
+
+        // Images may overlap (item tags and tag links), so clear them all first
+        for image in &restored {
+            conn.execute(
+                &format!(
+                    "DELETE FROM {} WHERE {} IN ({})",
+                    image.table,
+                    image.column,
+                    Self::placeholders(image.ids.len())
+                ),
+                params_from_iter(&image.ids),
+            )?;
+        }
+        // Removals first, so a row that another image restores wins
+        for image in removed.iter().chain(&restored) {
+            for row in &image.rows {
+                Self::insert_row(conn, &image.table, row)?;
+            }
+        }
+
+        for image in &restored {
+            let Some(synced) = Self::synced_table(&image.table) else {
+                continue;
+            };
+            let mut values = vec![SqlValue::Text(now.clone())];
+            values.extend(image.ids.iter().map(|id| SqlValue::Integer(*id)));
+            let set_updated_at = if synced.has_updated_at {
+                ", updated_at = ?1"
+            } else {
+                ""
+            };
+            conn.execute(
+                &format!(
+                    "UPDATE {} SET sync_status = 'pending'{set_updated_at}
+                     WHERE {} IN ({})
+                       AND (sync_status != 'deleted' OR sync_status IS NULL)",
+                    image.table,
+                    image.column,
+                    Self::numbered_placeholders(2, image.ids.len())
+                ),
+                params_from_iter(values),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn insert_row(
+        conn: &Connection,
+        table: &str,
+        row: &Map<String, Value>,
+    ) -> Result<(), rusqlite::Error> {
+        let columns: Vec<&str> = row.keys().map(String::as_str).collect();
+        let sql = format!(
+            "INSERT OR REPLACE INTO {table} ({}) VALUES ({})",
+            columns.join(", "),
+            Self::placeholders(columns.len())
+        );
+        conn.execute(&sql, params_from_iter(row.values().map(Self::to_sql)))?;
+        Ok(())
+    }
+
+    fn synced_table(table: &str) -> Option<&'static SyncedTable> {
+        SYNCED_TABLES.iter().find(|synced| synced.name == table)
+    }
+
+    /// Rows of `current` that `image` doesn't have, as they must stay
+    /// until sync has sent their removal. Empty for tables that don't sync.
+    fn removed_rows(image: &TableImage, current: &TableImage, now: &str) -> TableImage {
+        let mut removed = TableImage {
+            rows: Vec::new(),
+            ..image.clone()
+        };
+        let Some(synced) = Self::synced_table(&image.table) else {
+            return removed;
+        };
+
+        let kept: Vec<Vec<Value>> = image
+            .rows
+            .iter()
+            .map(|row| Self::identity(&image.table, row))
+            .collect();
+        for row in &current.rows {
+            if kept.contains(&Self::identity(&image.table, row)) {
+                continue;
+            }
+            if synced.has_sync_id && row.get("sync_id").is_none_or(Value::is_null) {
+                continue;
+            }
+
+            let mut row = row.clone();
+            match synced.removal {
+                Removal::Tombstone => {
+                    row.insert("sync_status".to_string(), "deleted".into());
+                    // Out of the trash too: the row only waits for its remote delete
+                    if row.contains_key("deleted_at") {
+                        row.insert("deleted_at".to_string(), Value::Null);
+                    }
+                }
+                Removal::EmptyCount => {
+                    row.insert("completed_count".to_string(), 0.into());
+                    row.insert("sync_status".to_string(), "pending".into());
+                }
+            }
+            if synced.has_updated_at {
+                row.insert("updated_at".to_string(), now.into());
+            }
+            removed.rows.push(row);
+        }
+        removed
+    }
+
+    /// Whether a row only waits for sync to send its removal
+    fn is_removal(table: &str, row: &Map<String, Value>) -> bool {
+        match Self::synced_table(table).map(|synced| synced.removal) {
+            Some(Removal::Tombstone) => {
+                row.get("sync_status").and_then(Value::as_str) == Some("deleted")
+                    && row.get("deleted_at").is_none_or(Value::is_null)
+            }
+            Some(Removal::EmptyCount) => {
+                row.get("completed_count").and_then(Value::as_i64) == Some(0)
+            }
+            None => false,
+        }
+    }
+
+    /// Whether two images of the same rows hold the same data, leaving out
+    /// what sync changes on its own
+    pub fn same_rows(a: &[TableImage], b: &[TableImage]) -> bool {
+        let live = |image: &TableImage| -> Vec<String> {
+            let mut rows: Vec<String> = image
+                .rows
+                .iter()
+                .filter(|row| !Self::is_removal(&image.table, row))
+                .map(|row| {
+                    let mut row = row.clone();
+                    for column in SYNC_COLUMNS {
+                        row.remove(column);
+                    }
+                    Value::Object(row).to_string()
+                })
+                .collect();
+            rows.sort();
+            rows
+        };
+        a.len() == b.len()
+            && a.iter()
+                .zip(b)
+                .all(|(a, b)| a.table == b.table && live(a) == live(b))
+    }
+
+    /// `image` with the sync ids the rows have in `current` filled in
+    fn keep_sync_ids(image: &TableImage, current: &TableImage) -> TableImage {
+        let key = |row: &Map<String, Value>| Self::identity(&image.table, row);
+        let sync_ids: HashMap<String, Value> = current
+            .rows
+            .iter()
+            .filter_map(|row| match row.get("sync_id") {
+                Some(sync_id) if !sync_id.is_null() => {
+                    Some((Value::Array(key(row)).to_string(), sync_id.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut image = image.clone();
+        for row in &mut image.rows {
+            if !row.get("sync_id").is_some_and(Value::is_null) {
+                continue;
+            }
+            if let Some(sync_id) = sync_ids.get(&Value::Array(key(row)).to_string()) {
+                row.insert("sync_id".to_string(), sync_id.clone());
+            }
+        }
+        image
+    }
+
+    /// Values that tell the rows of `table` apart
+    fn identity(table: &str, row: &Map<String, Value>) -> Vec<Value> {
+        let columns: &[&str] = match table {
+            "todo_tags" => &["todo_id", "tag_id"],
+            "completion_logs" => &["item_id", "completed_on"],
+            "skip_logs" => &["item_id", "skipped_on"],
+            "streak_freezes" => &["item_id", "frozen_on"],
+            "schedule_versions" => &["item_id", "effective_from"],
+            _ => &["id"],
+        };
+        columns
+            .iter()
+            .map(|column| row.get(*column).cloned().unwrap_or(Value::Null))
+            .collect()
+    }
+
+    fn placeholders(count: usize) -> String {
+        Self::numbered_placeholders(1, count)
+    }
+
+    fn numbered_placeholders(first: usize, count: usize) -> String {
+        (first..first + count)
+            .map(|index| format!("?{index}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn to_json(value: ValueRef) -> Value {
+        match value {
+            ValueRef::Null | ValueRef::Blob(_) => Value::Null,
+            ValueRef::Integer(i) => Value::from(i),
+            ValueRef::Real(f) => Number::from_f64(f).map_or(Value::Null, Value::Number),
+            ValueRef::Text(text) => Value::String(String::from_utf8_lossy(text).into_owned()),
+        }
+    }
+
+    fn to_sql(value: &Value) -> SqlValue {
+        match value {
+            Value::Bool(b) => SqlValue::Integer(*b as i64),
+            Value::Number(n) => n
+                .as_i64()
+                .map(SqlValue::Integer)
+                .or_else(|| n.as_f64().map(SqlValue::Real))
+                .unwrap_or(SqlValue::Null),
+            Value::String(s) => SqlValue::Text(s.clone()),
+            Value::Null | Value::Array(_) | Value::Object(_) => SqlValue::Null,
+        }
+    }
+
+    /// Record an operation. Anything undone is dropped, since it can't be
+    /// redone once something new happened, and only the newest `keep`
+    /// entries are kept.
+    pub fn push(
+        conn: &Connection,
+        label: &str,
+        before: &[TableImage],
+        after: &[TableImage],
+        keep: i64,
+    ) -> Result<(), rusqlite::Error> {
+        let before = serde_json::to_string(before).unwrap_or_else(|_| "[]".to_string());
+        let after = serde_json::to_string(after).unwrap_or_else(|_| "[]".to_string());
+        let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+
+        Self::delete_undone(conn)?;
+        conn.execute(
+            "INSERT INTO operation_journal (label, before_image, after_image, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![label, before, after, now],
+        )?;
+        conn.execute(
+            "DELETE FROM operation_journal WHERE id NOT IN
+             (SELECT id FROM operation_journal ORDER BY id DESC LIMIT ?1)",
+            params![keep],
+        )?;
+        Ok(())
+    }
+
+    /// The newest entry that can be undone
+    pub fn last_done(conn: &Connection) -> Result<Option<JournalEntry>, rusqlite::Error> {
+        Self::get_entry(
+            conn,
+            "SELECT id, label, before_image, after_image FROM operation_journal
+             WHERE undone = 0 ORDER BY id DESC LIMIT 1",
+        )
+    }
+
+    /// The oldest undone entry, which is the next one to redo
+    pub fn first_undone(conn: &Connection) -> Result<Option<JournalEntry>, rusqlite::Error> {
+        Self::get_entry(
+            conn,
+            "SELECT id, label, before_image, after_image FROM operation_journal
+             WHERE undone = 1 ORDER BY id ASC LIMIT 1",
+        )
+    }
+
+    fn get_entry(conn: &Connection, sql: &str) -> Result<Option<JournalEntry>, rusqlite::Error> {
+        conn.query_row(sql, [], |row| {
+            let before: String = row.get(2)?;
+            let after: String = row.get(3)?;
+            Ok(JournalEntry {
+                id: row.get(0)?,
+                label: row.get(1)?,
+                before: serde_json::from_str(&before).unwrap_or_default(),
+                after: serde_json::from_str(&after).unwrap_or_default(),
+            })
+        })
+        .optional()
+    }
+
+    pub fn delete(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        conn.execute("DELETE FROM operation_journal WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    /// Drop everything that could be redone
+    pub fn delete_undone(conn: &Connection) -> Result<(), rusqlite::Error> {
+        conn.execute("DELETE FROM operation_journal WHERE undone = 1", [])?;
+        Ok(())
+    }
+
+    /// Forget all operations, once their images no longer match the rows
+    pub fn clear(conn: &Connection) -> Result<(), rusqlite::Error> {
+        conn.execute("DELETE FROM operation_journal", [])?;
+        Ok(())
+    }
+
+    pub fn set_undone(conn: &Connection, id: i64, undone: bool) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE operation_journal SET undone = ?1 WHERE id = ?2",
+            params![undone as i32, id],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(values: &[(&str, Value)]) -> Map<String, Value> {
+        values
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn numbered_placeholders_start_at_first() {
+        assert_eq!(JournalRepository::placeholders(3), "?1, ?2, ?3");
+        assert_eq!(JournalRepository::numbered_placeholders(2, 2), "?2, ?3");
+    }
+
+    #[test]
+    fn keep_sync_ids_fills_only_missing_ids() {
+        let image = TableImage {
+            table: "todo_tags".to_string(),
+            column: "todo_id".to_string(),
+            ids: vec![1],
+            rows: vec![
+                row(&[
+                    ("todo_id", 1.into()),
+                    ("tag_id", 2.into()),
+                    ("sync_id", Value::Null),
+                ]),
+                row(&[
+                    ("todo_id", 1.into()),
+                    ("tag_id", 3.into()),
+                    ("sync_id", "old".into()),
+                ]),
+            ],
+        };
+        let current = TableImage {
+            rows: vec![
+                row(&[
+                    ("todo_id", 1.into()),
+                    ("tag_id", 2.into()),
+                    ("sync_id", "a".into()),
+                ]),
+                row(&[
+                    ("todo_id", 1.into()),
+                    ("tag_id", 3.into()),
+                    ("sync_id", "b".into()),
+                ]),
+            ],
+            ..image.clone()
+        };
+
+        let kept = JournalRepository::keep_sync_ids(&image, &current);
+        assert_eq!(kept.rows[0]["sync_id"], Value::from("a"));
+        assert_eq!(kept.rows[1]["sync_id"], Value::from("old"));
+    }
+}
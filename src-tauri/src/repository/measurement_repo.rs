@@ -0,0 +1,141 @@
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::models::{Measurement, SyncStatus};
+
+const SELECT_COLUMNS: &str =
+    "id, item_id, recorded_on, recorded_at, value, unit, sync_id, sync_status";
+
+pub struct MeasurementRepository;
+
+impl MeasurementRepository {
+    fn row_to_measurement(row: &rusqlite::Row) -> Result<Measurement, rusqlite::Error> {
+        let status: String = row.get(7)?;
+        Ok(Measurement {
+            id: row.get(0)?,
+            item_id: row.get(1)?,
+            recorded_on: row.get(2)?,
+            recorded_at: row.get(3)?,
+            value: row.get(4)?,
+            unit: row.get(5)?,
+            sync_id: row.get(6)?,
+            sync_status: SyncStatus::from_str(&status),
+        })
+    }
+
+    /// Record the value of one completion
+    pub fn add(
+        conn: &Connection,
+        item_id: i64,
+        recorded_on: &str,
+        recorded_at: &str,
+        value: f64,
+        unit: Option<&str>,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT INTO measurements (item_id, recorded_on, recorded_at, value, unit, sync_status)
+             VALUES (?1, ?2, ?3, ?4, ?5, 'pending')",
+            params![item_id, recorded_on, recorded_at, value, unit],
+        )?;
+        Ok(())
+    }
+
+    /// Drop the latest value recorded at or after `since`, when the
+    /// completion it came with is undone. Synced rows wait for their
+    /// remote delete.
+    pub fn remove_latest_since(
+        conn: &Connection,
+        item_id: i64,
+        since: &str,
+    ) -> Result<(), rusqlite::Error> {
+        let latest: Option<(i64, Option<String>)> = conn
+            .query_row(
+                "SELECT id, sync_id FROM measurements
+                 WHERE item_id = ?1 AND recorded_at >= ?2 AND sync_status != 'deleted'
+                 ORDER BY recorded_at DESC, id DESC
+                 LIMIT 1",
+                params![item_id, since],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        match latest {
+            Some((id, None)) => Self::delete(conn, id),
+            Some((id, Some(_))) => {
+                conn.execute(
+                    "UPDATE measurements SET sync_status = 'deleted' WHERE id = ?1",
+                    params![id],
+                )?;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Values of an item, oldest first. `since` limits them to logical
+    /// dates on or after it.
+    pub fn get_for_item(
+        conn: &Connection,
+        item_id: i64,
+        since: Option<&str>,
+    ) -> Result<Vec<Measurement>, rusqlite::Error> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SELECT_COLUMNS} FROM measurements
+             WHERE item_id = ?1 AND sync_status != 'deleted'
+             AND (?2 IS NULL OR recorded_on >= ?2)
+             ORDER BY recorded_at ASC, id ASC"
+        ))?;
+
+        let measurements = stmt
+            .query_map(params![item_id, since], Self::row_to_measurement)?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(measurements)
+    }
+
+    /// All values, removed ones included (for sync)
+    pub fn get_all(conn: &Connection) -> Result<Vec<Measurement>, rusqlite::Error> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {SELECT_COLUMNS} FROM measurements ORDER BY id"
+        ))?;
+
+        let measurements = stmt
+            .query_map([], Self::row_to_measurement)?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(measurements)
+    }
+
+    /// Insert a value pulled from the server unless it is known already
+    pub fn insert_synced(
+        conn: &Connection,
+        item_id: i64,
+        recorded_on: &str,
+        recorded_at: &str,
+        value: f64,
+        unit: Option<&str>,
+        sync_id: &str,
+    ) -> Result<bool, rusqlite::Error> {
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO measurements
+                (item_id, recorded_on, recorded_at, value, unit, sync_id, sync_status)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'synced')",
+            params![item_id, recorded_on, recorded_at, value, unit, sync_id],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    pub fn update_sync_id(conn: &Connection, id: i64, sync_id: &str) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE measurements SET sync_id = ?1, sync_status = 'synced' WHERE id = ?2",
+            params![sync_id, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn delete(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        conn.execute("DELETE FROM measurements WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+}
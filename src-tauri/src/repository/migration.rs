@@ -16,6 +16,25 @@ pub fn run_migrations(conn: &Connection) -> Result<(), rusqlite::Error> {
     migrate_create_tags(conn)?;
     migrate_create_todo_tags(conn)?;
     migrate_add_linked_app(conn)?;
+    migrate_add_repeat_interval(conn)?;
+    migrate_add_repeat_end(conn)?;
+    migrate_create_skip_logs(conn)?;
+    migrate_create_occurrences(conn)?;
+    migrate_add_setting_sync(conn)?;
+    migrate_add_category_reset_policy(conn)?;
+    migrate_create_exclusion_calendars(conn)?;
+    migrate_add_due_times(conn)?;
+    migrate_create_streak_freezes(conn)?;
+    migrate_add_goals(conn)?;
+    migrate_add_quantity(conn)?;
+    migrate_create_measurements(conn)?;
+    migrate_add_completion_log_sync(conn)?;
+    migrate_create_schedule_versions(conn)?;
+    migrate_add_stats_indexes(conn)?;
+    migrate_add_subtasks(conn)?;
+    migrate_create_templates(conn)?;
+    migrate_add_trash(conn)?;
+    migrate_create_operation_journal(conn)?;
     Ok(())
 }
 
@@ -88,7 +107,7 @@ fn migrate_add_repeat_columns(conn: &Connection) -> Result<(), rusqlite::Error>
         )?;
     }
 
-    // Add repeat_detail column (JSON: weekly [0,3,5], monthly [1,15])
+    // Add repeat_detail column (JSON: weekly [0,3,5], monthly [1,15], yearly [month, day])
     if should_add_column(conn, "todos", "repeat_detail") {
         conn.execute("ALTER TABLE todos ADD COLUMN repeat_detail TEXT", [])?;
     }
@@ -291,3 +310,433 @@ fn migrate_create_todo_tags(conn: &Connection) -> Result<(), rusqlite::Error> {
     )?;
     Ok(())
 }
+
+fn migrate_add_repeat_interval(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Add repeat_interval column (every N days/weeks/months/years)
+    if should_add_column(conn, "todos", "repeat_interval") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN repeat_interval INTEGER NOT NULL DEFAULT 1",
+            [],
+        )?;
+    }
+
+    // Add repeat_start column (YYYY-MM-DD, date intervals are counted from)
+    if should_add_column(conn, "todos", "repeat_start") {
+        conn.execute("ALTER TABLE todos ADD COLUMN repeat_start TEXT", [])?;
+    }
+
+    Ok(())
+}
+
+fn migrate_add_repeat_end(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Add repeat_until column (YYYY-MM-DD, last allowed occurrence)
+    if should_add_column(conn, "todos", "repeat_until") {
+        conn.execute("ALTER TABLE todos ADD COLUMN repeat_until TEXT", [])?;
+    }
+
+    // Add repeat_count column (series ends after this many completions)
+    if should_add_column(conn, "todos", "repeat_count") {
+        conn.execute("ALTER TABLE todos ADD COLUMN repeat_count INTEGER", [])?;
+    }
+
+    // Add repeat_completed column (completions counted towards repeat_count)
+    if should_add_column(conn, "todos", "repeat_completed") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN repeat_completed INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+
+    // Add repeat_ended column (series ran out of occurrences, so resets
+    // leave it done). Rows that already ended are recognised by their bounds.
+    if should_add_column(conn, "todos", "repeat_ended") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN repeat_ended INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+        conn.execute(
+            "UPDATE todos SET repeat_ended = 1
+             WHERE repeat_type != 'none' AND done = 1 AND next_due_at IS NULL
+               AND ((repeat_count IS NOT NULL AND repeat_completed >= repeat_count)
+                    OR repeat_until < date('now'))",
+            [],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn migrate_create_skip_logs(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Removed skips stay as 'deleted' until the remote delete went out
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS skip_logs (
+            item_id INTEGER NOT NULL,
+            skipped_on TEXT NOT NULL,
+            sync_status TEXT NOT NULL DEFAULT 'pending',
+            PRIMARY KEY (item_id, skipped_on)
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn migrate_create_occurrences(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // One row per scheduled slot: completed, missed or skipped
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS occurrences (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            item_id INTEGER NOT NULL,
+            scheduled_on TEXT NOT NULL,
+            status TEXT NOT NULL,
+            completed_at TEXT,
+            completed_on TEXT,
+            source TEXT,
+            UNIQUE (item_id, scheduled_on)
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn migrate_add_setting_sync(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Synced settings (the time zone) are marked pending when the user
+    // changes them; everything else stays local and counts as synced
+    if should_add_column(conn, "settings", "updated_at") {
+        conn.execute("ALTER TABLE settings ADD COLUMN updated_at TEXT", [])?;
+    }
+    if should_add_column(conn, "settings", "sync_status") {
+        conn.execute(
+            "ALTER TABLE settings ADD COLUMN sync_status TEXT NOT NULL DEFAULT 'synced'",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+fn migrate_add_category_reset_policy(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Add reset_policy column (global, daily, weekdays, never)
+    if should_add_column(conn, "categories", "reset_policy") {
+        conn.execute(
+            "ALTER TABLE categories ADD COLUMN reset_policy TEXT NOT NULL DEFAULT 'global'",
+            [],
+        )?;
+    }
+
+    // Add reset_times column (JSON array of "HH:MM")
+    if should_add_column(conn, "categories", "reset_times") {
+        conn.execute("ALTER TABLE categories ADD COLUMN reset_times TEXT", [])?;
+    }
+
+    // Add reset_weekdays column (JSON array of weekdays, 0=Sunday)
+    if should_add_column(conn, "categories", "reset_weekdays") {
+        conn.execute("ALTER TABLE categories ADD COLUMN reset_weekdays TEXT", [])?;
+    }
+
+    // Add last_reset_at column, seeded from the global reset so existing
+    // categories don't reset twice on the day of the upgrade
+    if should_add_column(conn, "categories", "last_reset_at") {
+        conn.execute("ALTER TABLE categories ADD COLUMN last_reset_at TEXT", [])?;
+        conn.execute(
+            "UPDATE categories SET last_reset_at =
+                (SELECT value FROM settings WHERE key = 'last_reset_date') || 'T' ||
+                COALESCE((SELECT value FROM settings WHERE key = 'reset_time'), '00:00')",
+            [],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn migrate_create_exclusion_calendars(conn: &Connection) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS exclusion_calendars (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            created_at TEXT
+        )",
+        [],
+    )?;
+
+    // Single days are stored with start_date = end_date
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS exclusion_ranges (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            calendar_id INTEGER NOT NULL,
+            start_date TEXT NOT NULL,
+            end_date TEXT NOT NULL,
+            label TEXT
+        )",
+        [],
+    )?;
+
+    // Add exclusion_calendar_id column to todos
+    if should_add_column(conn, "todos", "exclusion_calendar_id") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN exclusion_calendar_id INTEGER",
+            [],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn migrate_add_due_times(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Add due_at column (YYYY-MM-DDTHH:MM of the current or upcoming slot)
+    if should_add_column(conn, "todos", "due_at") {
+        conn.execute("ALTER TABLE todos ADD COLUMN due_at TEXT", [])?;
+    }
+
+    // Add due_times column (JSON array of "HH:MM" slots per scheduled day)
+    if should_add_column(conn, "todos", "due_times") {
+        conn.execute("ALTER TABLE todos ADD COLUMN due_times TEXT", [])?;
+    }
+
+    Ok(())
+}
+
+fn migrate_create_streak_freezes(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Missed slots bridged by a freeze or grace day (kind: freeze | grace)
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS streak_freezes (
+            item_id INTEGER NOT NULL,
+            frozen_on TEXT NOT NULL,
+            kind TEXT NOT NULL DEFAULT 'freeze',
+            sync_status TEXT NOT NULL DEFAULT 'pending',
+            PRIMARY KEY (item_id, frozen_on)
+        )",
+        [],
+    )?;
+
+    if should_add_column(conn, "todos", "streak_freezes_per_month") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN streak_freezes_per_month INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+
+    if should_add_column(conn, "todos", "streak_grace_per_week") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN streak_grace_per_week INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn migrate_add_goals(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Frequency goal period: none | week | month
+    if should_add_column(conn, "todos", "goal_period") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN goal_period TEXT NOT NULL DEFAULT 'none'",
+            [],
+        )?;
+    }
+
+    // Completions wanted per period
+    if should_add_column(conn, "todos", "goal_target") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN goal_target INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn migrate_add_quantity(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Completions needed before the item counts as done (0 = plain item)
+    if should_add_column(conn, "todos", "quantity_target") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN quantity_target INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+
+    // Progress in the current slot, cleared when the item comes back
+    if should_add_column(conn, "todos", "quantity_progress") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN quantity_progress INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn migrate_create_measurements(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Measurement recorded with a completion (72.4 kg, 30 pages), one per
+    // completion
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS measurements (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            item_id INTEGER NOT NULL,
+            recorded_on TEXT NOT NULL,
+            recorded_at TEXT NOT NULL,
+            value REAL NOT NULL,
+            unit TEXT,
+            sync_id TEXT UNIQUE,
+            sync_status TEXT NOT NULL DEFAULT 'pending'
+        )",
+        [],
+    )?;
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_measurements_item ON measurements (item_id, recorded_on)",
+        [],
+    )?;
+    Ok(())
+}
+
+fn migrate_add_completion_log_sync(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Logs carry their change time so the later edit wins across devices,
+    // including the removal of a completion
+    if should_add_column(conn, "completion_logs", "updated_at") {
+        conn.execute("ALTER TABLE completion_logs ADD COLUMN updated_at TEXT", [])?;
+    }
+    if should_add_column(conn, "completion_logs", "sync_status") {
+        conn.execute(
+            "ALTER TABLE completion_logs ADD COLUMN sync_status TEXT NOT NULL DEFAULT 'pending'",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+fn migrate_create_schedule_versions(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Repeat settings of an item from each change on, so streaks judge
+    // every date by the rule active then. Versions sync with their item.
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS schedule_versions (
+            item_id INTEGER NOT NULL,
+            effective_from TEXT NOT NULL,
+            repeat_type TEXT NOT NULL DEFAULT 'none',
+            repeat_detail TEXT,
+            repeat_interval INTEGER NOT NULL DEFAULT 1,
+            repeat_start TEXT,
+            repeat_until TEXT,
+            repeat_count INTEGER,
+            sync_status TEXT NOT NULL DEFAULT 'pending',
+            PRIMARY KEY (item_id, effective_from)
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn migrate_add_stats_indexes(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Stats filter by date across all items, which the (item_id, date)
+    // keys can't serve
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_occurrences_scheduled_on ON occurrences (scheduled_on)",
+        [],
+    )?;
+    // Logged completions are matched to ledger rows by completion day
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_occurrences_completed_on ON occurrences (item_id, completed_on)",
+        [],
+    )?;
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_completion_logs_completed_on ON completion_logs (completed_on)",
+        [],
+    )?;
+
+    // Local time of the day's latest completion, for completions that have
+    // no occurrence row (one-off items, other devices)
+    if should_add_column(conn, "completion_logs", "completed_at") {
+        conn.execute(
+            "ALTER TABLE completion_logs ADD COLUMN completed_at TEXT",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+fn migrate_add_subtasks(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Sub-items point at their parent todo
+    if should_add_column(conn, "todos", "parent_id") {
+        conn.execute("ALTER TABLE todos ADD COLUMN parent_id INTEGER", [])?;
+    }
+
+    // Parent completes itself once every sub-item is done
+    if should_add_column(conn, "todos", "auto_complete") {
+        conn.execute(
+            "ALTER TABLE todos ADD COLUMN auto_complete INTEGER NOT NULL DEFAULT 0",
+            [],
+        )?;
+    }
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_todos_parent_id ON todos (parent_id)",
+        [],
+    )?;
+    Ok(())
+}
+
+fn migrate_create_templates(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Checklist templates, synced like categories
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS checklist_templates (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            sync_id TEXT UNIQUE,
+            created_at TEXT,
+            updated_at TEXT,
+            sync_status TEXT DEFAULT 'pending'
+        )",
+        [],
+    )?;
+
+    // Template items in order; tags is a JSON array of tag names
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS checklist_template_items (
+            template_id INTEGER NOT NULL,
+            position INTEGER NOT NULL,
+            text TEXT NOT NULL,
+            memo TEXT,
+            repeat_type TEXT NOT NULL DEFAULT 'none',
+            repeat_detail TEXT,
+            repeat_interval INTEGER NOT NULL DEFAULT 1,
+            repeat_start TEXT,
+            repeat_until TEXT,
+            repeat_count INTEGER,
+            due_times TEXT,
+            reminder_at TEXT,
+            tags TEXT NOT NULL DEFAULT '[]',
+            parent_position INTEGER,
+            PRIMARY KEY (template_id, position)
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
+fn migrate_add_trash(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // When a row went to the trash; NULL for live rows and for rows
+    // purged from the trash that wait for their remote delete
+    for table in ["todos", "categories", "tags"] {
+        if should_add_column(conn, table, "deleted_at") {
+            conn.execute(
+                &format!("ALTER TABLE {table} ADD COLUMN deleted_at TEXT"),
+                [],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn migrate_create_operation_journal(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // Undo/redo journal; images are JSON arrays of table images. Local only.
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS operation_journal (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            label TEXT NOT NULL,
+            before_image TEXT NOT NULL,
+            after_image TEXT NOT NULL,
+            undone INTEGER NOT NULL DEFAULT 0,
+            created_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+    Ok(())
+}
+
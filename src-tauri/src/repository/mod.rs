@@ -2,21 +2,43 @@ mod auth_repo;
 mod category_repo;
 mod completion_log_repo;
 mod database;
+mod exclusion_repo;
 mod graph_repo;
+mod journal_repo;
+mod measurement_repo;
 mod migration;
+mod occurrence_repo;
+mod schedule_version_repo;
 mod settings_repo;
+mod skip_log_repo;
+mod stats_repo;
+mod streak_freeze_repo;
 mod sync_repo;
 mod tag_repo;
+mod template_repo;
 mod todo_repo;
 mod todo_tag_repo;
+mod trash_repo;
 
 pub use auth_repo::AuthRepository;
 pub use category_repo::CategoryRepository;
 pub use completion_log_repo::CompletionLogRepository;
 pub use database::init_database;
+#[cfg(test)]
+pub use database::open_in_memory;
+pub use exclusion_repo::ExclusionRepository;
 pub use graph_repo::GraphRepository;
+pub use journal_repo::JournalRepository;
+pub use measurement_repo::MeasurementRepository;
+pub use occurrence_repo::OccurrenceRepository;
+pub use schedule_version_repo::ScheduleVersionRepository;
 pub use settings_repo::SettingsRepository;
+pub use skip_log_repo::SkipLogRepository;
+pub use stats_repo::StatsRepository;
+pub use streak_freeze_repo::StreakFreezeRepository;
 pub use sync_repo::SyncRepository;
 pub use tag_repo::TagRepository;
+pub use template_repo::TemplateRepository;
 pub use todo_repo::TodoRepository;
 pub use todo_tag_repo::TodoTagRepository;
+pub use trash_repo::TrashRepository;
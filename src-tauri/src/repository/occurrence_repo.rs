@@ -0,0 +1,107 @@
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::models::{Occurrence, OccurrenceSource, OccurrenceStatus};
+
+pub struct OccurrenceRepository;
+
+impl OccurrenceRepository {
+    fn row_to_occurrence(row: &rusqlite::Row) -> Result<Occurrence, rusqlite::Error> {
+        let status_str: String = row.get(3)?;
+        let source_str: Option<String> = row.get(5)?;
+        Ok(Occurrence {
+            id: row.get(0)?,
+            item_id: row.get(1)?,
+            scheduled_on: row.get(2)?,
+            status: OccurrenceStatus::from_str(&status_str),
+            completed_at: row.get(4)?,
+            source: source_str.map(|s| OccurrenceSource::from_str(&s)),
+        })
+    }
+
+    /// Record the outcome of a slot. A later completion or skip replaces an
+    /// earlier "missed" entry for the same slot.
+    pub fn record(
+        conn: &Connection,
+        item_id: i64,
+        scheduled_on: &str,
+        status: OccurrenceStatus,
+        completed_at: Option<&str>,
+        source: Option<OccurrenceSource>,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT INTO occurrences (item_id, scheduled_on, status, completed_at, completed_on, source)
+             VALUES (?1, ?2, ?3, ?4, substr(?4, 1, 10), ?5)
+             ON CONFLICT(item_id, scheduled_on) DO UPDATE SET
+                status = excluded.status,
+                completed_at = excluded.completed_at,
+                completed_on = excluded.completed_on,
+                source = excluded.source",
+            params![
+                item_id,
+                scheduled_on,
+                status.to_str(),
+                completed_at,
+                source.map(|s| s.to_str())
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Record a missed slot unless something was already recorded for it
+    pub fn record_missed(
+        conn: &Connection,
+        item_id: i64,
+        scheduled_on: &str,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT OR IGNORE INTO occurrences (item_id, scheduled_on, status)
+             VALUES (?1, ?2, 'missed')",
+            params![item_id, scheduled_on],
+        )?;
+        Ok(())
+    }
+
+    /// Drop the most recent completion of an item (when it is unchecked).
+    /// Returns the slot the removed completion was recorded for
+    pub fn remove_latest_completed(
+        conn: &Connection,
+        item_id: i64,
+    ) -> Result<Option<String>, rusqlite::Error> {
+        let latest: Option<(i64, String)> = conn
+            .query_row(
+                "SELECT id, scheduled_on FROM occurrences
+                 WHERE item_id = ?1 AND status = 'completed'
+                 ORDER BY completed_at DESC, id DESC
+                 LIMIT 1",
+                params![item_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        let Some((id, scheduled_on)) = latest else {
+            return Ok(None);
+        };
+        conn.execute("DELETE FROM occurrences WHERE id = ?1", params![id])?;
+        Ok(Some(scheduled_on))
+    }
+
+    /// Get the occurrence ledger of an item from `since` (a logical date) on
+    pub fn get_for_item(
+        conn: &Connection,
+        item_id: i64,
+        since: &str,
+    ) -> Result<Vec<Occurrence>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT id, item_id, scheduled_on, status, completed_at, source FROM occurrences
+             WHERE item_id = ?1 AND scheduled_on >= ?2
+             ORDER BY scheduled_on ASC",
+        )?;
+
+        let occurrences = stmt
+            .query_map(params![item_id, since], Self::row_to_occurrence)?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(occurrences)
+    }
+}
@@ -0,0 +1,213 @@
+use rusqlite::{params, Connection};
+
+use crate::models::{RepeatSchedule, RepeatType, ScheduleVersion, SyncStatus};
+
+pub struct ScheduleVersionRepository;
+
+impl ScheduleVersionRepository {
+    fn row_to_version(row: &rusqlite::Row) -> Result<ScheduleVersion, rusqlite::Error> {
+        let repeat_type: String = row.get(2)?;
+        Ok(ScheduleVersion {
+            item_id: row.get(0)?,
+            effective_from: row.get(1)?,
+            repeat_type: RepeatType::from_str(&repeat_type),
+            repeat_detail: row.get(3)?,
+            repeat_interval: row.get(4)?,
+            repeat_start: row.get(5)?,
+            repeat_until: row.get(6)?,
+            repeat_count: row.get(7)?,
+        })
+    }
+
+    /// Record the schedule an item follows from `effective_from` on.
+    /// A second change on the same day replaces that day's version.
+    pub fn record(
+        conn: &Connection,
+        item_id: i64,
+        effective_from: &str,
+        schedule: &RepeatSchedule,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT OR REPLACE INTO schedule_versions
+             (item_id, effective_from, repeat_type, repeat_detail, repeat_interval, repeat_start,
+              repeat_until, repeat_count, sync_status)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 'pending')",
+            params![
+                item_id,
+                effective_from,
+                schedule.repeat_type.to_str(),
+                schedule.repeat_detail,
+                schedule.repeat_interval,
+                schedule.repeat_start,
+                schedule.repeat_until,
+                schedule.repeat_count
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Versions of an item, oldest first
+    pub fn get_for_item(
+        conn: &Connection,
+        item_id: i64,
+    ) -> Result<Vec<ScheduleVersion>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT item_id, effective_from, repeat_type, repeat_detail, repeat_interval,
+                    repeat_start, repeat_until, repeat_count
+             FROM schedule_versions
+             WHERE item_id = ?1 AND sync_status != 'deleted'
+             ORDER BY effective_from ASC",
+        )?;
+
+        let versions = stmt
+            .query_map(params![item_id], Self::row_to_version)?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(versions)
+    }
+
+    /// Versions not pushed yet and removed ones waiting for their remote
+    /// delete, with their status (for sync)
+    pub fn get_pending(
+        conn: &Connection,
+    ) -> Result<Vec<(ScheduleVersion, SyncStatus)>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT item_id, effective_from, repeat_type, repeat_detail, repeat_interval,
+                    repeat_start, repeat_until, repeat_count, sync_status
+             FROM schedule_versions
+             WHERE sync_status IN ('pending', 'deleted')
+             ORDER BY item_id, effective_from",
+        )?;
+
+        let versions = stmt
+            .query_map([], |row| {
+                let status: String = row.get(8)?;
+                Ok((Self::row_to_version(row)?, SyncStatus::from_str(&status)))
+            })?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(versions)
+    }
+
+    /// Versions the server has (for spotting remote deletes)
+    pub fn get_synced(conn: &Connection) -> Result<Vec<ScheduleVersion>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT item_id, effective_from, repeat_type, repeat_detail, repeat_interval,
+                    repeat_start, repeat_until, repeat_count
+             FROM schedule_versions
+             WHERE sync_status = 'synced'",
+        )?;
+
+        let versions = stmt
+            .query_map([], Self::row_to_version)?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(versions)
+    }
+
+    pub fn mark_synced(
+        conn: &Connection,
+        item_id: i64,
+        effective_from: &str,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE schedule_versions SET sync_status = 'synced'
+             WHERE item_id = ?1 AND effective_from = ?2 AND sync_status = 'pending'",
+            params![item_id, effective_from],
+        )?;
+        Ok(())
+    }
+
+    /// Store a version pulled from the server. A local version of the same
+    /// day that has not been pushed or removed yet wins.
+    pub fn upsert_synced(
+        conn: &Connection,
+        version: &ScheduleVersion,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT INTO schedule_versions
+             (item_id, effective_from, repeat_type, repeat_detail, repeat_interval, repeat_start,
+              repeat_until, repeat_count, sync_status)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 'synced')
+             ON CONFLICT(item_id, effective_from) DO UPDATE SET
+                repeat_type = excluded.repeat_type,
+                repeat_detail = excluded.repeat_detail,
+                repeat_interval = excluded.repeat_interval,
+                repeat_start = excluded.repeat_start,
+                repeat_until = excluded.repeat_until,
+                repeat_count = excluded.repeat_count
+             WHERE schedule_versions.sync_status = 'synced'
+               AND (schedule_versions.repeat_type IS NOT excluded.repeat_type
+                    OR schedule_versions.repeat_detail IS NOT excluded.repeat_detail
+                    OR schedule_versions.repeat_interval IS NOT excluded.repeat_interval
+                    OR schedule_versions.repeat_start IS NOT excluded.repeat_start
+                    OR schedule_versions.repeat_until IS NOT excluded.repeat_until
+                    OR schedule_versions.repeat_count IS NOT excluded.repeat_count)",
+            params![
+                version.item_id,
+                version.effective_from,
+                version.repeat_type.to_str(),
+                version.repeat_detail,
+                version.repeat_interval,
+                version.repeat_start,
+                version.repeat_until,
+                version.repeat_count
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Delete a version for good (after its remote delete)
+    pub fn delete(
+        conn: &Connection,
+        item_id: i64,
+        effective_from: &str,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "DELETE FROM schedule_versions WHERE item_id = ?1 AND effective_from = ?2",
+            params![item_id, effective_from],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(repeat_interval: i32) -> ScheduleVersion {
+        ScheduleVersion {
+            item_id: 1,
+            effective_from: "2026-03-04".to_string(),
+            repeat_type: RepeatType::Daily,
+            repeat_detail: None,
+            repeat_interval,
+            repeat_start: Some("2026-03-04".to_string()),
+            repeat_until: None,
+            repeat_count: None,
+        }
+    }
+
+    #[test]
+    fn pulled_version_waits_for_local_push() {
+        let conn = crate::repository::open_in_memory();
+        ScheduleVersionRepository::record(&conn, 1, "2026-03-04", &version(2).schedule()).unwrap();
+
+        // The local change of the same day has not been pushed yet
+        ScheduleVersionRepository::upsert_synced(&conn, &version(3)).unwrap();
+        let pending = ScheduleVersionRepository::get_pending(&conn).unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].0.repeat_interval, 2);
+
+        ScheduleVersionRepository::mark_synced(&conn, 1, "2026-03-04").unwrap();
+        ScheduleVersionRepository::upsert_synced(&conn, &version(3)).unwrap();
+        let versions = ScheduleVersionRepository::get_for_item(&conn, 1).unwrap();
+        assert_eq!(versions[0].repeat_interval, 3);
+        assert!(ScheduleVersionRepository::get_pending(&conn)
+            .unwrap()
+            .is_empty());
+    }
+}
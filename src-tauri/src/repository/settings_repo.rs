@@ -1,4 +1,7 @@
-use rusqlite::{params, Connection};
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::models::SyncStatus;
 
 pub struct SettingsRepository;
 
@@ -24,4 +27,54 @@ impl SettingsRepository {
         )?;
         Ok(())
     }
+
+    /// Set a synced setting changed by the user; it goes out on the next sync
+    pub fn set_pending(conn: &Connection, key: &str, value: &str) -> Result<(), rusqlite::Error> {
+        let now = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        conn.execute(
+            "INSERT OR REPLACE INTO settings (key, value, updated_at, sync_status)
+             VALUES (?1, ?2, ?3, 'pending')",
+            params![key, value, now],
+        )?;
+        Ok(())
+    }
+
+    /// Set a setting pulled from the server
+    pub fn set_synced(
+        conn: &Connection,
+        key: &str,
+        value: &str,
+        updated_at: &str,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT OR REPLACE INTO settings (key, value, updated_at, sync_status)
+             VALUES (?1, ?2, ?3, 'synced')",
+            params![key, value, updated_at],
+        )?;
+        Ok(())
+    }
+
+    /// Value, update time and sync status of a setting
+    pub fn get_with_sync(
+        conn: &Connection,
+        key: &str,
+    ) -> Result<Option<(String, Option<String>, SyncStatus)>, rusqlite::Error> {
+        conn.query_row(
+            "SELECT value, updated_at, sync_status FROM settings WHERE key = ?1",
+            params![key],
+            |row| {
+                let status: String = row.get(2)?;
+                Ok((row.get(0)?, row.get(1)?, SyncStatus::from_str(&status)))
+            },
+        )
+        .optional()
+    }
+
+    pub fn mark_synced(conn: &Connection, key: &str) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE settings SET sync_status = 'synced' WHERE key = ?1",
+            params![key],
+        )?;
+        Ok(())
+    }
 }
@@ -0,0 +1,124 @@
+use rusqlite::{params, Connection};
+
+use crate::models::{SkipLog, SyncStatus};
+
+pub struct SkipLogRepository;
+
+impl SkipLogRepository {
+    /// Record a skipped occurrence (no-op if already recorded)
+    pub fn add(conn: &Connection, item_id: i64, date: &str) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT INTO skip_logs (item_id, skipped_on, sync_status) VALUES (?1, ?2, 'pending')
+             ON CONFLICT(item_id, skipped_on) DO UPDATE SET sync_status = 'pending'
+             WHERE sync_status = 'deleted'",
+            params![item_id, date],
+        )?;
+        Ok(())
+    }
+
+    /// Record a skip pulled from the server. A local removal that has not
+    /// been pushed yet wins.
+    pub fn add_synced(conn: &Connection, item_id: i64, date: &str) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT OR IGNORE INTO skip_logs (item_id, skipped_on, sync_status)
+             VALUES (?1, ?2, 'synced')",
+            params![item_id, date],
+        )?;
+        Ok(())
+    }
+
+    /// Remove a skip; the row is kept as deleted until the next sync
+    pub fn remove(conn: &Connection, item_id: i64, date: &str) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE skip_logs SET sync_status = 'deleted' WHERE item_id = ?1 AND skipped_on = ?2",
+            params![item_id, date],
+        )?;
+        Ok(())
+    }
+
+    /// Delete a skip row for good (after its remote delete)
+    pub fn purge(conn: &Connection, item_id: i64, date: &str) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "DELETE FROM skip_logs WHERE item_id = ?1 AND skipped_on = ?2",
+            params![item_id, date],
+        )?;
+        Ok(())
+    }
+
+    pub fn mark_synced(conn: &Connection, item_id: i64, date: &str) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE skip_logs SET sync_status = 'synced'
+             WHERE item_id = ?1 AND skipped_on = ?2 AND sync_status = 'pending'",
+            params![item_id, date],
+        )?;
+        Ok(())
+    }
+
+    /// Get skipped dates for a specific item (for streak calculation)
+    pub fn get_dates_for_item(
+        conn: &Connection,
+        item_id: i64,
+    ) -> Result<Vec<String>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT skipped_on FROM skip_logs
+             WHERE item_id = ?1 AND sync_status != 'deleted'
+             ORDER BY skipped_on ASC",
+        )?;
+
+        let dates = stmt
+            .query_map(params![item_id], |row| row.get(0))?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(dates)
+    }
+
+    /// Get all skip logs, removed ones included (for sync)
+    pub fn get_all(conn: &Connection) -> Result<Vec<SkipLog>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT item_id, skipped_on, sync_status FROM skip_logs
+             ORDER BY item_id, skipped_on",
+        )?;
+
+        let logs = stmt
+            .query_map([], |row| {
+                let status: String = row.get(2)?;
+                Ok(SkipLog {
+                    item_id: row.get(0)?,
+                    skipped_on: row.get(1)?,
+                    sync_status: SyncStatus::from_str(&status),
+                })
+            })?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(logs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removed_skip_waits_for_its_remote_delete() {
+        let conn = crate::repository::open_in_memory();
+        SkipLogRepository::add(&conn, 1, "2026-03-04").unwrap();
+        SkipLogRepository::mark_synced(&conn, 1, "2026-03-04").unwrap();
+
+        SkipLogRepository::remove(&conn, 1, "2026-03-04").unwrap();
+        // A pull must not bring it back before the delete is pushed
+        SkipLogRepository::add_synced(&conn, 1, "2026-03-04").unwrap();
+
+        assert!(SkipLogRepository::get_dates_for_item(&conn, 1)
+            .unwrap()
+            .is_empty());
+        let logs = SkipLogRepository::get_all(&conn).unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].sync_status, SyncStatus::Deleted);
+
+        SkipLogRepository::add(&conn, 1, "2026-03-04").unwrap();
+        let logs = SkipLogRepository::get_all(&conn).unwrap();
+        assert_eq!(logs[0].sync_status, SyncStatus::Pending);
+    }
+}
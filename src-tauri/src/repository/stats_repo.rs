@@ -0,0 +1,301 @@
+use rusqlite::{params, Connection};
+
+use crate::models::{CategoryTrend, MissedItem};
+
+/// Aggregate queries over the whole completion history. Grouping happens
+/// in SQLite so only a handful of rows come back however long the history.
+pub struct StatsRepository;
+
+/// Items that are not waiting for a sync delete
+const LIVE_ITEMS: &str =
+    "SELECT id FROM todos WHERE sync_status != 'deleted' OR sync_status IS NULL";
+
+/// Logged completions on days the occurrence ledger has no row for, such
+/// as one-off items and completions synced from another device. Each
+/// check has its own (item_id, day) index.
+const UNLEDGERED_LOGS: &str = "completion_logs l
+     WHERE l.completed_count > 0
+       AND NOT EXISTS (
+           SELECT 1 FROM occurrences o
+           WHERE o.item_id = l.item_id
+             AND o.scheduled_on BETWEEN l.completed_on AND l.completed_on || 'T99')
+       AND NOT EXISTS (
+           SELECT 1 FROM occurrences o
+           WHERE o.item_id = l.item_id AND o.completed_on = l.completed_on)";
+
+impl StatsRepository {
+    /// Completed and missed slots per weekday (0 = Sunday) from `since` on.
+    /// Days without ledger rows count their logged completions.
+    pub fn slots_by_weekday(
+        conn: &Connection,
+        since: &str,
+    ) -> Result<Vec<(u32, i64, i64)>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT CAST(strftime('%w', day) AS INTEGER) AS weekday,
+                    SUM(completed), SUM(missed)
+             FROM (
+                 SELECT substr(scheduled_on, 1, 10) AS day, item_id,
+                        status = 'completed' AS completed, status = 'missed' AS missed
+                 FROM occurrences
+                 WHERE scheduled_on >= ?1
+                 UNION ALL
+                 SELECT l.completed_on, l.item_id, l.completed_count, 0
+                 FROM {UNLEDGERED_LOGS} AND l.completed_on >= ?1
+             )
+             WHERE item_id IN ({LIVE_ITEMS})
+             GROUP BY weekday"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map(params![since], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })?
+            .filter_map(Result::ok)
+            .collect();
+        Ok(rows)
+    }
+
+    /// Completions per hour of day from `since` on. The hour is read from
+    /// the local timestamp stored with each completion; a day without
+    /// ledger rows counts once, at the time of its latest completion.
+    pub fn completions_by_hour(
+        conn: &Connection,
+        since: &str,
+    ) -> Result<Vec<(u32, i64)>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT CAST(substr(completed_at, 12, 2) AS INTEGER) AS hour, COUNT(*)
+             FROM (
+                 SELECT completed_at, item_id
+                 FROM occurrences
+                 WHERE status = 'completed' AND scheduled_on >= ?1
+                 UNION ALL
+                 SELECT l.completed_at, l.item_id
+                 FROM {UNLEDGERED_LOGS} AND l.completed_on >= ?1
+             )
+             WHERE completed_at IS NOT NULL AND item_id IN ({LIVE_ITEMS})
+             GROUP BY hour"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map(params![since], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .filter_map(Result::ok)
+            .collect();
+        Ok(rows)
+    }
+
+    /// Completion counts per category in four windows: the last 30 days
+    /// from `start_30`, the 30 before from `start_60`, the last 90 from
+    /// `start_90` and the 90 before from `start_180`. Changes are left unset.
+    pub fn category_completions(
+        conn: &Connection,
+        start_30: &str,
+        start_60: &str,
+        start_90: &str,
+        start_180: &str,
+    ) -> Result<Vec<CategoryTrend>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT t.category_id, c.name,
+                    SUM(CASE WHEN l.completed_on >= ?1 THEN l.completed_count ELSE 0 END),
+                    SUM(CASE WHEN l.completed_on >= ?2 AND l.completed_on < ?1
+                        THEN l.completed_count ELSE 0 END),
+                    SUM(CASE WHEN l.completed_on >= ?3 THEN l.completed_count ELSE 0 END),
+                    SUM(CASE WHEN l.completed_on < ?3 THEN l.completed_count ELSE 0 END)
+             FROM completion_logs l
+             JOIN todos t ON t.id = l.item_id
+             LEFT JOIN categories c ON c.id = t.category_id
+             WHERE l.completed_on >= ?4
+               AND (t.sync_status != 'deleted' OR t.sync_status IS NULL)
+             GROUP BY t.category_id
+             ORDER BY c.display_order ASC",
+        )?;
+        let rows = stmt
+            .query_map(params![start_30, start_60, start_90, start_180], |row| {
+                Ok(CategoryTrend {
+                    category_id: row.get(0)?,
+                    category_name: row.get(1)?,
+                    last_30_days: row.get(2)?,
+                    previous_30_days: row.get(3)?,
+                    change_30_days: None,
+                    last_90_days: row.get(4)?,
+                    previous_90_days: row.get(5)?,
+                    change_90_days: None,
+                })
+            })?
+            .filter_map(Result::ok)
+            .collect();
+        Ok(rows)
+    }
+
+    /// Items with the most missed slots from `since` on. Completions on
+    /// days without ledger rows count towards the miss rate.
+    pub fn most_missed(
+        conn: &Connection,
+        since: &str,
+        limit: i64,
+    ) -> Result<Vec<MissedItem>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT s.item_id, t.text, t.category_id,
+                    SUM(s.is_missed) AS missed, SUM(s.is_completed)
+             FROM (
+                 SELECT item_id, status = 'missed' AS is_missed, status = 'completed' AS is_completed
+                 FROM occurrences
+                 WHERE scheduled_on >= ?1
+                 UNION ALL
+                 SELECT l.item_id, 0, l.completed_count
+                 FROM {UNLEDGERED_LOGS} AND l.completed_on >= ?1
+             ) s
+             JOIN todos t ON t.id = s.item_id
+             WHERE t.sync_status != 'deleted' OR t.sync_status IS NULL
+             GROUP BY s.item_id
+             HAVING missed > 0
+             ORDER BY missed DESC, s.item_id ASC
+             LIMIT ?2"
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map(params![since, limit], |row| {
+                let missed: i64 = row.get(3)?;
+                let completed: i64 = row.get(4)?;
+                Ok(MissedItem {
+                    item_id: row.get(0)?,
+                    text: row.get(1)?,
+                    category_id: row.get(2)?,
+                    missed,
+                    completed,
+                    miss_rate: missed as f64 / (missed + completed) as f64,
+                })
+            })?
+            .filter_map(Result::ok)
+            .collect();
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert_item(conn: &Connection, text: &str) -> i64 {
+        conn.execute("INSERT INTO todos (text) VALUES (?1)", params![text])
+            .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn occurrence(conn: &Connection, item_id: i64, slot: &str, status: &str, at: Option<&str>) {
+        conn.execute(
+            "INSERT INTO occurrences (item_id, scheduled_on, status, completed_at, completed_on)
+             VALUES (?1, ?2, ?3, ?4, substr(?4, 1, 10))",
+            params![item_id, slot, status, at],
+        )
+        .unwrap();
+    }
+
+    fn log(conn: &Connection, item_id: i64, day: &str, count: i32, at: Option<&str>) {
+        conn.execute(
+            "INSERT INTO completion_logs (item_id, completed_on, completed_count, completed_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![item_id, day, count, at],
+        )
+        .unwrap();
+    }
+
+    /// 2026-03-02 is a Monday, 2026-03-03 a Tuesday
+    fn history(conn: &Connection) -> (i64, i64) {
+        let daily = insert_item(conn, "Stretch");
+        occurrence(
+            conn,
+            daily,
+            "2026-03-02",
+            "completed",
+            Some("2026-03-02T07:30:00+00:00"),
+        );
+        occurrence(conn, daily, "2026-03-03", "missed", None);
+        // Logged alongside the ledger row, so it must not count twice
+        log(
+            conn,
+            daily,
+            "2026-03-02",
+            1,
+            Some("2026-03-02T07:30:00+00:00"),
+        );
+        // A day the ledger has no row for, e.g. synced from another device
+        log(
+            conn,
+            daily,
+            "2026-03-09",
+            1,
+            Some("2026-03-09T21:10:00+00:00"),
+        );
+
+        let one_off = insert_item(conn, "File taxes");
+        log(
+            conn,
+            one_off,
+            "2026-03-03",
+            2,
+            Some("2026-03-03T12:05:00+00:00"),
+        );
+        (daily, one_off)
+    }
+
+    #[test]
+    fn unledgered_logs_search_occurrences_by_index() {
+        let conn = crate::repository::open_in_memory();
+        let sql = format!("EXPLAIN QUERY PLAN SELECT l.item_id FROM {UNLEDGERED_LOGS}");
+        let mut stmt = conn.prepare(&sql).unwrap();
+        let steps: Vec<String> = stmt
+            .query_map([], |row| row.get(3))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let searches = steps
+            .iter()
+            .filter(|step| step.starts_with("SEARCH o USING"));
+        assert_eq!(searches.count(), 2, "{steps:?}");
+        assert!(
+            !steps.iter().any(|step| step.starts_with("SCAN o")),
+            "{steps:?}"
+        );
+    }
+
+    #[test]
+    fn weekday_counts_logged_days_without_ledger_rows() {
+        let conn = crate::repository::open_in_memory();
+        history(&conn);
+
+        let mut rows = StatsRepository::slots_by_weekday(&conn, "2026-03-01").unwrap();
+        rows.sort();
+
+        // Mondays: the ledger completion and the logged one; Tuesday: the
+        // one-off item's two completions and the daily miss
+        assert_eq!(rows, vec![(1, 2, 0), (2, 2, 1)]);
+    }
+
+    #[test]
+    fn hours_include_logged_completion_times() {
+        let conn = crate::repository::open_in_memory();
+        history(&conn);
+
+        let mut rows = StatsRepository::completions_by_hour(&conn, "").unwrap();
+        rows.sort();
+
+        assert_eq!(rows, vec![(7, 1), (12, 1), (21, 1)]);
+    }
+
+    #[test]
+    fn miss_rate_counts_logged_completions() {
+        let conn = crate::repository::open_in_memory();
+        let (daily, _) = history(&conn);
+
+        let missed = StatsRepository::most_missed(&conn, "2026-03-01", 10).unwrap();
+
+        assert_eq!(missed.len(), 1);
+        assert_eq!(missed[0].item_id, daily);
+        assert_eq!((missed[0].missed, missed[0].completed), (1, 2));
+        // Nothing is counted before the window
+        assert!(StatsRepository::most_missed(&conn, "2026-03-04", 10)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,92 @@
+use rusqlite::{params, Connection};
+
+use crate::models::{FreezeKind, StreakFreeze, SyncStatus};
+
+pub struct StreakFreezeRepository;
+
+impl StreakFreezeRepository {
+    fn row_to_freeze(row: &rusqlite::Row) -> Result<StreakFreeze, rusqlite::Error> {
+        let kind: String = row.get(2)?;
+        let status: String = row.get(3)?;
+        Ok(StreakFreeze {
+            item_id: row.get(0)?,
+            frozen_on: row.get(1)?,
+            kind: FreezeKind::from_str(&kind),
+            sync_status: SyncStatus::from_str(&status),
+        })
+    }
+
+    /// Record a consumed freeze (no-op if the slot is already frozen)
+    pub fn add(
+        conn: &Connection,
+        item_id: i64,
+        date: &str,
+        kind: FreezeKind,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT OR IGNORE INTO streak_freezes (item_id, frozen_on, kind, sync_status)
+             VALUES (?1, ?2, ?3, 'pending')",
+            params![item_id, date, kind.to_str()],
+        )?;
+        Ok(())
+    }
+
+    /// Record a freeze pulled from the server
+    pub fn add_synced(
+        conn: &Connection,
+        item_id: i64,
+        date: &str,
+        kind: FreezeKind,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "INSERT OR IGNORE INTO streak_freezes (item_id, frozen_on, kind, sync_status)
+             VALUES (?1, ?2, ?3, 'synced')",
+            params![item_id, date, kind.to_str()],
+        )?;
+        Ok(())
+    }
+
+    pub fn mark_synced(conn: &Connection, item_id: i64, date: &str) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE streak_freezes SET sync_status = 'synced'
+             WHERE item_id = ?1 AND frozen_on = ?2",
+            params![item_id, date],
+        )?;
+        Ok(())
+    }
+
+    /// Get consumed freezes for a specific item (for streak calculation)
+    pub fn get_for_item(
+        conn: &Connection,
+        item_id: i64,
+    ) -> Result<Vec<StreakFreeze>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT item_id, frozen_on, kind, sync_status FROM streak_freezes
+             WHERE item_id = ?1
+             ORDER BY frozen_on ASC",
+        )?;
+
+        let freezes = stmt
+            .query_map(params![item_id], Self::row_to_freeze)?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(freezes)
+    }
+
+    /// Get freezes not pushed yet (for sync push)
+    pub fn get_pending(conn: &Connection) -> Result<Vec<StreakFreeze>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT item_id, frozen_on, kind, sync_status FROM streak_freezes
+             WHERE sync_status = 'pending'
+             ORDER BY item_id, frozen_on",
+        )?;
+
+        let freezes = stmt
+            .query_map([], Self::row_to_freeze)?
+            .filter_map(Result::ok)
+            .collect();
+
+        Ok(freezes)
+    }
+}
@@ -85,7 +85,7 @@ impl TagRepository {
     pub fn mark_deleted(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
         let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
         conn.execute(
-            "UPDATE tags SET sync_status = 'deleted', updated_at = ?1 WHERE id = ?2",
+            "UPDATE tags SET sync_status = 'deleted', deleted_at = NULL, updated_at = ?1 WHERE id = ?2",
             params![now, id],
         )?;
         // Also mark all todo_tags for this tag as deleted
@@ -102,7 +102,7 @@ impl TagRepository {
 
     pub fn get_pending_sync(conn: &Connection) -> Result<Vec<Tag>, rusqlite::Error> {
         let sql = format!(
-            "SELECT {} FROM tags WHERE sync_status = 'pending' OR sync_status = 'deleted' OR sync_status IS NULL",
+            "SELECT {} FROM tags WHERE sync_status = 'pending' OR (sync_status = 'deleted' AND deleted_at IS NULL) OR sync_status IS NULL",
             Self::SELECT_COLUMNS
         );
         let mut stmt = conn.prepare(&sql)?;
@@ -112,6 +112,24 @@ impl TagRepository {
         Ok(tags)
     }
 
+    /// A tag in the trash with this name
+    pub fn get_trashed_by_name(
+        conn: &Connection,
+        name: &str,
+    ) -> Result<Option<Tag>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT {} FROM tags WHERE name = ?1 AND sync_status = 'deleted' AND deleted_at IS NOT NULL",
+            Self::SELECT_COLUMNS
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let mut rows = stmt.query_map(params![name], Self::row_to_tag)?;
+        if let Some(tag) = rows.next() {
+            Ok(Some(tag?))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn get_by_sync_id(
         conn: &Connection,
         sync_id: &str,
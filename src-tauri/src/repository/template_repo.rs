@@ -0,0 +1,215 @@
+use rusqlite::{params, Connection};
+
+use crate::models::{ChecklistTemplate, RepeatType, SyncStatus, TemplateItem};
+
+pub struct TemplateRepository;
+
+impl TemplateRepository {
+    const SELECT_COLUMNS: &'static str = "id, name, sync_id, created_at, updated_at, sync_status";
+
+    /// The template without its items; callers load them with `get_items`
+    fn row_to_template(row: &rusqlite::Row) -> Result<ChecklistTemplate, rusqlite::Error> {
+        let sync_status_str: Option<String> = row.get(5)?;
+        Ok(ChecklistTemplate {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            items: Vec::new(),
+            sync_id: row.get(2)?,
+            created_at: row.get(3)?,
+            updated_at: row.get(4)?,
+            sync_status: sync_status_str
+                .map(|s| SyncStatus::from_str(&s))
+                .unwrap_or_default(),
+        })
+    }
+
+    fn row_to_item(row: &rusqlite::Row) -> Result<TemplateItem, rusqlite::Error> {
+        let repeat_type_str: String = row.get(2)?;
+        let tags_json: String = row.get(6)?;
+        let parent_position: Option<i64> = row.get(7)?;
+        Ok(TemplateItem {
+            text: row.get(0)?,
+            memo: row.get(1)?,
+            repeat_type: RepeatType::from_str(&repeat_type_str),
+            repeat_detail: row.get(3)?,
+            repeat_interval: row.get(4)?,
+            repeat_start: row.get(8)?,
+            repeat_until: row.get(9)?,
+            repeat_count: row.get(10)?,
+            due_times: row.get(11)?,
+            reminder_at: row.get(5)?,
+            tags: serde_json::from_str(&tags_json).unwrap_or_default(),
+            parent_position: parent_position.map(|position| position as usize),
+        })
+    }
+
+    fn collect_templates<P>(
+        conn: &Connection,
+        sql: &str,
+        query_params: P,
+    ) -> Result<Vec<ChecklistTemplate>, rusqlite::Error>
+    where
+        P: rusqlite::Params,
+    {
+        let mut stmt = conn.prepare(sql)?;
+        let mut templates = stmt
+            .query_map(query_params, Self::row_to_template)?
+            .collect::<Result<Vec<_>, _>>()?;
+        for template in &mut templates {
+            template.items = Self::get_items(conn, template.id)?;
+        }
+        Ok(templates)
+    }
+
+    pub fn get_all(conn: &Connection) -> Result<Vec<ChecklistTemplate>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT {} FROM checklist_templates WHERE sync_status != 'deleted' OR sync_status IS NULL ORDER BY name ASC",
+            Self::SELECT_COLUMNS
+        );
+        Self::collect_templates(conn, &sql, [])
+    }
+
+    pub fn get_by_id(
+        conn: &Connection,
+        id: i64,
+    ) -> Result<Option<ChecklistTemplate>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT {} FROM checklist_templates WHERE id = ?1",
+            Self::SELECT_COLUMNS
+        );
+        Ok(Self::collect_templates(conn, &sql, params![id])?.pop())
+    }
+
+    pub fn get_by_sync_id(
+        conn: &Connection,
+        sync_id: &str,
+    ) -> Result<Option<ChecklistTemplate>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT {} FROM checklist_templates WHERE sync_id = ?1",
+            Self::SELECT_COLUMNS
+        );
+        Ok(Self::collect_templates(conn, &sql, params![sync_id])?.pop())
+    }
+
+    pub fn get_pending_sync(conn: &Connection) -> Result<Vec<ChecklistTemplate>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT {} FROM checklist_templates WHERE sync_status = 'pending' OR sync_status = 'deleted' OR sync_status IS NULL",
+            Self::SELECT_COLUMNS
+        );
+        Self::collect_templates(conn, &sql, [])
+    }
+
+    pub fn get_items(
+        conn: &Connection,
+        template_id: i64,
+    ) -> Result<Vec<TemplateItem>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT text, memo, repeat_type, repeat_detail, repeat_interval, reminder_at, tags, parent_position,
+                    repeat_start, repeat_until, repeat_count, due_times
+             FROM checklist_template_items
+             WHERE template_id = ?1
+             ORDER BY position ASC",
+        )?;
+        let items = stmt
+            .query_map(params![template_id], Self::row_to_item)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(items)
+    }
+
+    pub fn create(
+        conn: &Connection,
+        name: &str,
+        items: &[TemplateItem],
+    ) -> Result<ChecklistTemplate, rusqlite::Error> {
+        let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        conn.execute(
+            "INSERT INTO checklist_templates (name, created_at, updated_at, sync_status) VALUES (?1, ?2, ?3, 'pending')",
+            params![name, &now, &now],
+        )?;
+        let id = conn.last_insert_rowid();
+        Self::replace_items(conn, id, items)?;
+        Ok(ChecklistTemplate {
+            id,
+            name: name.to_string(),
+            items: items.to_vec(),
+            sync_id: None,
+            created_at: Some(now.clone()),
+            updated_at: Some(now),
+            sync_status: SyncStatus::Pending,
+        })
+    }
+
+    /// Store `items` as the template's items, dropping the previous ones
+    pub fn replace_items(
+        conn: &Connection,
+        template_id: i64,
+        items: &[TemplateItem],
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "DELETE FROM checklist_template_items WHERE template_id = ?1",
+            params![template_id],
+        )?;
+        for (position, item) in items.iter().enumerate() {
+            let tags_json = serde_json::to_string(&item.tags).unwrap_or_else(|_| "[]".to_string());
+            conn.execute(
+                "INSERT INTO checklist_template_items (template_id, position, text, memo, repeat_type, repeat_detail, repeat_interval, reminder_at, tags, parent_position, repeat_start, repeat_until, repeat_count, due_times)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+                params![
+                    template_id,
+                    position as i64,
+                    item.text,
+                    item.memo,
+                    item.repeat_type.to_str(),
+                    item.repeat_detail,
+                    item.repeat_interval,
+                    item.reminder_at,
+                    tags_json,
+                    item.parent_position.map(|position| position as i64),
+                    item.repeat_start,
+                    item.repeat_until,
+                    item.repeat_count,
+                    item.due_times
+                ],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn delete(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "DELETE FROM checklist_template_items WHERE template_id = ?1",
+            params![id],
+        )?;
+        conn.execute("DELETE FROM checklist_templates WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    pub fn mark_deleted(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        conn.execute(
+            "UPDATE checklist_templates SET sync_status = 'deleted', updated_at = ?1 WHERE id = ?2",
+            params![now, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn update_sync_id(
+        conn: &Connection,
+        id: i64,
+        sync_id: &str,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE checklist_templates SET sync_id = ?1, sync_status = 'synced' WHERE id = ?2",
+            params![sync_id, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn mark_synced(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE checklist_templates SET sync_status = 'synced' WHERE id = ?1",
+            params![id],
+        )?;
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use rusqlite::{params, Connection};
 
-use crate::models::{RepeatType, SyncStatus, TodoItem, TrackedItem};
+use crate::models::{GoalPeriod, RepeatSchedule, RepeatType, SyncStatus, TodoItem, TrackedItem};
 
 pub struct TodoRepository;
 
@@ -9,6 +9,9 @@ impl TodoRepository {
         let repeat_type_str: String = row.get(6)?;
         let track_streak_int: i32 = row.get(10)?;
         let sync_status_str: Option<String> = row.get(16)?;
+        let goal_period_str: String = row.get(27)?;
+        let auto_complete_int: i32 = row.get(32)?;
+        let repeat_ended_int: i32 = row.get(33)?;
         Ok(TodoItem {
             id: row.get(0)?,
             text: row.get(1)?,
@@ -18,6 +21,23 @@ impl TodoRepository {
             memo: row.get(5)?,
             repeat_type: RepeatType::from_str(&repeat_type_str),
             repeat_detail: row.get(7)?,
+            repeat_interval: row.get(17)?,
+            repeat_start: row.get(18)?,
+            repeat_until: row.get(19)?,
+            repeat_count: row.get(20)?,
+            repeat_completed: row.get(21)?,
+            repeat_ended: repeat_ended_int != 0,
+            exclusion_calendar_id: row.get(22)?,
+            due_at: row.get(23)?,
+            due_times: row.get(24)?,
+            streak_freezes_per_month: row.get(25)?,
+            streak_grace_per_week: row.get(26)?,
+            goal_period: GoalPeriod::from_str(&goal_period_str),
+            goal_target: row.get(28)?,
+            quantity_target: row.get(29)?,
+            quantity_progress: row.get(30)?,
+            parent_id: row.get(31)?,
+            auto_complete: auto_complete_int != 0,
             next_due_at: row.get(8)?,
             last_completed_at: row.get(9)?,
             track_streak: track_streak_int != 0,
@@ -66,8 +86,10 @@ impl TodoRepository {
         }
     }
 
-    const SELECT_COLUMNS: &'static str = "id, text, done, category_id, display_order, memo, repeat_type, repeat_detail, next_due_at, last_completed_at, track_streak, reminder_at, linked_app, sync_id, created_at, updated_at, sync_status";
+    const SELECT_COLUMNS: &'static str = "id, text, done, category_id, display_order, memo, repeat_type, repeat_detail, next_due_at, last_completed_at, track_streak, reminder_at, linked_app, sync_id, created_at, updated_at, sync_status, repeat_interval, repeat_start, repeat_until, repeat_count, repeat_completed, exclusion_calendar_id, due_at, due_times, streak_freezes_per_month, streak_grace_per_week, goal_period, goal_target, quantity_target, quantity_progress, parent_id, auto_complete, repeat_ended";
 
+    /// Items of a category (all items with None). Sub-items come right
+    /// after their parent, in their own display order.
     pub fn get_by_category(
         conn: &Connection,
         category_id: Option<i64>,
@@ -75,26 +97,49 @@ impl TodoRepository {
         match category_id {
             Some(id) => {
                 let sql = format!(
-                    "SELECT {} FROM todos WHERE category_id = ?1 AND (sync_status != 'deleted' OR sync_status IS NULL) ORDER BY done ASC, display_order ASC",
-                    Self::SELECT_COLUMNS
+                    "SELECT {} FROM todos WHERE category_id = ?1 AND (sync_status != 'deleted' OR sync_status IS NULL) ORDER BY {}",
+                    Self::SELECT_COLUMNS,
+                    Self::PARENT_FIRST_ORDER
                 );
                 Self::collect_items(conn, &sql, params![id])
             }
             None => {
                 let sql = format!(
-                    "SELECT {} FROM todos WHERE sync_status != 'deleted' OR sync_status IS NULL ORDER BY done ASC, display_order ASC",
-                    Self::SELECT_COLUMNS
+                    "SELECT {} FROM todos WHERE sync_status != 'deleted' OR sync_status IS NULL ORDER BY {}",
+                    Self::SELECT_COLUMNS,
+                    Self::PARENT_FIRST_ORDER
                 );
                 Self::collect_items(conn, &sql, [])
             }
         }
     }
 
+    /// Top-level items by done and display order, each followed by its
+    /// sub-items
+    const PARENT_FIRST_ORDER: &'static str =
+        "COALESCE((SELECT p.done FROM todos p WHERE p.id = todos.parent_id), done) ASC,
+         COALESCE((SELECT p.display_order FROM todos p WHERE p.id = todos.parent_id), display_order) ASC,
+         COALESCE(parent_id, id) ASC,
+         parent_id IS NOT NULL ASC,
+         display_order ASC";
+
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<TodoItem>, rusqlite::Error> {
         let sql = format!("SELECT {} FROM todos WHERE id = ?1", Self::SELECT_COLUMNS);
         Self::get_optional_item(conn, &sql, params![id])
     }
 
+    /// Sub-items of an item in their display order
+    pub fn get_children(
+        conn: &Connection,
+        parent_id: i64,
+    ) -> Result<Vec<TodoItem>, rusqlite::Error> {
+        let sql = format!(
+            "SELECT {} FROM todos WHERE parent_id = ?1 AND (sync_status != 'deleted' OR sync_status IS NULL) ORDER BY display_order ASC",
+            Self::SELECT_COLUMNS
+        );
+        Self::collect_items(conn, &sql, params![parent_id])
+    }
+
     pub fn get_all(conn: &Connection) -> Result<Vec<TodoItem>, rusqlite::Error> {
         let sql = format!(
             "SELECT {} FROM todos WHERE sync_status != 'deleted' OR sync_status IS NULL ORDER BY display_order ASC",
@@ -104,7 +149,6 @@ impl TodoRepository {
     }
 
     /// Get all todos including deleted ones (for sync purposes)
-    #[allow(dead_code)]
     pub fn get_all_including_deleted(conn: &Connection) -> Result<Vec<TodoItem>, rusqlite::Error> {
         let sql = format!(
             "SELECT {} FROM todos ORDER BY display_order ASC",
@@ -115,7 +159,7 @@ impl TodoRepository {
 
     pub fn get_pending_sync(conn: &Connection) -> Result<Vec<TodoItem>, rusqlite::Error> {
         let sql = format!(
-            "SELECT {} FROM todos WHERE sync_status = 'pending' OR sync_status = 'deleted' OR sync_status IS NULL",
+            "SELECT {} FROM todos WHERE sync_status = 'pending' OR (sync_status = 'deleted' AND deleted_at IS NULL) OR sync_status IS NULL",
             Self::SELECT_COLUMNS
         );
         Self::collect_items(conn, &sql, [])
@@ -153,16 +197,8 @@ impl TodoRepository {
         Ok(items)
     }
 
-    pub fn create(
-        conn: &Connection,
-        text: &str,
-        category_id: Option<i64>,
-        repeat_type: &RepeatType,
-        repeat_detail: Option<&str>,
-        next_due_at: Option<&str>,
-        track_streak: bool,
-        reminder_at: Option<&str>,
-    ) -> Result<TodoItem, rusqlite::Error> {
+    /// Order that places an item after everything in `category_id`
+    pub fn next_display_order(conn: &Connection, category_id: Option<i64>) -> i64 {
         let max_order: i64 = if let Some(cat_id) = category_id {
             conn.query_row(
                 "SELECT COALESCE(MAX(display_order), 0) FROM todos WHERE category_id = ?1",
@@ -179,13 +215,25 @@ impl TodoRepository {
             .unwrap_or(0)
         };
 
-        let display_order = max_order + 1000;
-        let repeat_type_str = repeat_type.to_str();
+        max_order + 1000
+    }
+
+    pub fn create(
+        conn: &Connection,
+        text: &str,
+        category_id: Option<i64>,
+        repeat: &RepeatSchedule,
+        next_due_at: Option<&str>,
+        track_streak: bool,
+        reminder_at: Option<&str>,
+    ) -> Result<TodoItem, rusqlite::Error> {
+        let display_order = Self::next_display_order(conn, category_id);
+        let repeat_type_str = repeat.repeat_type.to_str();
         let now = Self::now_iso();
 
         conn.execute(
-            "INSERT INTO todos (text, done, category_id, display_order, repeat_type, repeat_detail, next_due_at, track_streak, reminder_at, created_at, updated_at, sync_status) VALUES (?1, 0, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, 'pending')",
-            params![text, category_id, display_order, repeat_type_str, repeat_detail, next_due_at, track_streak, reminder_at, &now, &now],
+            "INSERT INTO todos (text, done, category_id, display_order, repeat_type, repeat_detail, repeat_interval, repeat_start, repeat_until, repeat_count, next_due_at, track_streak, reminder_at, created_at, updated_at, sync_status) VALUES (?1, 0, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, 'pending')",
+            params![text, category_id, display_order, repeat_type_str, repeat.repeat_detail, repeat.repeat_interval, repeat.repeat_start, repeat.repeat_until, repeat.repeat_count, next_due_at, track_streak, reminder_at, &now, &now],
         )?;
 
         let id = conn.last_insert_rowid();
@@ -197,8 +245,25 @@ impl TodoRepository {
             category_id,
             display_order,
             memo: None,
-            repeat_type: repeat_type.clone(),
-            repeat_detail: repeat_detail.map(|s| s.to_string()),
+            repeat_type: repeat.repeat_type.clone(),
+            repeat_detail: repeat.repeat_detail.clone(),
+            repeat_interval: repeat.repeat_interval,
+            repeat_start: repeat.repeat_start.clone(),
+            repeat_until: repeat.repeat_until.clone(),
+            repeat_count: repeat.repeat_count,
+            repeat_completed: 0,
+            repeat_ended: false,
+            exclusion_calendar_id: None,
+            due_at: None,
+            due_times: None,
+            streak_freezes_per_month: 0,
+            streak_grace_per_week: 0,
+            goal_period: GoalPeriod::None,
+            goal_target: 0,
+            quantity_target: 0,
+            quantity_progress: 0,
+            parent_id: None,
+            auto_complete: false,
             next_due_at: next_due_at.map(|s| s.to_string()),
             last_completed_at: None,
             track_streak,
@@ -224,6 +289,107 @@ impl TodoRepository {
         Ok(())
     }
 
+    pub fn update_streak_grace(
+        conn: &Connection,
+        id: i64,
+        freezes_per_month: i32,
+        grace_per_week: i32,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET streak_freezes_per_month = ?1, streak_grace_per_week = ?2 WHERE id = ?3",
+            params![freezes_per_month, grace_per_week, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    pub fn update_goal(
+        conn: &Connection,
+        id: i64,
+        goal_period: &GoalPeriod,
+        goal_target: i32,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET goal_period = ?1, goal_target = ?2 WHERE id = ?3",
+            params![goal_period.to_str(), goal_target, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    /// Attach an item to a parent, or detach it with None. The item moves
+    /// to `category_id`, its parent's category.
+    pub fn set_parent(
+        conn: &Connection,
+        id: i64,
+        parent_id: Option<i64>,
+        category_id: Option<i64>,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET parent_id = ?1, category_id = ?2 WHERE id = ?3",
+            params![parent_id, category_id, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    /// Re-home an item in `category_id` at `display_order`
+    pub fn move_to_category(
+        conn: &Connection,
+        id: i64,
+        category_id: Option<i64>,
+        parent_id: Option<i64>,
+        display_order: i64,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET category_id = ?1, parent_id = ?2, display_order = ?3 WHERE id = ?4",
+            params![category_id, parent_id, display_order, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    pub fn update_auto_complete(
+        conn: &Connection,
+        id: i64,
+        auto_complete: bool,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET auto_complete = ?1 WHERE id = ?2",
+            params![auto_complete as i32, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    /// Set the quantity target. Progress beyond a lowered target is capped.
+    pub fn update_quantity_target(
+        conn: &Connection,
+        id: i64,
+        quantity_target: i32,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET quantity_target = ?1, quantity_progress = MIN(quantity_progress, ?1)
+             WHERE id = ?2",
+            params![quantity_target, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    pub fn set_quantity_progress(
+        conn: &Connection,
+        id: i64,
+        quantity_progress: i32,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET quantity_progress = ?1 WHERE id = ?2",
+            params![quantity_progress, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
     pub fn update_reminder(
         conn: &Connection,
         id: i64,
@@ -277,34 +443,151 @@ impl TodoRepository {
     pub fn update_repeat(
         conn: &Connection,
         id: i64,
-        repeat_type: &RepeatType,
-        repeat_detail: Option<&str>,
+        repeat: &RepeatSchedule,
+        next_due_at: Option<&str>,
+    ) -> Result<(), rusqlite::Error> {
+        let repeat_type_str = repeat.repeat_type.to_str();
+        // Changed repeat settings start a new series, so repeat_completed restarts
+        conn.execute(
+            "UPDATE todos SET repeat_type = ?1, repeat_detail = ?2, repeat_interval = ?3, repeat_start = ?4, repeat_until = ?5, repeat_count = ?6, repeat_completed = 0, repeat_ended = 0, next_due_at = ?7 WHERE id = ?8",
+            params![
+                repeat_type_str,
+                repeat.repeat_detail,
+                repeat.repeat_interval,
+                repeat.repeat_start,
+                repeat.repeat_until,
+                repeat.repeat_count,
+                next_due_at,
+                id
+            ],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    pub fn update_exclusion_calendar(
+        conn: &Connection,
+        id: i64,
+        calendar_id: Option<i64>,
+        next_due_at: Option<&str>,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET exclusion_calendar_id = ?1, next_due_at = ?2 WHERE id = ?3",
+            params![calendar_id, next_due_at, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    pub fn update_due(
+        conn: &Connection,
+        id: i64,
+        due_at: Option<&str>,
+        due_times: Option<&str>,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET due_at = ?1, due_times = ?2 WHERE id = ?3",
+            params![due_at, due_times, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    /// Point an item with due times at a slot (date and datetime)
+    pub fn set_due_slot(
+        conn: &Connection,
+        id: i64,
         next_due_at: Option<&str>,
+        due_at: Option<&str>,
     ) -> Result<(), rusqlite::Error> {
-        let repeat_type_str = repeat_type.to_str();
         conn.execute(
-            "UPDATE todos SET repeat_type = ?1, repeat_detail = ?2, next_due_at = ?3 WHERE id = ?4",
-            params![repeat_type_str, repeat_detail, next_due_at, id],
+            "UPDATE todos SET next_due_at = ?1, due_at = ?2 WHERE id = ?3",
+            params![next_due_at, due_at, id],
         )?;
         Self::mark_updated(conn, id)?;
         Ok(())
     }
 
+    pub fn set_repeat_completed(
+        conn: &Connection,
+        id: i64,
+        repeat_completed: i32,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET repeat_completed = ?1 WHERE id = ?2",
+            params![repeat_completed, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    pub fn set_repeat_ended(
+        conn: &Connection,
+        id: i64,
+        repeat_ended: bool,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET repeat_ended = ?1 WHERE id = ?2",
+            params![repeat_ended as i32, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    /// Put an item aside until `next_due_at` without recording a completion
+    pub fn defer(
+        conn: &Connection,
+        id: i64,
+        next_due_at: Option<&str>,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "UPDATE todos SET done = 1, next_due_at = ?1 WHERE id = ?2",
+            params![next_due_at, id],
+        )?;
+        Self::mark_updated(conn, id)?;
+        Ok(())
+    }
+
+    /// Bring an item back, together with its sub-items
     pub fn reactivate(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
-        conn.execute("UPDATE todos SET done = 0 WHERE id = ?1", params![id])?;
+        conn.execute(
+            "UPDATE todos SET done = 0, quantity_progress = 0 WHERE id = ?1",
+            params![id],
+        )?;
         Self::mark_updated(conn, id)?;
+        conn.execute(
+            "UPDATE todos SET done = 0, quantity_progress = 0, updated_at = ?1, sync_status = 'pending'
+             WHERE parent_id = ?2 AND (done = 1 OR quantity_progress > 0)",
+            params![Self::now_iso(), id],
+        )?;
         Ok(())
     }
 
+    /// Delete the item for good, with its tags and history
     pub fn delete(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        conn.execute("DELETE FROM todo_tags WHERE todo_id = ?1", params![id])?;
+        for table in [
+            "completion_logs",
+            "measurements",
+            "skip_logs",
+            "occurrences",
+            "streak_freezes",
+            "schedule_versions",
+        ] {
+            conn.execute(
+                &format!("DELETE FROM {table} WHERE item_id = ?1"),
+                params![id],
+            )?;
+        }
         conn.execute("DELETE FROM todos WHERE id = ?1", params![id])?;
         Ok(())
     }
 
+    /// Mark a synced item for its remote delete on the next sync
     pub fn mark_deleted(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
         let now = Self::now_iso();
         conn.execute(
-            "UPDATE todos SET sync_status = 'deleted', updated_at = ?1 WHERE id = ?2",
+            "UPDATE todos SET sync_status = 'deleted', deleted_at = NULL, updated_at = ?1 WHERE id = ?2",
             params![now, id],
         )?;
         Ok(())
@@ -372,15 +655,67 @@ impl TodoRepository {
         Ok(())
     }
 
-    pub fn reset_all(conn: &Connection, category_id: Option<i64>) -> Result<(), rusqlite::Error> {
+    /// Reset the items of a category, or every item with None. `today` is
+    /// the logical date; items skipped or snoozed past it stay done.
+    pub fn reset_all(
+        conn: &Connection,
+        category_id: Option<i64>,
+        today: &str,
+    ) -> Result<(), rusqlite::Error> {
+        // Completion-relative repeats come back on their own schedule in process_repeats,
+        // and repeats whose series has ended stay done. Sub-items follow their parent,
+        // so they are reset first while the parent still shows whether it qualifies.
         if let Some(id) = category_id {
             conn.execute(
-                "UPDATE todos SET done = 0 WHERE category_id = ?1",
-                params![id],
+                &Self::reset_children_sql("AND category_id = ?2"),
+                params![today, id],
+            )?;
+            conn.execute(
+                "UPDATE todos SET done = 0, quantity_progress = 0 WHERE category_id = ?1 AND parent_id IS NULL
+                 AND repeat_type != 'after_completion'
+                 AND repeat_ended = 0
+                 AND (next_due_at IS NULL OR next_due_at <= ?2)",
+                params![id, today],
             )?;
         } else {
-            conn.execute("UPDATE todos SET done = 0", [])?;
+            conn.execute(&Self::reset_children_sql(""), params![today])?;
+            conn.execute(
+                "UPDATE todos SET done = 0, quantity_progress = 0 WHERE parent_id IS NULL
+                 AND repeat_type != 'after_completion'
+                 AND repeat_ended = 0
+                 AND (next_due_at IS NULL OR next_due_at <= ?1)",
+                params![today],
+            )?;
         }
         Ok(())
     }
+
+    /// Reset items that don't belong to any category
+    pub fn reset_uncategorized(conn: &Connection, today: &str) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            &Self::reset_children_sql("AND category_id IS NULL"),
+            params![today],
+        )?;
+        conn.execute(
+            "UPDATE todos SET done = 0, quantity_progress = 0 WHERE category_id IS NULL AND parent_id IS NULL
+             AND repeat_type != 'after_completion'
+             AND repeat_ended = 0
+             AND (next_due_at IS NULL OR next_due_at <= ?1)",
+            params![today],
+        )?;
+        Ok(())
+    }
+
+    /// Statement resetting the sub-items of parents a reset applies to.
+    /// `parents` narrows the parents further, e.g. "AND category_id = ?2";
+    /// ?1 is today.
+    fn reset_children_sql(parents: &str) -> String {
+        format!(
+            "UPDATE todos SET done = 0, quantity_progress = 0 WHERE parent_id IN (
+                 SELECT id FROM todos WHERE repeat_type != 'after_completion'
+                 AND repeat_ended = 0
+                 AND (next_due_at IS NULL OR next_due_at <= ?1)
+                 {parents})"
+        )
+    }
 }
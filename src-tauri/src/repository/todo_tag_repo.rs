@@ -18,10 +18,14 @@ impl TodoTagRepository {
         })
     }
 
+    /// Link a tag to an item; a removed link that waits for its remote
+    /// delete comes back instead
     pub fn add_tag(conn: &Connection, todo_id: i64, tag_id: i64) -> Result<(), rusqlite::Error> {
         let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
         conn.execute(
-            "INSERT OR IGNORE INTO todo_tags (todo_id, tag_id, created_at, sync_status) VALUES (?1, ?2, ?3, 'pending')",
+            "INSERT INTO todo_tags (todo_id, tag_id, created_at, sync_status) VALUES (?1, ?2, ?3, 'pending')
+             ON CONFLICT(todo_id, tag_id) DO UPDATE SET sync_status = 'pending'
+             WHERE sync_status = 'deleted'",
             params![todo_id, tag_id, &now],
         )?;
         Ok(())
@@ -47,6 +51,20 @@ impl TodoTagRepository {
         Ok(())
     }
 
+    /// Unlink a tag from all its items. Synced links are kept as deleted
+    /// until the next sync sends their remote delete.
+    pub fn remove_all_for_tag(conn: &Connection, tag_id: i64) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            "DELETE FROM todo_tags WHERE tag_id = ?1 AND sync_id IS NULL",
+            params![tag_id],
+        )?;
+        conn.execute(
+            "UPDATE todo_tags SET sync_status = 'deleted' WHERE tag_id = ?1",
+            params![tag_id],
+        )?;
+        Ok(())
+    }
+
     pub fn get_tags_for_item(conn: &Connection, todo_id: i64) -> Result<Vec<Tag>, rusqlite::Error> {
         let mut stmt = conn.prepare(
             "SELECT t.id, t.name, t.sync_id, t.created_at, t.updated_at, t.sync_status
@@ -0,0 +1,140 @@
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::models::{TrashEntry, TrashKind};
+
+/// Rows in the trash keep `sync_status = 'deleted'` and carry the time they
+/// were trashed in `deleted_at`. Sync leaves them alone until they are
+/// purged, so the remote copy is only deleted then.
+pub struct TrashRepository;
+
+impl TrashRepository {
+    /// Move a row to the trash at `deleted_at`
+    pub fn trash(
+        conn: &Connection,
+        kind: TrashKind,
+        id: i64,
+        deleted_at: &str,
+    ) -> Result<(), rusqlite::Error> {
+        conn.execute(
+            &format!(
+                "UPDATE {} SET sync_status = 'deleted', deleted_at = ?1, updated_at = ?1 WHERE id = ?2",
+                kind.table()
+            ),
+            params![deleted_at, id],
+        )?;
+        Ok(())
+    }
+
+    /// Bring a trashed row back; it syncs as an update
+    pub fn restore(conn: &Connection, kind: TrashKind, id: i64) -> Result<(), rusqlite::Error> {
+        let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        conn.execute(
+            &format!(
+                "UPDATE {} SET sync_status = 'pending', deleted_at = NULL, updated_at = ?1 WHERE id = ?2",
+                kind.table()
+            ),
+            params![now, id],
+        )?;
+        Ok(())
+    }
+
+    /// When the row went to the trash, or None when it isn't in the trash
+    pub fn deleted_at(
+        conn: &Connection,
+        kind: TrashKind,
+        id: i64,
+    ) -> Result<Option<String>, rusqlite::Error> {
+        let deleted_at = conn
+            .query_row(
+                &format!(
+                    "SELECT deleted_at FROM {} WHERE id = ?1 AND sync_status = 'deleted'",
+                    kind.table()
+                ),
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        Ok(deleted_at)
+    }
+
+    /// Trashed items that went to the trash with `parent_id` at `deleted_at`
+    pub fn trashed_children(
+        conn: &Connection,
+        parent_id: i64,
+        deleted_at: &str,
+    ) -> Result<Vec<i64>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT id FROM todos WHERE parent_id = ?1 AND deleted_at = ?2 AND sync_status = 'deleted'",
+        )?;
+        let ids = stmt
+            .query_map(params![parent_id, deleted_at], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ids)
+    }
+
+    /// Trashed items that went to the trash with `category_id` at `deleted_at`
+    pub fn trashed_category_items(
+        conn: &Connection,
+        category_id: i64,
+        deleted_at: &str,
+    ) -> Result<Vec<i64>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT id FROM todos WHERE category_id = ?1 AND deleted_at = ?2 AND sync_status = 'deleted'",
+        )?;
+        let ids = stmt
+            .query_map(params![category_id, deleted_at], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ids)
+    }
+
+    /// Everything in the trash, newest first. `purge_at` is left empty.
+    pub fn get_entries(conn: &Connection) -> Result<Vec<TrashEntry>, rusqlite::Error> {
+        let mut stmt = conn.prepare(
+            "SELECT 'item', id, text, category_id, deleted_at FROM todos
+             WHERE sync_status = 'deleted' AND deleted_at IS NOT NULL
+             UNION ALL
+             SELECT 'category', id, name, NULL, deleted_at FROM categories
+             WHERE sync_status = 'deleted' AND deleted_at IS NOT NULL
+             UNION ALL
+             SELECT 'tag', id, name, NULL, deleted_at FROM tags
+             WHERE sync_status = 'deleted' AND deleted_at IS NOT NULL
+             ORDER BY 5 DESC, 2 ASC",
+        )?;
+        let entries = stmt
+            .query_map([], |row| {
+                let kind: String = row.get(0)?;
+                Ok(TrashEntry {
+                    kind: TrashKind::from_str(&kind).unwrap_or(TrashKind::Item),
+                    id: row.get(1)?,
+                    name: row.get(2)?,
+                    category_id: row.get(3)?,
+                    deleted_at: row.get(4)?,
+                    purge_at: String::new(),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
+
+    /// Trashed rows from before `before`, or all of them with None. Items
+    /// come first, then categories, then tags.
+    pub fn get_expired(
+        conn: &Connection,
+        before: Option<&str>,
+    ) -> Result<Vec<(TrashKind, i64)>, rusqlite::Error> {
+        let mut expired = Vec::new();
+        for kind in [TrashKind::Item, TrashKind::Category, TrashKind::Tag] {
+            let mut stmt = conn.prepare(&format!(
+                "SELECT id FROM {} WHERE sync_status = 'deleted' AND deleted_at IS NOT NULL
+                   AND (?1 IS NULL OR deleted_at < ?1)",
+                kind.table()
+            ))?;
+            let ids = stmt
+                .query_map(params![before], |row| row.get::<_, i64>(0))?
+                .collect::<Result<Vec<_>, _>>()?;
+            expired.extend(ids.into_iter().map(|id| (kind, id)));
+        }
+        Ok(expired)
+    }
+}
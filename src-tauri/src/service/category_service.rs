@@ -1,7 +1,10 @@
 use rusqlite::Connection;
 
-use crate::models::Category;
+use chrono::NaiveTime;
+
+use crate::models::{Category, ResetPolicy};
 use crate::repository::CategoryRepository;
+use crate::service::{ResetService, TrashService};
 
 pub struct CategoryService;
 
@@ -18,27 +21,49 @@ impl CategoryService {
         CategoryRepository::update(conn, id, name)
     }
 
+    pub fn update_reset_policy(
+        conn: &Connection,
+        id: i64,
+        policy: ResetPolicy,
+        reset_times: &[String],
+        reset_weekdays: &[u32],
+    ) -> Result<(), String> {
+        if let Some(time) = reset_times
+            .iter()
+            .find(|time| NaiveTime::parse_from_str(time, "%H:%M").is_err())
+        {
+            return Err(format!("Invalid reset time: {}", time));
+        }
+        if let Some(day) = reset_weekdays.iter().find(|day| **day > 6) {
+            return Err(format!("Invalid weekday: {}", day));
+        }
+        if policy == ResetPolicy::Weekdays && reset_weekdays.is_empty() {
+            return Err("Weekday reset needs at least one weekday".to_string());
+        }
+
+        let reset_times = (!reset_times.is_empty())
+            .then(|| serde_json::to_string(reset_times).map_err(|e| e.to_string()))
+            .transpose()?;
+        let reset_weekdays = (!reset_weekdays.is_empty())
+            .then(|| serde_json::to_string(reset_weekdays).map_err(|e| e.to_string()))
+            .transpose()?;
+
+        CategoryRepository::update_reset_policy(
+            conn,
+            id,
+            &policy,
+            reset_times.as_deref(),
+            reset_weekdays.as_deref(),
+        )
+        .map_err(|e| e.to_string())?;
+        // The new policy takes over from its next boundary
+        ResetService::mark_category_reset(conn, id).map_err(|e| e.to_string())
+    }
+
+    /// Move a category and its items to the trash
     pub fn delete(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
-        // Check if category has been synced (has sync_id)
-        if let Some(category) = CategoryRepository::get_by_id(conn, id)? {
-            if category.sync_id.is_some() {
-                // Category was synced - mark as deleted, also mark all todos in this category
-                // First mark all todos in this category as deleted
-                conn.execute(
-                    "UPDATE todos SET sync_status = 'deleted', updated_at = datetime('now') WHERE category_id = ?1 AND sync_id IS NOT NULL",
-                    rusqlite::params![id],
-                )?;
-                // Delete todos that were never synced
-                conn.execute(
-                    "DELETE FROM todos WHERE category_id = ?1 AND sync_id IS NULL",
-                    rusqlite::params![id],
-                )?;
-                // Mark category as deleted
-                CategoryRepository::mark_deleted(conn, id)
-            } else {
-                // Category was never synced - delete immediately
-                CategoryRepository::delete(conn, id)
-            }
+        if CategoryRepository::get_by_id(conn, id)?.is_some() {
+            TrashService::trash_category(conn, id)
         } else {
             Ok(())
         }
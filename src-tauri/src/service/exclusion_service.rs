@@ -0,0 +1,104 @@
+use chrono::NaiveDate;
+use rusqlite::Connection;
+
+use crate::models::{ExclusionCalendar, ExclusionRange};
+use crate::repository::ExclusionRepository;
+
+mod ics;
+
+pub struct ExclusionService;
+
+impl ExclusionService {
+    pub fn get_calendars(conn: &Connection) -> Result<Vec<ExclusionCalendar>, rusqlite::Error> {
+        ExclusionRepository::get_calendars(conn)
+    }
+
+    pub fn create_calendar(conn: &Connection, name: &str) -> Result<ExclusionCalendar, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Calendar name is required".to_string());
+        }
+        ExclusionRepository::create_calendar(conn, name).map_err(|e| e.to_string())
+    }
+
+    pub fn delete_calendar(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        ExclusionRepository::delete_calendar(conn, id)
+    }
+
+    pub fn get_ranges(
+        conn: &Connection,
+        calendar_id: i64,
+    ) -> Result<Vec<ExclusionRange>, rusqlite::Error> {
+        ExclusionRepository::get_ranges(conn, calendar_id)
+    }
+
+    /// Exclude a single day, or the inclusive range up to `end_date`
+    pub fn add_range(
+        conn: &Connection,
+        calendar_id: i64,
+        start_date: &str,
+        end_date: Option<&str>,
+        label: Option<&str>,
+    ) -> Result<ExclusionRange, String> {
+        let start = Self::parse_date(start_date)?;
+        let end = match end_date {
+            Some(end_date) => Self::parse_date(end_date)?,
+            None => start,
+        };
+        if end < start {
+            return Err("End date must not be before start date".to_string());
+        }
+
+        ExclusionRepository::add_range(
+            conn,
+            calendar_id,
+            &start.format("%Y-%m-%d").to_string(),
+            &end.format("%Y-%m-%d").to_string(),
+            label,
+        )
+        .map_err(|e| e.to_string())
+    }
+
+    pub fn delete_range(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        ExclusionRepository::delete_range(conn, id)
+    }
+
+    /// Import the events of a local .ics file into a calendar.
+    /// Events already present with the same dates are not added again.
+    /// Returns the number of ranges added.
+    pub fn import_ics(conn: &Connection, calendar_id: i64, path: &str) -> Result<usize, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read calendar file: {}", e))?;
+        let events = ics::parse_events(&content)?;
+
+        let existing =
+            ExclusionRepository::get_ranges(conn, calendar_id).map_err(|e| e.to_string())?;
+        let mut added = 0;
+        for event in events {
+            let start = event.start.format("%Y-%m-%d").to_string();
+            let end = event.end.format("%Y-%m-%d").to_string();
+            if existing
+                .iter()
+                .any(|range| range.start_date == start && range.end_date == end)
+            {
+                continue;
+            }
+
+            ExclusionRepository::add_range(
+                conn,
+                calendar_id,
+                &start,
+                &end,
+                event.summary.as_deref(),
+            )
+            .map_err(|e| e.to_string())?;
+            added += 1;
+        }
+
+        Ok(added)
+    }
+
+    fn parse_date(date: &str) -> Result<NaiveDate, String> {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| format!("Invalid date: {}", date))
+    }
+}
@@ -0,0 +1,160 @@
+use chrono::NaiveDate;
+
+/// An event of an iCalendar file as an inclusive range of dates
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsEvent {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub summary: Option<String>,
+}
+
+/// Read the VEVENTs of an iCalendar (.ics) file.
+///
+/// Only the dates matter: timed events cover the days they touch, and the
+/// DTEND of all-day events is exclusive as in RFC 5545. Recurring events
+/// contribute their first instance only.
+pub fn parse_events(content: &str) -> Result<Vec<IcsEvent>, String> {
+    let lines = unfold_lines(content);
+    if !lines
+        .iter()
+        .any(|line| line.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err("Not an iCalendar file".to_string());
+    }
+
+    let mut events = Vec::new();
+    let mut in_event = false;
+    let mut start: Option<(NaiveDate, bool)> = None;
+    let mut end: Option<(NaiveDate, bool)> = None;
+    let mut summary: Option<String> = None;
+
+    for line in &lines {
+        let Some((name_and_params, value)) = line.split_once(':') else {
+            continue;
+        };
+        let mut parts = name_and_params.split(';');
+        let name = parts.next().unwrap_or_default().to_ascii_uppercase();
+        let is_date_value = parts.any(|param| param.eq_ignore_ascii_case("VALUE=DATE"));
+
+        match name.as_str() {
+            "BEGIN" if value.eq_ignore_ascii_case("VEVENT") => {
+                in_event = true;
+                start = None;
+                end = None;
+                summary = None;
+            }
+            "END" if value.eq_ignore_ascii_case("VEVENT") => {
+                in_event = false;
+                let Some((start_date, _)) = start else {
+                    continue;
+                };
+                let end_date = match end {
+                    // All-day DTEND points at the day after the event
+                    Some((date, true)) => date - chrono::Duration::days(1),
+                    Some((date, false)) => date,
+                    None => start_date,
+                };
+                events.push(IcsEvent {
+                    start: start_date,
+                    end: end_date.max(start_date),
+                    summary: summary.take(),
+                });
+            }
+            "DTSTART" if in_event => start = parse_date(value, is_date_value),
+            "DTEND" if in_event => end = parse_date(value, is_date_value),
+            "SUMMARY" if in_event => summary = Some(unescape_text(value)),
+            _ => {}
+        }
+    }
+
+    Ok(events)
+}
+
+/// Join folded continuation lines (starting with a space or tab)
+fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in content.lines() {
+        if let Some(rest) = raw.strip_prefix(' ').or_else(|| raw.strip_prefix('\t')) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(rest);
+                continue;
+            }
+        }
+        lines.push(raw.trim_end().to_string());
+    }
+    lines
+}
+
+/// Parse a DATE (`20260101`) or DATE-TIME (`20260101T090000Z`) value.
+/// The flag tells whether the value was a plain date.
+fn parse_date(value: &str, is_date_value: bool) -> Option<(NaiveDate, bool)> {
+    let date = NaiveDate::parse_from_str(value.get(..8)?, "%Y%m%d").ok()?;
+    Some((date, is_date_value || value.len() == 8))
+}
+
+fn unescape_text(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn reads_all_day_and_timed_events() {
+        let content = "BEGIN:VCALENDAR\r\n\
+            VERSION:2.0\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20260301\r\n\
+            DTEND;VALUE=DATE:20260302\r\n\
+            SUMMARY:Independence Movement Day\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART;VALUE=DATE:20260803\r\n\
+            DTEND;VALUE=DATE:20260808\r\n\
+            SUMMARY:Summer\\, family\r\n  \
+            vacation\r\n\
+            END:VEVENT\r\n\
+            BEGIN:VEVENT\r\n\
+            DTSTART:20260910T090000Z\r\n\
+            DTEND:20260910T170000Z\r\n\
+            END:VEVENT\r\n\
+            END:VCALENDAR\r\n";
+
+        let events = parse_events(content).unwrap();
+        assert_eq!(
+            events,
+            vec![
+                IcsEvent {
+                    start: date("2026-03-01"),
+                    end: date("2026-03-01"),
+                    summary: Some("Independence Movement Day".to_string()),
+                },
+                IcsEvent {
+                    start: date("2026-08-03"),
+                    end: date("2026-08-07"),
+                    summary: Some("Summer, family vacation".to_string()),
+                },
+                IcsEvent {
+                    start: date("2026-09-10"),
+                    end: date("2026-09-10"),
+                    summary: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_non_calendar_content() {
+        assert!(parse_events("just some text").is_err());
+    }
+}
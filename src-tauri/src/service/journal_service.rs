@@ -0,0 +1,530 @@
+use rusqlite::Connection;
+use serde_json::Value;
+
+use crate::models::{TableImage, TrashKind, UndoState};
+use crate::repository::{JournalRepository, TodoRepository, TrashRepository};
+
+/// How many operations can be undone
+const MAX_JOURNAL_ENTRIES: i64 = 50;
+
+/// Item rows and the history kept per item
+const ITEM_TABLES: [(&str, &str); 8] = [
+    ("todos", "id"),
+    ("todo_tags", "todo_id"),
+    ("completion_logs", "item_id"),
+    ("measurements", "item_id"),
+    ("skip_logs", "item_id"),
+    ("occurrences", "item_id"),
+    ("streak_freezes", "item_id"),
+    ("schedule_versions", "item_id"),
+];
+
+/// Tables whose new rows an action creating things may have added
+const CREATED_TABLES: [&str; 3] = ["todos", "categories", "tags"];
+
+/// Rows of `table` whose `column` is in the ids
+type TableScope = (&'static str, &'static str, Vec<i64>);
+
+/// Rows an operation may change. Items always take their parent and
+/// sub-items along, since completing or deleting one can change the others.
+pub enum JournalScope {
+    Items(Vec<i64>),
+    /// Items of a category, or all items with None
+    CategoryItems(Option<i64>),
+    /// A category row with its items
+    Category(i64),
+    /// Category rows only
+    Categories(Vec<i64>),
+    /// A tag row with its item links
+    Tag(i64),
+    /// A row in the trash with the rows that went to the trash with it
+    Trashed(TrashKind, i64),
+    /// Items, categories and tags the action creates, plus these items
+    Created(Vec<i64>),
+}
+
+pub struct JournalService;
+
+impl JournalService {
+    /// Run `action` and record the rows of `scope` before and after it, so
+    /// it can be undone and redone. Nothing is recorded when the action
+    /// fails or changes nothing.
+    pub fn record<T, E: ToString>(
+        conn: &Connection,
+        label: &str,
+        scope: JournalScope,
+        action: impl FnOnce(&Connection) -> Result<T, E>,
+    ) -> Result<T, String> {
+        let last_ids = match scope {
+            JournalScope::Created(_) => Some(Self::last_ids(conn).map_err(|e| e.to_string())?),
+            _ => None,
+        };
+        let tables = Self::tables(conn, scope).map_err(|e| e.to_string())?;
+        let mut before = Self::capture(conn, &tables).map_err(|e| e.to_string())?;
+        let result = action(conn).map_err(|e| e.to_string())?;
+
+        // The action already happened, so a journal failure only costs the undo
+        let recorded = Self::capture(conn, &tables).and_then(|mut after| {
+            // Created rows didn't exist before, so their before images are empty
+            if let Some(last_ids) = &last_ids {
+                let created = Self::created_tables(conn, last_ids)?;
+                before.extend(created.iter().map(|(table, column, ids)| TableImage {
+                    table: table.to_string(),
+                    column: column.to_string(),
+                    ids: ids.clone(),
+                    rows: Vec::new(),
+                }));
+                after.extend(Self::capture(conn, &created)?);
+            }
+            let (before, after) = Self::changed_rows(before, after);
+            if before.is_empty() {
+                return Ok(());
+            }
+            JournalRepository::push(conn, label, &before, &after, MAX_JOURNAL_ENTRIES)
+        });
+        if let Err(error) = recorded {
+            log::error!(
+                "Failed to record '{}' in the undo journal: {}",
+                label,
+                error
+            );
+        }
+        Ok(result)
+    }
+
+    /// Undo the newest recorded operation. If its rows changed since, it
+    /// is dropped instead and named in `discarded_label`.
+    pub fn undo(conn: &Connection) -> Result<UndoState, rusqlite::Error> {
+        let mut discarded_label = None;
+        if let Some(entry) = JournalRepository::last_done(conn)? {
+            if Self::is_current(conn, &entry.after)? {
+                Self::apply(conn, &entry.before)?;
+                JournalRepository::set_undone(conn, entry.id, true)?;
+            } else {
+                JournalRepository::delete(conn, entry.id)?;
+                discarded_label = Some(entry.label);
+            }
+        }
+        Ok(UndoState {
+            discarded_label,
+            ..Self::get_state(conn)?
+        })
+    }
+
+    /// Redo the operation undone last. If its rows changed since, it is
+    /// dropped with everything redoable after it and named in
+    /// `discarded_label`.
+    pub fn redo(conn: &Connection) -> Result<UndoState, rusqlite::Error> {
+        let mut discarded_label = None;
+        if let Some(entry) = JournalRepository::first_undone(conn)? {
+            if Self::is_current(conn, &entry.before)? {
+                Self::apply(conn, &entry.after)?;
+                JournalRepository::set_undone(conn, entry.id, false)?;
+            } else {
+                JournalRepository::delete_undone(conn)?;
+                discarded_label = Some(entry.label);
+            }
+        }
+        Ok(UndoState {
+            discarded_label,
+            ..Self::get_state(conn)?
+        })
+    }
+
+    pub fn get_state(conn: &Connection) -> Result<UndoState, rusqlite::Error> {
+        Ok(UndoState {
+            undo_label: JournalRepository::last_done(conn)?.map(|entry| entry.label),
+            redo_label: JournalRepository::first_undone(conn)?.map(|entry| entry.label),
+            discarded_label: None,
+        })
+    }
+
+    /// Whether the rows still look the way `images` recorded them
+    fn is_current(conn: &Connection, images: &[TableImage]) -> Result<bool, rusqlite::Error> {
+        let current = images
+            .iter()
+            .map(|image| JournalRepository::capture(conn, &image.table, &image.column, &image.ids))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(JournalRepository::same_rows(&current, images))
+    }
+
+    fn apply(conn: &Connection, images: &[TableImage]) -> Result<(), rusqlite::Error> {
+        conn.execute("BEGIN TRANSACTION", [])?;
+        if let Err(e) = JournalRepository::restore(conn, images) {
+            let _ = conn.execute("ROLLBACK", []);
+            return Err(e);
+        }
+        conn.execute("COMMIT", [])?;
+        Ok(())
+    }
+
+    fn capture(
+        conn: &Connection,
+        tables: &[TableScope],
+    ) -> Result<Vec<TableImage>, rusqlite::Error> {
+        tables
+            .iter()
+            .map(|(table, column, ids)| JournalRepository::capture(conn, table, column, ids))
+            .collect()
+    }
+
+    /// `before` and `after` narrowed to the ids whose rows the action
+    /// changed; tables it left alone are dropped
+    fn changed_rows(
+        before: Vec<TableImage>,
+        after: Vec<TableImage>,
+    ) -> (Vec<TableImage>, Vec<TableImage>) {
+        let rows_of = |image: &TableImage, id: i64| -> Vec<String> {
+            let id = Value::from(id);
+            let mut rows: Vec<String> = image
+                .rows
+                .iter()
+                .filter(|row| row.get(&image.column) == Some(&id))
+                .map(|row| Value::Object(row.clone()).to_string())
+                .collect();
+            rows.sort();
+            rows
+        };
+        let narrow = |image: &TableImage, ids: &[i64]| -> TableImage {
+            TableImage {
+                table: image.table.clone(),
+                column: image.column.clone(),
+                ids: ids.to_vec(),
+                rows: image
+                    .rows
+                    .iter()
+                    .filter(|row| {
+                        row.get(&image.column)
+                            .and_then(Value::as_i64)
+                            .is_some_and(|id| ids.contains(&id))
+                    })
+                    .cloned()
+                    .collect(),
+            }
+        };
+
+        before
+            .iter()
+            .zip(&after)
+            .filter_map(|(before, after)| {
+                let ids: Vec<i64> = before
+                    .ids
+                    .iter()
+                    .copied()
+                    .filter(|&id| rows_of(before, id) != rows_of(after, id))
+                    .collect();
+                (!ids.is_empty()).then(|| (narrow(before, &ids), narrow(after, &ids)))
+            })
+            .unzip()
+    }
+
+    /// The `(table, column, ids)` sets that cover `scope`
+    fn tables(conn: &Connection, scope: JournalScope) -> Result<Vec<TableScope>, rusqlite::Error> {
+        let item_ids_of = |category_id: Option<i64>| -> Result<Vec<i64>, rusqlite::Error> {
+            Ok(TodoRepository::get_by_category(conn, category_id)?
+                .into_iter()
+                .map(|item| item.id)
+                .collect())
+        };
+
+        let mut tables = Vec::new();
+        let item_ids = match scope {
+            JournalScope::Items(ids) => ids,
+            JournalScope::CategoryItems(category_id) => item_ids_of(category_id)?,
+            JournalScope::Category(id) => {
+                tables.push(("categories", "id", vec![id]));
+                item_ids_of(Some(id))?
+            }
+            JournalScope::Categories(ids) => {
+                tables.push(("categories", "id", ids));
+                Vec::new()
+            }
+            JournalScope::Tag(id) => {
+                tables.push(("tags", "id", vec![id]));
+                tables.push(("todo_tags", "tag_id", vec![id]));
+                Vec::new()
+            }
+            JournalScope::Trashed(kind, id) => {
+                let deleted_at = TrashRepository::deleted_at(conn, kind, id)?.unwrap_or_default();
+                match kind {
+                    TrashKind::Item => {
+                        let mut ids = TrashRepository::trashed_children(conn, id, &deleted_at)?;
+                        ids.push(id);
+                        ids
+                    }
+                    TrashKind::Category => {
+                        tables.push(("categories", "id", vec![id]));
+                        TrashRepository::trashed_category_items(conn, id, &deleted_at)?
+                    }
+                    TrashKind::Tag => {
+                        tables.push(("tags", "id", vec![id]));
+                        tables.push(("todo_tags", "tag_id", vec![id]));
+                        Vec::new()
+                    }
+                }
+            }
+            JournalScope::Created(ids) => ids,
+        };
+
+        let item_ids = Self::with_family(conn, item_ids)?;
+        if !item_ids.is_empty() {
+            for (table, column) in ITEM_TABLES {
+                tables.push((table, column, item_ids.clone()));
+            }
+        }
+        Ok(tables)
+    }
+
+    /// Highest id of each table rows may be created in
+    fn last_ids(conn: &Connection) -> Result<Vec<i64>, rusqlite::Error> {
+        CREATED_TABLES
+            .iter()
+            .map(|table| JournalRepository::max_id(conn, table))
+            .collect()
+    }
+
+    /// The `(table, column, ids)` sets that cover rows created after
+    /// `last_ids`, items with their history
+    fn created_tables(
+        conn: &Connection,
+        last_ids: &[i64],
+    ) -> Result<Vec<TableScope>, rusqlite::Error> {
+        let mut tables = Vec::new();
+        for (table, last_id) in CREATED_TABLES.iter().zip(last_ids) {
+            let ids = JournalRepository::ids_after(conn, table, *last_id)?;
+            if ids.is_empty() {
+                continue;
+            }
+            if *table == "todos" {
+                for (table, column) in ITEM_TABLES {
+                    tables.push((table, column, ids.clone()));
+                }
+            } else {
+                tables.push((*table, "id", ids));
+            }
+        }
+        Ok(tables)
+    }
+
+    /// `ids` with their parents and sub-items
+    fn with_family(conn: &Connection, ids: Vec<i64>) -> Result<Vec<i64>, rusqlite::Error> {
+        let mut family = ids.clone();
+        for id in ids {
+            if let Some(parent_id) = TodoRepository::get_by_id(conn, id)?.and_then(|i| i.parent_id)
+            {
+                family.push(parent_id);
+            }
+            family.extend(TodoRepository::get_children(conn, id)?.iter().map(|c| c.id));
+        }
+        family.sort_unstable();
+        family.dedup();
+        Ok(family)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RepeatSchedule, RepeatType, SyncStatus, TodoItem};
+    use crate::repository::CompletionLogRepository;
+    use crate::service::TodoService;
+
+    fn item(conn: &Connection, text: &str, repeat_type: RepeatType) -> TodoItem {
+        TodoService::create_item(
+            conn,
+            text,
+            None,
+            RepeatSchedule::new(repeat_type, None),
+            true,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn toggle(conn: &Connection, id: i64) {
+        JournalService::record(conn, "toggle", JournalScope::Items(vec![id]), |db| {
+            TodoService::toggle_item(db, id)
+        })
+        .unwrap();
+    }
+
+    fn todo(conn: &Connection, id: i64) -> TodoItem {
+        TodoRepository::get_by_id(conn, id).unwrap().unwrap()
+    }
+
+    /// (completed_count, sync_status) of each log row of the item
+    fn logs(conn: &Connection, id: i64) -> Vec<(i32, String)> {
+        let mut stmt = conn
+            .prepare(
+                "SELECT completed_count, sync_status FROM completion_logs
+                 WHERE item_id = ?1 ORDER BY completed_on",
+            )
+            .unwrap();
+        stmt.query_map([id], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    /// What a sync push leaves behind
+    fn mark_pushed(conn: &Connection, id: i64) {
+        TodoRepository::update_sync_id(conn, id, &format!("remote-{id}")).unwrap();
+        for (log, updated_at) in CompletionLogRepository::get_pending(conn).unwrap() {
+            CompletionLogRepository::mark_synced(conn, log.item_id, &log.completed_on, &updated_at)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_a_toggle_keep_sync_state() {
+        let conn = crate::repository::open_in_memory();
+        let run = item(&conn, "Run", RepeatType::Daily);
+        mark_pushed(&conn, run.id);
+
+        toggle(&conn, run.id);
+        mark_pushed(&conn, run.id);
+        assert!(todo(&conn, run.id).done);
+        assert_eq!(logs(&conn, run.id), vec![(1, "synced".to_string())]);
+
+        let state = JournalService::undo(&conn).unwrap();
+        assert_eq!(state.redo_label.as_deref(), Some("toggle"));
+        assert_eq!(state.discarded_label, None);
+        let undone = todo(&conn, run.id);
+        assert!(!undone.done);
+        assert_eq!(undone.sync_status, SyncStatus::Pending);
+        // The pushed completion stays as an emptied day, so the next push
+        // overrides the server's count instead of pulling it back
+        assert_eq!(logs(&conn, run.id), vec![(0, "pending".to_string())]);
+        assert!(
+            CompletionLogRepository::get_all_logs_for_item(&conn, run.id)
+                .unwrap()
+                .is_empty()
+        );
+
+        mark_pushed(&conn, run.id);
+        let state = JournalService::redo(&conn).unwrap();
+        assert_eq!(state.undo_label.as_deref(), Some("toggle"));
+        let redone = todo(&conn, run.id);
+        assert!(redone.done);
+        assert_eq!(redone.sync_status, SyncStatus::Pending);
+        assert_eq!(logs(&conn, run.id), vec![(1, "pending".to_string())]);
+    }
+
+    #[test]
+    fn undo_of_changed_rows_is_dropped() {
+        let conn = crate::repository::open_in_memory();
+        let run = item(&conn, "Run", RepeatType::Daily);
+        toggle(&conn, run.id);
+        TodoRepository::update_text(&conn, run.id, "Run 5k").unwrap();
+
+        let state = JournalService::undo(&conn).unwrap();
+
+        assert_eq!(state.discarded_label.as_deref(), Some("toggle"));
+        assert_eq!(state.undo_label, None);
+        assert_eq!(state.redo_label, None);
+        assert!(todo(&conn, run.id).done);
+    }
+
+    #[test]
+    fn sync_bookkeeping_does_not_block_undo() {
+        let conn = crate::repository::open_in_memory();
+        let run = item(&conn, "Run", RepeatType::Daily);
+        toggle(&conn, run.id);
+        mark_pushed(&conn, run.id);
+
+        let state = JournalService::undo(&conn).unwrap();
+
+        assert_eq!(state.discarded_label, None);
+        assert!(!todo(&conn, run.id).done);
+    }
+
+    #[test]
+    fn auto_reset_keeps_undo_of_untouched_items() {
+        let conn = crate::repository::open_in_memory();
+        let run = item(&conn, "Run", RepeatType::None);
+        JournalService::record(&conn, "edit", JournalScope::Items(vec![run.id]), |db| {
+            TodoRepository::update_text(db, run.id, "Run 5k")
+        })
+        .unwrap();
+        crate::repository::SettingsRepository::set(&conn, "last_reset_date", "2000-01-01").unwrap();
+
+        assert!(crate::service::ResetService::check_and_auto_reset(&conn).unwrap());
+        let state = JournalService::undo(&conn).unwrap();
+
+        assert_eq!(state.discarded_label, None);
+        assert_eq!(todo(&conn, run.id).text, "Run");
+    }
+
+    #[test]
+    fn undo_removes_a_created_sub_item_and_redo_brings_it_back() {
+        let conn = crate::repository::open_in_memory();
+        let parent = item(&conn, "Pack", RepeatType::None);
+        let scope = JournalScope::Created(vec![parent.id]);
+        let child = JournalService::record(&conn, "add", scope, |db| {
+            TodoService::create_subtask(db, parent.id, "Socks")
+        })
+        .unwrap();
+
+        JournalService::undo(&conn).unwrap();
+        assert!(TodoRepository::get_by_id(&conn, child.id)
+            .unwrap()
+            .is_none());
+
+        let state = JournalService::redo(&conn).unwrap();
+        assert_eq!(state.discarded_label, None);
+        assert_eq!(todo(&conn, child.id).parent_id, Some(parent.id));
+    }
+
+    #[test]
+    fn undo_of_a_restore_puts_the_rows_back_in_the_trash() {
+        use crate::service::TrashService;
+
+        let conn = crate::repository::open_in_memory();
+        let parent = item(&conn, "Pack", RepeatType::None);
+        let child = TodoService::create_subtask(&conn, parent.id, "Socks").unwrap();
+        TrashService::trash_item(&conn, parent.id).unwrap();
+
+        let scope = JournalScope::Trashed(TrashKind::Item, parent.id);
+        JournalService::record(&conn, "restore", scope, |db| {
+            TrashService::restore_item(db, parent.id)
+        })
+        .unwrap();
+        assert_eq!(todo(&conn, child.id).sync_status, SyncStatus::Pending);
+
+        JournalService::undo(&conn).unwrap();
+        for id in [parent.id, child.id] {
+            assert_eq!(todo(&conn, id).sync_status, SyncStatus::Deleted);
+            assert!(TrashRepository::deleted_at(&conn, TrashKind::Item, id)
+                .unwrap()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn reset_records_only_the_items_it_changed() {
+        let conn = crate::repository::open_in_memory();
+        let run = item(&conn, "Run", RepeatType::None);
+        let read = item(&conn, "Read", RepeatType::None);
+        toggle(&conn, run.id);
+
+        JournalService::record(&conn, "reset", JournalScope::CategoryItems(None), |db| {
+            crate::service::ResetService::reset_items(db, None)
+        })
+        .unwrap();
+
+        let entry = JournalRepository::last_done(&conn).unwrap().unwrap();
+        assert_eq!(entry.label, "reset");
+        assert!(!entry.before.is_empty());
+        for image in entry.before.iter().chain(&entry.after) {
+            assert_eq!(image.ids, vec![run.id], "{}", image.table);
+        }
+        assert!(!entry
+            .before
+            .iter()
+            .any(|image| image.ids.contains(&read.id)));
+
+        JournalService::undo(&conn).unwrap();
+        assert!(todo(&conn, run.id).done);
+        assert!(!todo(&conn, read.id).done);
+    }
+}
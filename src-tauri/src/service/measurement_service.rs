@@ -0,0 +1,230 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use rusqlite::Connection;
+
+use crate::models::{Measurement, MeasurementPoint, MeasurementSeries, OccurrenceSource, TodoItem};
+use crate::repository::{MeasurementRepository, TodoRepository};
+use crate::service::{RepeatService, TimeZoneService};
+
+pub struct MeasurementService;
+
+impl MeasurementService {
+    /// Complete an item and record a value with the completion (72.4 kg).
+    /// Every completion keeps its own value. A quantity item short of its
+    /// target just advances; the value is recorded with the step that
+    /// completes it.
+    pub fn complete_with_value(
+        conn: &Connection,
+        id: i64,
+        value: f64,
+        unit: Option<&str>,
+    ) -> Result<Option<TodoItem>, String> {
+        if !value.is_finite() {
+            return Err("Value must be a number".to_string());
+        }
+        let unit = unit.map(str::trim).filter(|unit| !unit.is_empty());
+        match TodoRepository::get_by_id(conn, id).map_err(|e| e.to_string())? {
+            Some(item) if item.done => return Err("Item is already done".to_string()),
+            Some(_) => {}
+            None => return Ok(None),
+        }
+
+        let item = RepeatService::increment_progress(conn, id, OccurrenceSource::App)
+            .map_err(|e| e.to_string())?;
+        let Some(item) = item else {
+            return Ok(None);
+        };
+        if !item.done {
+            return Ok(Some(item));
+        }
+
+        let today = TimeZoneService::logical_today(conn)
+            .map_err(|e| e.to_string())?
+            .format("%Y-%m-%d")
+            .to_string();
+        let now = TimeZoneService::now(conn)
+            .map_err(|e| e.to_string())?
+            .format("%Y-%m-%dT%H:%M:%S")
+            .to_string();
+        MeasurementRepository::add(conn, id, &today, &now, value, unit)
+            .map_err(|e| e.to_string())?;
+        Ok(Some(item))
+    }
+
+    /// Recorded values of an item, over the last `days` days or all time
+    pub fn get_series(
+        conn: &Connection,
+        item_id: i64,
+        days: Option<i32>,
+    ) -> Result<MeasurementSeries, rusqlite::Error> {
+        let since = match days.filter(|days| *days > 0) {
+            Some(days) => {
+                let today = TimeZoneService::logical_today(conn)?;
+                Some(
+                    (today - Duration::days(days as i64 - 1))
+                        .format("%Y-%m-%d")
+                        .to_string(),
+                )
+            }
+            None => None,
+        };
+
+        let measurements = MeasurementRepository::get_for_item(conn, item_id, since.as_deref())?;
+        Ok(Self::summarize(item_id, measurements))
+    }
+
+    fn summarize(item_id: i64, measurements: Vec<Measurement>) -> MeasurementSeries {
+        let points: Vec<MeasurementPoint> = measurements
+            .into_iter()
+            .map(|measurement| MeasurementPoint {
+                value: measurement.value,
+                date: measurement.recorded_on,
+                unit: measurement.unit,
+            })
+            .collect();
+        let values: Vec<f64> = points.iter().map(|point| point.value).collect();
+
+        MeasurementSeries {
+            item_id,
+            unit: points.last().and_then(|point| point.unit.clone()),
+            min: values.iter().copied().reduce(f64::min),
+            max: values.iter().copied().reduce(f64::max),
+            avg: (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64),
+            latest: values.last().copied(),
+            trend_per_day: Self::trend_per_day(&points),
+            points,
+        }
+    }
+
+    /// Least-squares slope of the values against their dates
+    fn trend_per_day(points: &[MeasurementPoint]) -> Option<f64> {
+        let samples: Vec<(f64, f64)> = points
+            .iter()
+            .filter_map(|point| {
+                let date = NaiveDate::parse_from_str(&point.date, "%Y-%m-%d").ok()?;
+                Some((date.num_days_from_ce() as f64, point.value))
+            })
+            .collect();
+        if samples.len() < 2 {
+            return None;
+        }
+
+        let n = samples.len() as f64;
+        let mean_x = samples.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = samples.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let covariance: f64 = samples
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        let variance: f64 = samples.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        (variance > 0.0).then(|| covariance / variance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RepeatSchedule, RepeatType, SyncStatus};
+    use crate::service::TodoService;
+
+    fn log(date: &str, value: f64) -> Measurement {
+        Measurement {
+            id: 0,
+            item_id: 1,
+            recorded_on: date.to_string(),
+            recorded_at: format!("{date}T08:00:00"),
+            value,
+            unit: Some("kg".to_string()),
+            sync_id: None,
+            sync_status: SyncStatus::Pending,
+        }
+    }
+
+    #[test]
+    fn summary_covers_range_average_and_trend() {
+        let logs = vec![
+            log("2026-03-01", 74.0),
+            log("2026-03-03", 73.0),
+            log("2026-03-05", 72.0),
+        ];
+
+        let series = MeasurementService::summarize(1, logs);
+
+        assert_eq!(series.min, Some(72.0));
+        assert_eq!(series.max, Some(74.0));
+        assert_eq!(series.avg, Some(73.0));
+        assert_eq!(series.latest, Some(72.0));
+        assert_eq!(series.unit.as_deref(), Some("kg"));
+        assert_eq!(series.trend_per_day, Some(-0.5));
+    }
+
+    #[test]
+    fn single_value_has_no_trend() {
+        let series = MeasurementService::summarize(1, vec![log("2026-03-01", 30.0)]);
+
+        assert_eq!(series.avg, Some(30.0));
+        assert_eq!(series.trend_per_day, None);
+    }
+
+    fn weigh_in(conn: &Connection) -> TodoItem {
+        TodoService::create_item(
+            conn,
+            "Weigh in",
+            None,
+            RepeatSchedule::new(RepeatType::None, None),
+            false,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn values(conn: &Connection, id: i64) -> Vec<f64> {
+        MeasurementRepository::get_for_item(conn, id, None)
+            .unwrap()
+            .into_iter()
+            .map(|measurement| measurement.value)
+            .collect()
+    }
+
+    #[test]
+    fn done_item_takes_no_value() {
+        let conn = crate::repository::open_in_memory();
+        let item = weigh_in(&conn);
+        MeasurementService::complete_with_value(&conn, item.id, 72.4, Some("kg")).unwrap();
+
+        let result = MeasurementService::complete_with_value(&conn, item.id, 71.0, Some("kg"));
+
+        assert!(result.is_err());
+        assert_eq!(values(&conn, item.id), vec![72.4]);
+    }
+
+    #[test]
+    fn each_completion_keeps_its_value() {
+        let conn = crate::repository::open_in_memory();
+        let item = weigh_in(&conn);
+
+        MeasurementService::complete_with_value(&conn, item.id, 72.4, Some("kg")).unwrap();
+        crate::service::ResetService::reset_items(&conn, None).unwrap();
+        MeasurementService::complete_with_value(&conn, item.id, 72.1, Some("kg")).unwrap();
+
+        assert_eq!(values(&conn, item.id), vec![72.4, 72.1]);
+    }
+
+    #[test]
+    fn value_goes_with_its_completion() {
+        let conn = crate::repository::open_in_memory();
+        let item = weigh_in(&conn);
+        TodoService::update_quantity_target(&conn, item.id, 2).unwrap();
+
+        // The first step doesn't complete the item, so nothing is recorded
+        let stepped = MeasurementService::complete_with_value(&conn, item.id, 1.0, None).unwrap();
+        assert!(!stepped.unwrap().done);
+        assert!(values(&conn, item.id).is_empty());
+
+        MeasurementService::complete_with_value(&conn, item.id, 2.0, None).unwrap();
+        assert_eq!(values(&conn, item.id), vec![2.0]);
+
+        // Unchecking undoes the completion and its value
+        TodoService::toggle_item(&conn, item.id).unwrap();
+        assert!(values(&conn, item.id).is_empty());
+    }
+}
@@ -1,26 +1,42 @@
 mod auth_service;
 mod category_service;
+mod exclusion_service;
+mod journal_service;
+mod measurement_service;
 mod oauth_service;
 pub mod realtime_messages;
 pub mod realtime_service;
 pub mod repeat_service;
 mod reset_service;
+mod scheduler_service;
+mod stats_service;
 mod streak_service;
 pub mod supabase_client;
 mod sync_service;
 mod tag_service;
+mod template_service;
+mod time_zone_service;
 mod todo_service;
+mod trash_service;
 mod widget_service;
 
 pub use auth_service::AuthService;
 pub use category_service::CategoryService;
+pub use exclusion_service::ExclusionService;
+pub use journal_service::{JournalScope, JournalService};
+pub use measurement_service::MeasurementService;
 pub use oauth_service::OAuthService;
 pub use realtime_service::{RealtimeConfig, RealtimeService};
 pub use repeat_service::RepeatService;
 pub use reset_service::ResetService;
+pub use scheduler_service::SchedulerService;
+pub use stats_service::StatsService;
 pub use streak_service::StreakService;
 pub use supabase_client::{SupabaseClient, SupabaseConfig};
 pub use sync_service::SyncService;
 pub use tag_service::TagService;
+pub use template_service::TemplateService;
+pub use time_zone_service::TimeZoneService;
 pub use todo_service::TodoService;
+pub use trash_service::TrashService;
 pub use widget_service::WidgetService;
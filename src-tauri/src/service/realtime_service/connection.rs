@@ -142,6 +142,30 @@ impl RealtimeService {
                         "table": "completion_logs",
                         "filter": format!("user_id=eq.{}", config.user_id)
                     },
+                    {
+                        "event": "*",
+                        "schema": "public",
+                        "table": "measurements",
+                        "filter": format!("user_id=eq.{}", config.user_id)
+                    },
+                    {
+                        "event": "*",
+                        "schema": "public",
+                        "table": "skip_logs",
+                        "filter": format!("user_id=eq.{}", config.user_id)
+                    },
+                    {
+                        "event": "*",
+                        "schema": "public",
+                        "table": "streak_freezes",
+                        "filter": format!("user_id=eq.{}", config.user_id)
+                    },
+                    {
+                        "event": "*",
+                        "schema": "public",
+                        "table": "schedule_versions",
+                        "filter": format!("user_id=eq.{}", config.user_id)
+                    },
                     {
                         "event": "*",
                         "schema": "public",
@@ -153,6 +177,18 @@ impl RealtimeService {
                         "schema": "public",
                         "table": "todo_tags",
                         "filter": format!("user_id=eq.{}", config.user_id)
+                    },
+                    {
+                        "event": "*",
+                        "schema": "public",
+                        "table": "checklist_templates",
+                        "filter": format!("user_id=eq.{}", config.user_id)
+                    },
+                    {
+                        "event": "*",
+                        "schema": "public",
+                        "table": "user_settings",
+                        "filter": format!("user_id=eq.{}", config.user_id)
                     }
                 ],
                 "private": false
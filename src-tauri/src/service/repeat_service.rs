@@ -1,18 +1,35 @@
-use chrono::{Datelike, Local, NaiveDate, NaiveTime};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
 use rusqlite::Connection;
 
-use crate::models::{RepeatType, TodoItem};
-use crate::repository::{SettingsRepository, TodoRepository};
-use crate::service::StreakService;
+use crate::models::{
+    Occurrence, OccurrenceSource, OccurrenceStatus, RepeatSchedule, RepeatType, TodoItem,
+};
+use crate::repository::{
+    ExclusionRepository, MeasurementRepository, OccurrenceRepository, SkipLogRepository,
+    TodoRepository,
+};
+use crate::service::{StreakService, TimeZoneService};
+
+mod due_time;
+mod rrule;
+
+pub use due_time::parse_due_at;
+pub use rrule::{Frequency, RecurrenceRule, WeekdayRule};
 
 pub struct RepeatService;
 
+/// Upper bound on consecutive excluded slots passed over in one calculation
+const MAX_EXCLUDED_SLOTS: usize = 1000;
+
+/// Most dates a single preview may return
+pub const MAX_PREVIEW_OCCURRENCES: usize = 100;
+
 /// Calculate the "logical date" based on reset time setting.
+/// `now` is the wall-clock time in the user's time zone.
 /// If current time is before the reset time, return yesterday.
 /// Otherwise, return today.
-pub fn get_logical_date(reset_time: &str) -> NaiveDate {
-    let now = Local::now();
-    let today = now.date_naive();
+pub fn get_logical_date(reset_time: &str, now: NaiveDateTime) -> NaiveDate {
+    let today = now.date();
 
     // Parse "HH:MM" format
     let parts: Vec<&str> = reset_time.split(':').collect();
@@ -31,14 +48,185 @@ pub fn get_logical_date(reset_time: &str) -> NaiveDate {
 }
 
 impl RepeatService {
-    /// Calculate the next due date based on repeat type and detail
-    pub fn calculate_next_due(
+    /// Calculate the next due date based on the item's repeat settings.
+    /// Excluded days are passed over.
+    /// Returns None once the next occurrence would fall after `repeat_until`.
+    pub fn calculate_next_due(schedule: &RepeatSchedule, from_date: NaiveDate) -> Option<String> {
+        let mut next_due = Self::calculate_unbounded_next_due(schedule, from_date)?;
+        for _ in 0..MAX_EXCLUDED_SLOTS {
+            if !schedule.is_excluded(&next_due) {
+                break;
+            }
+            let excluded = NaiveDate::parse_from_str(&next_due, "%Y-%m-%d").ok()?;
+            next_due = if schedule.repeat_type == RepeatType::AfterCompletion {
+                // Completion-relative items are simply due on the next open day
+                (excluded + chrono::Duration::days(1))
+                    .format("%Y-%m-%d")
+                    .to_string()
+            } else {
+                Self::calculate_unbounded_next_due(schedule, excluded)?
+            };
+        }
+        if schedule.is_excluded(&next_due) {
+            return None;
+        }
+
+        match Self::repeat_until(schedule) {
+            Some(until) if Self::parse_date(&next_due)? > until => None,
+            _ => Some(next_due),
+        }
+    }
+
+    /// Whether a series has no occurrence left after `date`: its count is
+    /// used up or it runs past its end date. A schedule that merely yields
+    /// no next date, like a rule that can't be computed or an exclusion
+    /// covering every remaining day, hasn't ended.
+    pub fn series_ended(schedule: &RepeatSchedule, repeat_completed: i32, date: NaiveDate) -> bool {
+        if schedule
+            .repeat_count
+            .is_some_and(|count| repeat_completed >= count)
+        {
+            return true;
+        }
+        if let Some(until) = Self::repeat_until(schedule) {
+            let next = Self::calculate_unbounded_next_due(schedule, date)
+                .and_then(|next| Self::parse_date(&next));
+            if date >= until || next.is_some_and(|next| next > until) {
+                return true;
+            }
+        }
+        Self::schedule_rule(schedule).is_some_and(|rule| rule.has_ended_after(date))
+    }
+
+    fn repeat_until(schedule: &RepeatSchedule) -> Option<NaiveDate> {
+        schedule.repeat_until.as_deref().and_then(Self::parse_date)
+    }
+
+    fn parse_date(s: &str) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+    }
+
+    /// Repeat schedule of an item with its exclusion calendar loaded
+    pub fn schedule_for(conn: &Connection, item: &TodoItem) -> Result<RepeatSchedule, rusqlite::Error> {
+        let exclusions = match item.exclusion_calendar_id {
+            Some(calendar_id) => ExclusionRepository::get_ranges(conn, calendar_id)?,
+            None => Vec::new(),
+        };
+        Ok(RepeatSchedule::from_item(item).with_exclusions(exclusions))
+    }
+
+    fn calculate_unbounded_next_due(
+        schedule: &RepeatSchedule,
+        from_date: NaiveDate,
+    ) -> Option<String> {
+        if schedule.repeat_type == RepeatType::AfterCompletion {
+            // from_date is the completion date, not a calendar slot
+            let next = from_date + chrono::Duration::days(schedule.repeat_interval.max(1) as i64);
+            return Some(next.format("%Y-%m-%d").to_string());
+        }
+
+        if let Some(rule) = Self::schedule_rule(schedule) {
+            return rule
+                .next_after(from_date)
+                .map(|next| next.format("%Y-%m-%d").to_string());
+        }
+
+        Self::calculate_simple_next_due(
+            &schedule.repeat_type,
+            schedule.repeat_detail.as_deref(),
+            from_date,
+        )
+    }
+
+    /// Express a schedule as a recurrence rule when the simple weekday/month-day
+    /// calculation can't: custom rules, yearly repeats and intervals above one.
+    pub fn schedule_rule(schedule: &RepeatSchedule) -> Option<RecurrenceRule> {
+        let repeat_detail = schedule.repeat_detail.as_deref();
+        let interval = schedule.repeat_interval.max(1) as u32;
+
+        let mut rule = match schedule.repeat_type {
+            RepeatType::None | RepeatType::AfterCompletion => return None,
+            RepeatType::Rule => return repeat_detail.and_then(|s| RecurrenceRule::parse(s).ok()),
+            RepeatType::Yearly => {
+                // repeat_detail is JSON array [month, day]
+                let values: Vec<u32> = repeat_detail
+                    .and_then(|s| serde_json::from_str(s).ok())
+                    .unwrap_or_default();
+                let [month, day] = values[..] else {
+                    return None;
+                };
+                // Checked against a leap year, so Feb 29 passes but Apr 31 doesn't
+                NaiveDate::from_ymd_opt(2024, month, day)?;
+
+                let mut rule = RecurrenceRule::new(Frequency::Yearly);
+                rule.by_month = vec![month];
+                rule.by_month_day = vec![day as i32];
+                rule
+            }
+            _ if interval == 1 => return None,
+            RepeatType::Daily => RecurrenceRule::new(Frequency::Daily),
+            RepeatType::Weekly => {
+                let days: Vec<u32> = repeat_detail
+                    .and_then(|s| serde_json::from_str(s).ok())
+                    .unwrap_or_default();
+                let by_day: Vec<WeekdayRule> = days
+                    .into_iter()
+                    .filter(|day| *day <= 6)
+                    // repeat_detail counts from Sunday, chrono from Monday
+                    .filter_map(|day| chrono::Weekday::try_from(((day + 6) % 7) as u8).ok())
+                    .map(|weekday| WeekdayRule {
+                        ordinal: None,
+                        weekday,
+                    })
+                    .collect();
+                if by_day.is_empty() {
+                    return None;
+                }
+
+                // Weeks start on Sunday, as in repeat_detail
+                let mut rule = RecurrenceRule::new(Frequency::Weekly);
+                rule.by_day = by_day;
+                rule.week_start = chrono::Weekday::Sun;
+                rule
+            }
+            RepeatType::Monthly => {
+                let days: Vec<u32> = repeat_detail
+                    .and_then(|s| serde_json::from_str(s).ok())
+                    .unwrap_or_default();
+                let by_month_day: Vec<i32> = days
+                    .into_iter()
+                    .filter(|day| (1..=31).contains(day))
+                    .map(|day| day as i32)
+                    .collect();
+                if by_month_day.is_empty() {
+                    return None;
+                }
+
+                let mut rule = RecurrenceRule::new(Frequency::Monthly);
+                rule.by_month_day = by_month_day;
+                rule
+            }
+        };
+
+        rule.interval = interval;
+        rule.dtstart = schedule
+            .repeat_start
+            .as_deref()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+        Some(rule)
+    }
+
+    fn calculate_simple_next_due(
         repeat_type: &RepeatType,
         repeat_detail: Option<&str>,
         from_date: NaiveDate,
     ) -> Option<String> {
         match repeat_type {
-            RepeatType::None => None,
+            // Yearly repeats and custom rules are handled by schedule_rule
+            RepeatType::None
+            | RepeatType::Yearly
+            | RepeatType::Rule
+            | RepeatType::AfterCompletion => None,
             RepeatType::Daily => {
                 let next = from_date + chrono::Duration::days(1);
                 Some(next.format("%Y-%m-%d").to_string())
@@ -122,6 +310,186 @@ impl RepeatService {
         }
     }
 
+    /// Check repeat settings before they are stored or previewed. The
+    /// calculations treat malformed details as "no next date", so this is
+    /// where the user learns what is wrong with them.
+    pub fn validate_schedule(schedule: &RepeatSchedule) -> Result<(), String> {
+        let detail = schedule.repeat_detail.as_deref();
+        match schedule.repeat_type {
+            RepeatType::None | RepeatType::Daily | RepeatType::AfterCompletion => {}
+            RepeatType::Weekly => {
+                // JSON array of weekdays [0-6], 0=Sunday
+                let days = Self::parse_detail_list(detail, "weekdays")?;
+                if let Some(day) = days.iter().find(|day| **day > 6) {
+                    return Err(format!("Invalid weekday: {} (expected 0-6)", day));
+                }
+            }
+            RepeatType::Monthly => {
+                // JSON array of days [1-31]
+                let days = Self::parse_detail_list(detail, "days of the month")?;
+                if let Some(day) = days.iter().find(|day| !(1..=31).contains(*day)) {
+                    return Err(format!("Invalid day of the month: {} (expected 1-31)", day));
+                }
+            }
+            RepeatType::Yearly => {
+                // JSON array [month, day]; Feb 29 is allowed
+                let values = Self::parse_detail_list(detail, "[month, day]")?;
+                let [month, day] = values[..] else {
+                    return Err("Yearly repeat needs [month, day]".to_string());
+                };
+                if NaiveDate::from_ymd_opt(2024, month, day).is_none() {
+                    return Err(format!("Invalid yearly date: {}/{}", month, day));
+                }
+            }
+            RepeatType::Rule => {
+                RecurrenceRule::parse(detail.ok_or("Missing recurrence rule")?)?;
+            }
+        }
+
+        if schedule.repeat_interval < 1 {
+            return Err("Repeat interval must be at least 1".to_string());
+        }
+        if schedule.repeat_count.is_some_and(|count| count < 1) {
+            return Err("Occurrence count must be at least 1".to_string());
+        }
+
+        if let Some(until) = schedule.repeat_until.as_deref() {
+            let until =
+                Self::parse_date(until).ok_or_else(|| format!("Invalid end date: {}", until))?;
+            if schedule
+                .repeat_start
+                .as_deref()
+                .and_then(Self::parse_date)
+                .is_some_and(|start| until < start)
+            {
+                return Err("Repeat end date is before its start date".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_detail_list(detail: Option<&str>, what: &str) -> Result<Vec<u32>, String> {
+        let values: Vec<u32> = detail
+            .ok_or_else(|| format!("Missing {}", what))
+            .and_then(|s| {
+                serde_json::from_str(s).map_err(|_| format!("Expected a JSON list of {}", what))
+            })?;
+        if values.is_empty() {
+            return Err(format!("Select at least one of the {}", what));
+        }
+        Ok(values)
+    }
+
+    /// Next `count` due dates of a schedule after `start`, computed the same
+    /// way items get their next_due_at. Stops early when the series ends.
+    pub fn preview_occurrences(
+        schedule: RepeatSchedule,
+        start: NaiveDate,
+        count: usize,
+    ) -> Result<Vec<String>, String> {
+        Self::validate_schedule(&schedule)?;
+        if schedule.repeat_type == RepeatType::None {
+            return Ok(Vec::new());
+        }
+
+        let schedule = Self::normalize_schedule(schedule, start);
+        let limit = schedule
+            .repeat_count
+            .map_or(count, |total| count.min(total as usize))
+            .min(MAX_PREVIEW_OCCURRENCES);
+
+        let mut dates = Vec::with_capacity(limit);
+        let mut from = start;
+        while dates.len() < limit {
+            let Some(next) = Self::calculate_next_due(&schedule, from) else {
+                break;
+            };
+            from = NaiveDate::parse_from_str(&next, "%Y-%m-%d").map_err(|e| e.to_string())?;
+            dates.push(next);
+        }
+
+        Ok(dates)
+    }
+
+    /// Preview for unsaved settings: starts today in the user's time zone
+    /// unless `start_date` is given, and honours an exclusion calendar.
+    pub fn preview_schedule(
+        conn: &Connection,
+        schedule: RepeatSchedule,
+        start_date: Option<&str>,
+        count: usize,
+        exclusion_calendar_id: Option<i64>,
+    ) -> Result<Vec<String>, String> {
+        let start = match start_date {
+            Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| format!("Invalid start date: {}", date))?,
+            None => TimeZoneService::today(conn).map_err(|e| e.to_string())?,
+        };
+        let exclusions = match exclusion_calendar_id {
+            Some(calendar_id) => {
+                ExclusionRepository::get_ranges(conn, calendar_id).map_err(|e| e.to_string())?
+            }
+            None => Vec::new(),
+        };
+
+        Self::preview_occurrences(schedule.with_exclusions(exclusions), start, count)
+    }
+
+    /// Prepare repeat settings for storage. Repeating items get a start date
+    /// so intervals ("every 3 days") count from the day the repeat was set,
+    /// and custom rules without DTSTART are anchored to the same date.
+    pub fn normalize_schedule(mut schedule: RepeatSchedule, start: NaiveDate) -> RepeatSchedule {
+        if schedule.repeat_type == RepeatType::None {
+            schedule.repeat_interval = 1;
+            schedule.repeat_start = None;
+            schedule.repeat_until = None;
+            schedule.repeat_count = None;
+            return schedule;
+        }
+
+        schedule.repeat_interval = schedule.repeat_interval.max(1);
+        let anchor = schedule
+            .repeat_start
+            .as_deref()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+            .unwrap_or(start);
+        schedule.repeat_start = Some(anchor.format("%Y-%m-%d").to_string());
+
+        if schedule.repeat_type == RepeatType::Rule {
+            if let Some(rule) = schedule
+                .repeat_detail
+                .as_deref()
+                .and_then(|s| RecurrenceRule::parse(s).ok())
+            {
+                schedule.repeat_detail = Some(rule.with_default_start(anchor).to_detail());
+            }
+        }
+
+        schedule
+    }
+
+    /// Whether two schedules land on the same slots once anchored to the
+    /// same start date. Rules are compared without their DTSTART.
+    pub fn same_cadence(a: &RepeatSchedule, b: &RepeatSchedule) -> bool {
+        if a.repeat_type != b.repeat_type || a.repeat_interval.max(1) != b.repeat_interval.max(1) {
+            return false;
+        }
+        if a.repeat_type != RepeatType::Rule {
+            return a.repeat_detail == b.repeat_detail;
+        }
+
+        let rule = |schedule: &RepeatSchedule| {
+            let mut rule = RecurrenceRule::parse(schedule.repeat_detail.as_deref()?).ok()?;
+            rule.dtstart = None;
+            Some(rule)
+        };
+        match (rule(a), rule(b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => a.repeat_detail == b.repeat_detail,
+        }
+    }
+
     /// Toggle an item and handle repeat logic
     /// Returns the updated item
     pub fn toggle_with_repeat(
@@ -135,37 +503,75 @@ impl RepeatService {
         };
 
         if item.done {
-            // Unchecking: just toggle done to false
+            // Unchecking: toggle done to false. An item with due times goes
+            // back to the slot whose completion is undone.
+            let completed_slot = OccurrenceRepository::remove_latest_completed(conn, id)?
+                .filter(|_| item.due_times.is_some())
+                .and_then(|slot| due_time::parse_due_at(&slot));
+            if let Some(slot) = completed_slot {
+                item.next_due_at = Some(slot.date().format("%Y-%m-%d").to_string());
+                item.due_at = Some(due_time::format_due_at(slot));
+                TodoRepository::set_due_slot(
+                    conn,
+                    id,
+                    item.next_due_at.as_deref(),
+                    item.due_at.as_deref(),
+                )?;
+            }
             TodoRepository::set_done(conn, id, false, None, item.next_due_at.as_deref())?;
             item.done = false;
+            if item.repeat_ended {
+                TodoRepository::set_repeat_ended(conn, id, false)?;
+                item.repeat_ended = false;
+            }
+            // Unchecking a quantity item starts its count over
+            if item.quantity_progress > 0 {
+                TodoRepository::set_quantity_progress(conn, id, 0)?;
+                item.quantity_progress = 0;
+            }
+            // The undone completion no longer counts towards repeat_count
+            if item.repeat_type != RepeatType::None && item.repeat_completed > 0 {
+                item.repeat_completed -= 1;
+                TodoRepository::set_repeat_completed(conn, id, item.repeat_completed)?;
+            }
             // Remove completion from streak log if tracking
             if item.track_streak {
                 let _ = StreakService::remove_completion(conn, id);
             }
+            // A value recorded with the undone completion goes with it
+            if let Some(completed_at) = item.last_completed_at.as_deref() {
+                MeasurementRepository::remove_latest_since(conn, id, completed_at)?;
+            }
+            Self::reopen_finished_parent(conn, &item)?;
         } else {
-            Self::apply_completion(conn, id, &mut item)?;
+            Self::apply_completion(conn, id, &mut item, OccurrenceSource::App)?;
         }
 
         Ok(Some(item))
     }
 
-    /// Complete an item and handle repeat logic.
-    /// If the item is already done, this is a no-op.
-    pub fn complete_with_repeat(
+    /// Count one step towards an item's quantity target ("3/8 glasses").
+    /// The item completes once the target is reached; items without a
+    /// target complete straight away. Done items are left alone.
+    pub fn increment_progress(
         conn: &Connection,
         id: i64,
+        source: OccurrenceSource,
     ) -> Result<Option<TodoItem>, rusqlite::Error> {
-        let item = TodoRepository::get_by_id(conn, id)?;
-
-        let Some(mut item) = item else {
+        let Some(mut item) = TodoRepository::get_by_id(conn, id)? else {
             return Ok(None);
         };
-
         if item.done {
             return Ok(Some(item));
         }
 
-        Self::apply_completion(conn, id, &mut item)?;
+        let progress = item.quantity_progress + 1;
+        if progress >= item.quantity_target {
+            Self::apply_completion(conn, id, &mut item, source)?;
+        } else {
+            TodoRepository::set_quantity_progress(conn, id, progress)?;
+            item.quantity_progress = progress;
+        }
         Ok(Some(item))
     }
 
@@ -173,8 +579,36 @@ impl RepeatService {
         conn: &Connection,
         id: i64,
         item: &mut TodoItem,
+        source: OccurrenceSource,
     ) -> Result<(), rusqlite::Error> {
-        let today = Local::now().format("%Y-%m-%d").to_string();
+        let now = TimeZoneService::now(conn)?;
+        let today = now.format("%Y-%m-%d").to_string();
+        let times = due_time::parse_due_times(item.due_times.as_deref());
+        let timed_slot = Self::current_timed_slot(item, &times, now.naive_local());
+        let slot = match timed_slot {
+            Some(slot) => due_time::format_due_at(slot),
+            None => Self::current_slot(item, Self::logical_today(conn)?)
+                .format("%Y-%m-%d")
+                .to_string(),
+        };
+        // Only repeating items have scheduled slots to keep a ledger of
+        if item.repeat_type != RepeatType::None {
+            OccurrenceRepository::record(
+                conn,
+                id,
+                &slot,
+                OccurrenceStatus::Completed,
+                Some(&now.format("%Y-%m-%dT%H:%M:%S%:z").to_string()),
+                Some(source.clone()),
+            )?;
+            // A skipped day that gets completed after all is no longer a skip
+            SkipLogRepository::remove(conn, id, slot.get(..10).unwrap_or(&slot))?;
+        }
+        // Completing a quantity item fills its count
+        if item.quantity_target > 0 {
+            TodoRepository::set_quantity_progress(conn, id, item.quantity_target)?;
+            item.quantity_progress = item.quantity_target;
+        }
 
         if item.repeat_type == RepeatType::None {
             // No repeat: mark as done
@@ -182,47 +616,246 @@ impl RepeatService {
             item.done = true;
             item.last_completed_at = Some(today);
         } else {
-            // Has repeat: calculate next due date and mark as done
-            let today_date = Local::now().date_naive();
-            let next_due = Self::calculate_next_due(
-                &item.repeat_type,
-                item.repeat_detail.as_deref(),
-                today_date,
-            );
+            // Has repeat: calculate next due date and mark as done.
+            // An exhausted series gets no next_due_at and simply stays done.
+            let today_date = now.date_naive();
+            let schedule = Self::schedule_for(conn, item)?;
+            let repeat_completed = item.repeat_completed + 1;
+            let exhausted = schedule
+                .repeat_count
+                .is_some_and(|count| repeat_completed >= count);
+            let (next_due, due_at) = match timed_slot {
+                _ if exhausted => (None, None),
+                // Timed items wait for their next slot, possibly later today
+                Some(slot) => {
+                    let next = Self::next_timed_slot(&schedule, &times, slot);
+                    (
+                        next.map(|next| next.date().format("%Y-%m-%d").to_string()),
+                        next.map(due_time::format_due_at),
+                    )
+                }
+                None => (Self::calculate_next_due(&schedule, today_date), None),
+            };
+
+            let ended =
+                next_due.is_none() && Self::series_ended(&schedule, repeat_completed, today_date);
 
             TodoRepository::set_done(conn, id, true, Some(&today), next_due.as_deref())?;
+            TodoRepository::set_repeat_completed(conn, id, repeat_completed)?;
+            if ended {
+                TodoRepository::set_repeat_ended(conn, id, true)?;
+            }
+            if timed_slot.is_some() {
+                TodoRepository::set_due_slot(conn, id, next_due.as_deref(), due_at.as_deref())?;
+                item.due_at = due_at;
+            }
             item.done = true;
             item.last_completed_at = Some(today);
             item.next_due_at = next_due;
+            item.repeat_completed = repeat_completed;
+            item.repeat_ended = ended;
         }
 
         if item.track_streak {
             let _ = StreakService::log_completion(conn, id);
         }
 
+        Self::complete_finished_parent(conn, item, source)
+    }
+
+    /// Complete an auto-completing parent once its last open sub-item is done
+    fn complete_finished_parent(
+        conn: &Connection,
+        item: &TodoItem,
+        source: OccurrenceSource,
+    ) -> Result<(), rusqlite::Error> {
+        let Some(parent_id) = item.parent_id else {
+            return Ok(());
+        };
+        let Some(mut parent) = TodoRepository::get_by_id(conn, parent_id)? else {
+            return Ok(());
+        };
+        if !parent.auto_complete || parent.done {
+            return Ok(());
+        }
+
+        let children = TodoRepository::get_children(conn, parent_id)?;
+        if children.iter().all(|child| child.done) {
+            Self::apply_completion(conn, parent_id, &mut parent, source)?;
+        }
+        Ok(())
+    }
+
+    /// Reopen an auto-completing parent once one of its sub-items is open
+    /// again, undoing the completion the sub-items gave it
+    fn reopen_finished_parent(conn: &Connection, item: &TodoItem) -> Result<(), rusqlite::Error> {
+        let Some(parent_id) = item.parent_id else {
+            return Ok(());
+        };
+        let Some(parent) = TodoRepository::get_by_id(conn, parent_id)? else {
+            return Ok(());
+        };
+        if parent.auto_complete && parent.done {
+            Self::toggle_with_repeat(conn, parent_id)?;
+        }
         Ok(())
     }
 
+    /// Skip the current occurrence of a repeating item without completing it.
+    /// The item waits for its next occurrence and the skipped slot is logged
+    /// so streaks treat it as neutral.
+    pub fn skip_occurrence(conn: &Connection, id: i64) -> Result<Option<TodoItem>, String> {
+        let Some(mut item) = TodoRepository::get_by_id(conn, id).map_err(|e| e.to_string())?
+        else {
+            return Ok(None);
+        };
+        if item.repeat_type == RepeatType::None {
+            return Err("Only repeating items can be skipped".to_string());
+        }
+
+        let schedule = Self::schedule_for(conn, &item).map_err(|e| e.to_string())?;
+        let times = due_time::parse_due_times(item.due_times.as_deref());
+        let now = TimeZoneService::now(conn).map_err(|e| e.to_string())?;
+        if let Some(slot) = Self::current_timed_slot(&item, &times, now.naive_local()) {
+            Self::skip_timed_slot(conn, &mut item, &schedule, &times, slot)
+                .map_err(|e| e.to_string())?;
+            return Ok(Some(item));
+        }
+
+        let today = Self::logical_today(conn).map_err(|e| e.to_string())?;
+        let slot = Self::current_slot(&item, today);
+        let next_due = Self::calculate_next_due(&schedule, slot);
+
+        Self::record_skip(conn, id, slot).map_err(|e| e.to_string())?;
+        TodoRepository::defer(conn, id, next_due.as_deref()).map_err(|e| e.to_string())?;
+        if next_due.is_none() && Self::series_ended(&schedule, item.repeat_completed, slot) {
+            TodoRepository::set_repeat_ended(conn, id, true).map_err(|e| e.to_string())?;
+            item.repeat_ended = true;
+        }
+        item.done = true;
+        item.next_due_at = next_due;
+
+        Ok(Some(item))
+    }
+
+    /// Snooze a repeating item until `until`. Every occurrence passed over
+    /// on the way is logged as skipped.
+    pub fn snooze_occurrence(
+        conn: &Connection,
+        id: i64,
+        until: NaiveDate,
+    ) -> Result<Option<TodoItem>, String> {
+        let Some(mut item) = TodoRepository::get_by_id(conn, id).map_err(|e| e.to_string())?
+        else {
+            return Ok(None);
+        };
+        if item.repeat_type == RepeatType::None {
+            return Err("Only repeating items can be snoozed".to_string());
+        }
+
+        let today = Self::logical_today(conn).map_err(|e| e.to_string())?;
+        if until <= today {
+            return Err("Snooze date must be after today".to_string());
+        }
+
+        let schedule = Self::schedule_for(conn, &item).map_err(|e| e.to_string())?;
+        let mut slot = Some(Self::current_slot(&item, today));
+        // Bounded so a dense schedule can't turn a long snooze into thousands of rows
+        for _ in 0..366 {
+            let Some(date) = slot.filter(|date| *date < until) else {
+                break;
+            };
+            Self::record_skip(conn, id, date).map_err(|e| e.to_string())?;
+            slot = Self::calculate_next_due(&schedule, date)
+                .and_then(|next| NaiveDate::parse_from_str(&next, "%Y-%m-%d").ok());
+        }
+
+        let next_due = until.format("%Y-%m-%d").to_string();
+        TodoRepository::defer(conn, id, Some(&next_due)).map_err(|e| e.to_string())?;
+        let times = due_time::parse_due_times(item.due_times.as_deref());
+        if let Some(first) = times.first() {
+            let due_at = due_time::format_due_at(until.and_time(*first));
+            TodoRepository::set_due_slot(conn, id, Some(&next_due), Some(&due_at))
+                .map_err(|e| e.to_string())?;
+            item.due_at = Some(due_at);
+        }
+        item.done = true;
+        item.next_due_at = Some(next_due);
+
+        Ok(Some(item))
+    }
+
+    fn record_skip(conn: &Connection, id: i64, slot: NaiveDate) -> Result<(), rusqlite::Error> {
+        let slot = slot.format("%Y-%m-%d").to_string();
+        SkipLogRepository::add(conn, id, &slot)?;
+        OccurrenceRepository::record(
+            conn,
+            id,
+            &slot,
+            OccurrenceStatus::Skipped,
+            None,
+            Some(OccurrenceSource::App),
+        )
+    }
+
+    /// Get the occurrence ledger of an item for the last N days
+    pub fn get_occurrences(
+        conn: &Connection,
+        item_id: i64,
+        days: i32,
+    ) -> Result<Vec<Occurrence>, rusqlite::Error> {
+        let since = Self::logical_today(conn)? - chrono::Duration::days(i64::from(days));
+        OccurrenceRepository::get_for_item(conn, item_id, &since.format("%Y-%m-%d").to_string())
+    }
+
+    /// The occurrence a skip, snooze or completion applies to: the upcoming one for a done
+    /// item, otherwise the one that is currently due.
+    fn current_slot(item: &TodoItem, today: NaiveDate) -> NaiveDate {
+        let next_due = item
+            .next_due_at
+            .as_deref()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+
+        match next_due {
+            Some(date) if item.done || date <= today => date,
+            _ => today,
+        }
+    }
+
+    fn logical_today(conn: &Connection) -> Result<NaiveDate, rusqlite::Error> {
+        TimeZoneService::logical_today(conn)
+    }
+
     /// Process all repeating items and reactivate those whose due date has arrived
     /// Returns the number of items reactivated
     pub fn process_repeats(conn: &Connection) -> Result<i32, rusqlite::Error> {
-        // Get reset time from settings (default: "00:00")
-        let reset_time =
-            SettingsRepository::get(conn, "reset_time")?.unwrap_or_else(|| "00:00".to_string());
-
-        let logical_date = get_logical_date(&reset_time);
+        let logical_date = Self::logical_today(conn)?;
         let today = logical_date.format("%Y-%m-%d").to_string();
+        let now = due_time::format_due_at(TimeZoneService::now(conn)?.naive_local());
 
         let all_items = TodoRepository::get_all(conn)?;
 
         let mut reactivated = 0;
 
         for item in all_items {
+            if !item.done
+                && item.repeat_type != RepeatType::None
+                && Self::first_slot(&item).is_some_and(|slot| slot < logical_date)
+            {
+                Self::record_missed_slots(conn, &item, logical_date)?;
+            }
+
             // Only process done items with a repeat type and a next_due_at
             if item.done && item.repeat_type != RepeatType::None {
-                if let Some(ref next_due) = item.next_due_at {
+                // Items with due times come back at their slot, not at the day's start
+                if let Some(due_at) = item.due_at.as_deref().filter(|_| item.due_times.is_some()) {
+                    if now.as_str() >= due_at {
+                        TodoRepository::reactivate(conn, item.id)?;
+                        reactivated += 1;
+                    }
+                } else if let Some(next_due) = Self::pending_due_date(&item) {
                     // If today >= next_due_at, reactivate the item
-                    if today >= *next_due {
+                    if today >= next_due {
                         TodoRepository::reactivate(conn, item.id)?;
                         reactivated += 1;
                     }
@@ -232,4 +865,549 @@ impl RepeatService {
 
         Ok(reactivated)
     }
+
+    /// Date of the earliest slot an open item is still waiting on: the slot
+    /// of `due_at` for items with due times, `next_due_at` otherwise
+    fn first_slot(item: &TodoItem) -> Option<NaiveDate> {
+        let timed = item
+            .due_at
+            .as_deref()
+            .filter(|_| item.due_times.is_some())
+            .and_then(due_time::parse_due_at)
+            .map(|due_at| due_at.date());
+        timed.or_else(|| {
+            item.next_due_at
+                .as_deref()
+                .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+        })
+    }
+
+    /// Log every slot of an open item that came due before today as missed.
+    /// Re-running is harmless: slots that already have an entry are left alone.
+    fn record_missed_slots(
+        conn: &Connection,
+        item: &TodoItem,
+        today: NaiveDate,
+    ) -> Result<(), rusqlite::Error> {
+        let schedule = Self::schedule_for(conn, item)?;
+        let times = due_time::parse_due_times(item.due_times.as_deref());
+        if let Some(due_at) = item.due_at.as_deref().and_then(due_time::parse_due_at) {
+            if !times.is_empty() {
+                return Self::record_missed_timed_slots(
+                    conn, item, &schedule, &times, due_at, today,
+                );
+            }
+        }
+
+        let mut slot = item
+            .next_due_at
+            .as_deref()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+
+        for _ in 0..366 {
+            let Some(date) = slot.filter(|date| *date < today) else {
+                break;
+            };
+            OccurrenceRepository::record_missed(conn, item.id, &date.format("%Y-%m-%d").to_string())?;
+            slot = Self::calculate_next_due(&schedule, date)
+                .and_then(|next| NaiveDate::parse_from_str(&next, "%Y-%m-%d").ok());
+        }
+
+        Ok(())
+    }
+
+    /// Set the due datetime of a one-off item, or the daily due times of a
+    /// repeating one. Repeating items derive `due_at` from their slots.
+    pub fn update_due(
+        conn: &Connection,
+        id: i64,
+        due_at: Option<&str>,
+        due_times: &[String],
+    ) -> Result<Option<TodoItem>, String> {
+        let Some(mut item) = TodoRepository::get_by_id(conn, id).map_err(|e| e.to_string())?
+        else {
+            return Ok(None);
+        };
+
+        if let Some(time) = due_times
+            .iter()
+            .find(|time| NaiveTime::parse_from_str(time, "%H:%M").is_err())
+        {
+            return Err(format!("Invalid due time: {}", time));
+        }
+        let repeating = item.repeat_type != RepeatType::None;
+        if !due_times.is_empty() && !repeating {
+            return Err("Due times per day need a repeating item".to_string());
+        }
+        if due_at.is_some() && repeating {
+            return Err("Repeating items take due times instead of a due datetime".to_string());
+        }
+        let due_at = due_at
+            .map(|due_at| {
+                due_time::parse_due_at(due_at)
+                    .map(due_time::format_due_at)
+                    .ok_or_else(|| format!("Invalid due datetime: {}", due_at))
+            })
+            .transpose()?;
+
+        let mut times: Vec<&String> = due_times.iter().collect();
+        times.sort();
+        times.dedup();
+        item.due_times = (!times.is_empty())
+            .then(|| serde_json::to_string(&times).map_err(|e| e.to_string()))
+            .transpose()?;
+        item.due_at = match due_at {
+            Some(due_at) => Some(due_at),
+            None => Self::aligned_due_at(conn, &item).map_err(|e| e.to_string())?,
+        };
+
+        TodoRepository::update_due(conn, id, item.due_at.as_deref(), item.due_times.as_deref())
+            .map_err(|e| e.to_string())?;
+        Ok(Some(item))
+    }
+
+    /// Re-derive `due_at` after the repeat settings of an item changed.
+    /// Due times are dropped when the item no longer repeats.
+    pub fn realign_due(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        let Some(item) = TodoRepository::get_by_id(conn, id)? else {
+            return Ok(());
+        };
+        if item.due_times.is_none() {
+            return Ok(());
+        }
+
+        if item.repeat_type == RepeatType::None {
+            return TodoRepository::update_due(conn, id, None, None);
+        }
+        let due_at = Self::aligned_due_at(conn, &item)?;
+        TodoRepository::set_due_slot(conn, id, item.next_due_at.as_deref(), due_at.as_deref())
+    }
+
+    /// Slot an item with due times should point at: the open slot of today
+    /// while it is open, the first slot of its next due date once done
+    fn aligned_due_at(
+        conn: &Connection,
+        item: &TodoItem,
+    ) -> Result<Option<String>, rusqlite::Error> {
+        let times = due_time::parse_due_times(item.due_times.as_deref());
+        if times.is_empty() || item.repeat_type == RepeatType::None {
+            return Ok(None);
+        }
+
+        let now = TimeZoneService::now(conn)?.naive_local();
+        let slot = if item.done {
+            item.next_due_at
+                .as_deref()
+                .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+                .map(|date| date.and_time(times[0]))
+        } else {
+            due_time::open_slot(now.date(), &times, now)
+        };
+        Ok(slot.map(due_time::format_due_at))
+    }
+
+    /// The timed slot a completion or skip applies to, for repeating items
+    /// with due times
+    fn current_timed_slot(
+        item: &TodoItem,
+        times: &[NaiveTime],
+        now: NaiveDateTime,
+    ) -> Option<NaiveDateTime> {
+        if times.is_empty() || item.repeat_type == RepeatType::None {
+            return None;
+        }
+        item.due_at
+            .as_deref()
+            .and_then(due_time::parse_due_at)
+            .or_else(|| due_time::open_slot(now.date(), times, now))
+    }
+
+    /// The slot after `slot`: a later time the same day, else the first time
+    /// on the next scheduled day
+    fn next_timed_slot(
+        schedule: &RepeatSchedule,
+        times: &[NaiveTime],
+        slot: NaiveDateTime,
+    ) -> Option<NaiveDateTime> {
+        due_time::later_slot_same_day(times, slot).or_else(|| {
+            let next = Self::calculate_next_due(schedule, slot.date())?;
+            let date = NaiveDate::parse_from_str(&next, "%Y-%m-%d").ok()?;
+            Some(date.and_time(*times.first()?))
+        })
+    }
+
+    /// Skip one timed slot. The day only counts as skipped for streaks
+    /// once its last slot is skipped.
+    fn skip_timed_slot(
+        conn: &Connection,
+        item: &mut TodoItem,
+        schedule: &RepeatSchedule,
+        times: &[NaiveTime],
+        slot: NaiveDateTime,
+    ) -> Result<(), rusqlite::Error> {
+        let next = Self::next_timed_slot(schedule, times, slot);
+        OccurrenceRepository::record(
+            conn,
+            item.id,
+            &due_time::format_due_at(slot),
+            OccurrenceStatus::Skipped,
+            None,
+            Some(OccurrenceSource::App),
+        )?;
+        if next.is_none_or(|next| next.date() != slot.date()) {
+            SkipLogRepository::add(conn, item.id, &slot.date().format("%Y-%m-%d").to_string())?;
+        }
+
+        let next_due = next.map(|next| next.date().format("%Y-%m-%d").to_string());
+        let due_at = next.map(due_time::format_due_at);
+        TodoRepository::defer(conn, item.id, next_due.as_deref())?;
+        TodoRepository::set_due_slot(conn, item.id, next_due.as_deref(), due_at.as_deref())?;
+        item.done = true;
+        item.next_due_at = next_due;
+        item.due_at = due_at;
+        Ok(())
+    }
+
+    /// Timed variant of record_missed_slots. The open item then moves on to
+    /// the first slot of today, so its due time and reminder stay current.
+    fn record_missed_timed_slots(
+        conn: &Connection,
+        item: &TodoItem,
+        schedule: &RepeatSchedule,
+        times: &[NaiveTime],
+        due_at: NaiveDateTime,
+        today: NaiveDate,
+    ) -> Result<(), rusqlite::Error> {
+        let mut slot = Some(due_at);
+        // Bounded like the date-only variant, with room for several slots a day
+        for _ in 0..(366 * times.len()) {
+            let Some(current) = slot.filter(|slot| slot.date() < today) else {
+                break;
+            };
+            OccurrenceRepository::record_missed(conn, item.id, &due_time::format_due_at(current))?;
+            slot = Self::next_timed_slot(schedule, times, current);
+        }
+
+        if slot != Some(due_at) {
+            let next_due = slot.map(|slot| slot.date().format("%Y-%m-%d").to_string());
+            let due_at = slot.map(due_time::format_due_at);
+            TodoRepository::set_due_slot(conn, item.id, next_due.as_deref(), due_at.as_deref())?;
+        }
+        Ok(())
+    }
+
+    /// Due date a done item is waiting for. Completion-relative items that
+    /// arrived without next_due_at (e.g. from sync) derive it from
+    /// last_completed_at so they still come back.
+    fn pending_due_date(item: &TodoItem) -> Option<String> {
+        if item.next_due_at.is_some() || item.repeat_type != RepeatType::AfterCompletion {
+            return item.next_due_at.clone();
+        }
+
+        let last_completed = item
+            .last_completed_at
+            .as_deref()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())?;
+        Self::calculate_next_due(&RepeatSchedule::from_item(item), last_completed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn preview_lists_next_weekly_dates() {
+        // Mondays and Wednesdays, starting on Wednesday 2026-03-04
+        let schedule = RepeatSchedule::new(RepeatType::Weekly, Some("[1,3]"));
+        let dates = RepeatService::preview_occurrences(schedule, date("2026-03-04"), 3).unwrap();
+        assert_eq!(dates, vec!["2026-03-09", "2026-03-11", "2026-03-16"]);
+    }
+
+    #[test]
+    fn preview_stops_when_series_ends() {
+        let schedule =
+            RepeatSchedule::new(RepeatType::Daily, None).with_end(Some("2026-03-06"), None);
+        let dates = RepeatService::preview_occurrences(schedule, date("2026-03-04"), 10).unwrap();
+        assert_eq!(dates, vec!["2026-03-05", "2026-03-06"]);
+
+        let schedule = RepeatSchedule::new(RepeatType::Daily, None).with_end(None, Some(2));
+        let dates = RepeatService::preview_occurrences(schedule, date("2026-03-04"), 10).unwrap();
+        assert_eq!(dates.len(), 2);
+    }
+
+    #[test]
+    fn invalid_details_are_reported() {
+        let invalid = [
+            RepeatSchedule::new(RepeatType::Weekly, Some("[7]")),
+            RepeatSchedule::new(RepeatType::Weekly, Some("[]")),
+            RepeatSchedule::new(RepeatType::Monthly, Some("first monday")),
+            RepeatSchedule::new(RepeatType::Yearly, Some("[2,30]")),
+            RepeatSchedule::new(RepeatType::Rule, Some("FREQ=SOMETIMES")),
+            RepeatSchedule::new(RepeatType::Rule, Some("FREQ=WEEKLY;BYDAY=éA")),
+            RepeatSchedule::new(RepeatType::Rule, None),
+            RepeatSchedule::new(RepeatType::Daily, None).with_end(Some("next week"), None),
+            RepeatSchedule::new(RepeatType::Daily, None).with_interval(0),
+            RepeatSchedule::new(RepeatType::Daily, None).with_end(None, Some(0)),
+        ];
+        for schedule in invalid {
+            assert!(
+                RepeatService::preview_occurrences(schedule.clone(), date("2026-03-04"), 3)
+                    .is_err(),
+                "{:?} should be rejected",
+                schedule
+            );
+        }
+
+        let leap_day = RepeatSchedule::new(RepeatType::Yearly, Some("[2,29]"));
+        assert!(RepeatService::validate_schedule(&leap_day).is_ok());
+    }
+
+    #[test]
+    fn zero_interval_is_rejected_when_saving() {
+        use crate::service::TodoService;
+
+        let conn = crate::repository::open_in_memory();
+        let zero = RepeatSchedule::new(RepeatType::Daily, None).with_interval(0);
+        let error = "Repeat interval must be at least 1".to_string();
+
+        assert_eq!(
+            TodoService::create_item(&conn, "Stretch", None, zero.clone(), false, None).err(),
+            Some(error.clone())
+        );
+        let item = TodoService::create_item(
+            &conn,
+            "Stretch",
+            None,
+            RepeatSchedule::new(RepeatType::Daily, None),
+            false,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            TodoService::update_repeat(&conn, item.id, zero).err(),
+            Some(error)
+        );
+    }
+
+    #[test]
+    fn series_ends_only_at_its_bounds() {
+        let until =
+            RepeatSchedule::new(RepeatType::Weekly, Some("[1]")).with_end(Some("2026-03-11"), None);
+        assert!(!RepeatService::series_ended(&until, 0, date("2026-03-02")));
+        assert!(RepeatService::series_ended(&until, 0, date("2026-03-09")));
+
+        let counted = RepeatSchedule::new(RepeatType::Daily, None).with_end(None, Some(3));
+        assert!(!RepeatService::series_ended(
+            &counted,
+            2,
+            date("2026-03-09")
+        ));
+        assert!(RepeatService::series_ended(&counted, 3, date("2026-03-09")));
+
+        let rule = RepeatSchedule::new(
+            RepeatType::Rule,
+            Some("FREQ=WEEKLY;BYDAY=MO;UNTIL=20260311"),
+        );
+        assert!(RepeatService::series_ended(&rule, 0, date("2026-03-09")));
+
+        // No next date, but nothing ended it either
+        let april_31 = RepeatSchedule::new(RepeatType::Yearly, Some("[4,31]"));
+        assert!(!RepeatService::series_ended(
+            &april_31,
+            0,
+            date("2026-03-09")
+        ));
+    }
+
+    #[test]
+    fn impossible_yearly_date_has_no_rule() {
+        let april_31 = RepeatSchedule::new(RepeatType::Yearly, Some("[4,31]"));
+        assert!(RepeatService::schedule_rule(&april_31).is_none());
+        assert_eq!(
+            RepeatService::calculate_next_due(&april_31, date("2026-03-04")),
+            None
+        );
+    }
+
+    #[test]
+    fn biweekly_weeks_start_on_sunday() {
+        // Every other week on Sunday and Monday, from Sunday 2026-03-01
+        let mut schedule = RepeatSchedule::new(RepeatType::Weekly, Some("[0,1]")).with_interval(2);
+        schedule.repeat_start = Some("2026-03-01".to_string());
+        let dates = RepeatService::preview_occurrences(schedule, date("2026-02-28"), 4).unwrap();
+        assert_eq!(
+            dates,
+            vec!["2026-03-01", "2026-03-02", "2026-03-15", "2026-03-16"]
+        );
+    }
+
+    #[test]
+    fn normalize_keeps_given_start() {
+        let mut schedule = RepeatSchedule::new(RepeatType::Rule, Some("FREQ=DAILY;INTERVAL=3"));
+        schedule.repeat_start = Some("2026-01-10".to_string());
+        let schedule = RepeatService::normalize_schedule(schedule, date("2026-03-04"));
+
+        assert_eq!(schedule.repeat_start.as_deref(), Some("2026-01-10"));
+        assert_eq!(
+            schedule.repeat_detail.as_deref(),
+            Some("DTSTART:20260110\nRRULE:FREQ=DAILY;INTERVAL=3")
+        );
+    }
+
+    #[test]
+    fn same_cadence_ignores_rule_start() {
+        let stored = RepeatSchedule::new(
+            RepeatType::Rule,
+            Some("DTSTART:20260110\nRRULE:FREQ=WEEKLY;BYDAY=MO"),
+        );
+        let edited = RepeatSchedule::new(RepeatType::Rule, Some("RRULE:FREQ=WEEKLY;BYDAY=MO"));
+        assert!(RepeatService::same_cadence(&stored, &edited));
+
+        let weekly = RepeatSchedule::new(RepeatType::Weekly, Some("[1]"));
+        assert!(!RepeatService::same_cadence(
+            &weekly,
+            &weekly.clone().with_interval(2)
+        ));
+        assert!(!RepeatService::same_cadence(
+            &weekly,
+            &RepeatSchedule::new(RepeatType::Weekly, Some("[2]"))
+        ));
+    }
+
+    #[test]
+    fn only_repeating_items_get_occurrences() {
+        use crate::service::TodoService;
+
+        let conn = crate::repository::open_in_memory();
+        let once = TodoService::create_item(
+            &conn,
+            "Call the bank",
+            None,
+            RepeatSchedule::new(RepeatType::None, None),
+            false,
+            None,
+        )
+        .unwrap();
+        let daily = TodoService::create_item(
+            &conn,
+            "Stretch",
+            None,
+            RepeatSchedule::new(RepeatType::Daily, None),
+            false,
+            None,
+        )
+        .unwrap();
+
+        RepeatService::toggle_with_repeat(&conn, once.id).unwrap();
+        RepeatService::toggle_with_repeat(&conn, daily.id).unwrap();
+
+        assert!(RepeatService::get_occurrences(&conn, once.id, 7)
+            .unwrap()
+            .is_empty());
+        let ledger = RepeatService::get_occurrences(&conn, daily.id, 7).unwrap();
+        assert_eq!(ledger.len(), 1);
+        assert_eq!(ledger[0].status, OccurrenceStatus::Completed);
+    }
+
+    #[test]
+    fn only_overdue_items_get_missed_slots() {
+        use crate::service::TodoService;
+
+        let conn = crate::repository::open_in_memory();
+        let today = TimeZoneService::logical_today(&conn).unwrap();
+        let create = |title: &str, next_due: NaiveDate| {
+            let item = TodoService::create_item(
+                &conn,
+                title,
+                None,
+                RepeatSchedule::new(RepeatType::Daily, None),
+                false,
+                None,
+            )
+            .unwrap();
+            conn.execute(
+                "UPDATE todos SET next_due_at = ?1 WHERE id = ?2",
+                rusqlite::params![next_due.format("%Y-%m-%d").to_string(), item.id],
+            )
+            .unwrap();
+            TodoRepository::get_by_id(&conn, item.id).unwrap().unwrap()
+        };
+        let overdue = create("Stretch", today - chrono::Duration::days(2));
+        let current = create("Read", today);
+
+        assert_eq!(RepeatService::first_slot(&current), Some(today));
+        RepeatService::process_repeats(&conn).unwrap();
+
+        let missed = RepeatService::get_occurrences(&conn, overdue.id, 7).unwrap();
+        assert_eq!(missed.len(), 2);
+        assert!(missed.iter().all(|o| o.status == OccurrenceStatus::Missed));
+        assert!(RepeatService::get_occurrences(&conn, current.id, 7)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn progress_counts_up_to_the_quantity_target() {
+        use crate::service::TodoService;
+
+        let conn = crate::repository::open_in_memory();
+        let item = TodoService::create_item(
+            &conn,
+            "Drink water",
+            None,
+            RepeatSchedule::new(RepeatType::Daily, None),
+            false,
+            None,
+        )
+        .unwrap();
+        TodoService::update_quantity_target(&conn, item.id, 3).unwrap();
+        let increment = || {
+            RepeatService::increment_progress(&conn, item.id, OccurrenceSource::App)
+                .unwrap()
+                .unwrap()
+        };
+
+        let item = increment();
+        assert_eq!((item.quantity_progress, item.done), (1, false));
+        increment();
+        let item = increment();
+        assert_eq!((item.quantity_progress, item.done), (3, true));
+        assert_eq!(item.repeat_completed, 1);
+
+        // A done item is left alone
+        let item = increment();
+        assert_eq!((item.quantity_progress, item.repeat_completed), (3, 1));
+
+        // Unchecking starts the count over
+        let item = RepeatService::toggle_with_repeat(&conn, item.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!((item.quantity_progress, item.done), (0, false));
+    }
+
+    #[test]
+    fn progress_without_target_completes_at_once() {
+        use crate::service::TodoService;
+
+        let conn = crate::repository::open_in_memory();
+        let item = TodoService::create_item(
+            &conn,
+            "Post the letter",
+            None,
+            RepeatSchedule::new(RepeatType::None, None),
+            false,
+            None,
+        )
+        .unwrap();
+
+        let item = RepeatService::increment_progress(&conn, item.id, OccurrenceSource::App)
+            .unwrap()
+            .unwrap();
+        assert!(item.done);
+    }
 }
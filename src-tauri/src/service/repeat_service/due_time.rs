@@ -0,0 +1,86 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Format of `due_at` and of timed occurrence slots, in the user's time zone
+pub const DUE_AT_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
+/// Parse `due_times` (JSON array of "HH:MM") into sorted, distinct times
+pub fn parse_due_times(due_times: Option<&str>) -> Vec<NaiveTime> {
+    let mut times: Vec<NaiveTime> = due_times
+        .and_then(|s| serde_json::from_str::<Vec<String>>(s).ok())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|time| NaiveTime::parse_from_str(time, "%H:%M").ok())
+        .collect();
+    times.sort();
+    times.dedup();
+    times
+}
+
+pub fn parse_due_at(due_at: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(due_at, DUE_AT_FORMAT).ok()
+}
+
+pub fn format_due_at(due_at: NaiveDateTime) -> String {
+    due_at.format(DUE_AT_FORMAT).to_string()
+}
+
+/// The slot of `date` that is open at `now`: the latest one that has
+/// started, or the first one while none has
+pub fn open_slot(
+    date: NaiveDate,
+    times: &[NaiveTime],
+    now: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    let first = date.and_time(*times.first()?);
+    Some(
+        times
+            .iter()
+            .map(|time| date.and_time(*time))
+            .filter(|slot| *slot <= now)
+            .max()
+            .unwrap_or(first),
+    )
+}
+
+/// The next slot on the same day as `slot`, if any
+pub fn later_slot_same_day(times: &[NaiveTime], slot: NaiveDateTime) -> Option<NaiveDateTime> {
+    times
+        .iter()
+        .find(|time| **time > slot.time())
+        .map(|time| slot.date().and_time(*time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> NaiveDateTime {
+        parse_due_at(s).unwrap()
+    }
+
+    #[test]
+    fn slots_follow_the_day() {
+        let times = parse_due_times(Some(r#"["20:00","08:00","08:00"]"#));
+        assert_eq!(times.len(), 2);
+
+        let date = at("2026-03-04T00:00").date();
+        assert_eq!(
+            open_slot(date, &times, at("2026-03-04T06:30")),
+            Some(at("2026-03-04T08:00"))
+        );
+        assert_eq!(
+            open_slot(date, &times, at("2026-03-04T12:00")),
+            Some(at("2026-03-04T08:00"))
+        );
+        assert_eq!(
+            open_slot(date, &times, at("2026-03-04T21:00")),
+            Some(at("2026-03-04T20:00"))
+        );
+
+        assert_eq!(
+            later_slot_same_day(&times, at("2026-03-04T08:00")),
+            Some(at("2026-03-04T20:00"))
+        );
+        assert_eq!(later_slot_same_day(&times, at("2026-03-04T20:00")), None);
+    }
+}
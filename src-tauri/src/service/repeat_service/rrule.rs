@@ -0,0 +1,765 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{Datelike, Months, NaiveDate, Weekday};
+
+/// Upper bound on how many periods are scanned when looking for the next occurrence.
+/// Large enough for "Feb 29 every 4 years" style rules without risking runaway loops.
+const MAX_PERIOD_SCAN: u32 = 1000;
+
+/// Largest COUNT accepted. The end of a counted series is found by walking
+/// it, so an unbounded COUNT would stall every read of the item.
+const MAX_COUNT: u32 = 10_000;
+
+/// How many series ends are kept before the cache starts over
+const COUNT_END_CACHE_SIZE: usize = 1024;
+
+/// Ends of counted series by rule, so parsing a rule again on the next read
+/// doesn't walk the series again
+fn count_end_cache() -> &'static Mutex<HashMap<String, NaiveDate>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, NaiveDate>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A BYDAY entry such as `MO`, `2TU` or `-1FR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdayRule {
+    pub ordinal: Option<i32>,
+    pub weekday: Weekday,
+}
+
+/// Parsed subset of an RFC 5545 recurrence rule.
+///
+/// `repeat_detail` stores the rule as content lines, e.g.
+/// `DTSTART:20260302\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH`.
+/// Only date-level recurrence is supported (no BYHOUR/BYMINUTE).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<WeekdayRule>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub by_set_pos: Vec<i32>,
+    pub week_start: Weekday,
+    pub dtstart: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+    pub count: Option<u32>,
+    /// Last occurrence of a COUNT-bounded series, worked out once per rule
+    /// so checking a date doesn't walk the series again
+    count_end: Option<NaiveDate>,
+}
+
+impl RecurrenceRule {
+    /// Parse a rule from `repeat_detail`.
+    /// Accepts a bare `FREQ=...` value, an `RRULE:` line, and an optional `DTSTART:` line.
+    pub fn parse(detail: &str) -> Result<Self, String> {
+        let mut dtstart = None;
+        let mut rule_value = None;
+
+        for line in detail
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+        {
+            let upper = line.to_ascii_uppercase();
+            if let Some(value) = upper.strip_prefix("DTSTART") {
+                let value = value.rsplit(':').next().unwrap_or_default();
+                dtstart = Some(parse_date_value(value)?);
+            } else if let Some(value) = upper.strip_prefix("RRULE:") {
+                rule_value = Some(value.to_string());
+            } else if upper.starts_with("FREQ=") || upper.contains(";FREQ=") {
+                rule_value = Some(upper);
+            } else {
+                return Err(format!("Unsupported recurrence line: {}", line));
+            }
+        }
+
+        let rule_value = rule_value.ok_or("Missing RRULE")?;
+        let mut rule = Self::parse_rrule_value(&rule_value)?;
+        rule.dtstart = dtstart;
+
+        if let Some(count) = rule.count {
+            let dtstart = rule.dtstart.ok_or("COUNT requires DTSTART")?;
+            rule.count_end = Some(rule.cached_count_end(dtstart, count));
+        }
+
+        Ok(rule)
+    }
+
+    /// A rule with the given frequency and no BY* parts, repeating every period.
+    pub fn new(freq: Frequency) -> Self {
+        Self {
+            freq,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Mon,
+            dtstart: None,
+            until: None,
+            count: None,
+            count_end: None,
+        }
+    }
+
+    fn parse_rrule_value(value: &str) -> Result<Self, String> {
+        let mut freq = None;
+        let mut rule = Self::new(Frequency::Daily);
+
+        for part in value.split(';').filter(|part| !part.is_empty()) {
+            let (key, val) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid rule part: {}", part))?;
+
+            match key {
+                "FREQ" => {
+                    freq = Some(match val {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(format!("Unsupported FREQ: {}", val)),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = val
+                        .parse()
+                        .ok()
+                        .filter(|interval| *interval >= 1)
+                        .ok_or_else(|| format!("Invalid INTERVAL: {}", val))?;
+                }
+                "BYDAY" => {
+                    rule.by_day = val
+                        .split(',')
+                        .map(parse_weekday_rule)
+                        .collect::<Result<_, _>>()?;
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day =
+                        parse_int_list(val, |day| day != 0 && (-31..=31).contains(&day))
+                            .ok_or_else(|| format!("Invalid BYMONTHDAY: {}", val))?;
+                }
+                "BYMONTH" => {
+                    rule.by_month = parse_int_list(val, |month| (1..=12).contains(&month))
+                        .ok_or_else(|| format!("Invalid BYMONTH: {}", val))?
+                        .into_iter()
+                        .map(|month| month as u32)
+                        .collect();
+                }
+                "BYSETPOS" => {
+                    rule.by_set_pos =
+                        parse_int_list(val, |pos| pos != 0 && (-366..=366).contains(&pos))
+                            .ok_or_else(|| format!("Invalid BYSETPOS: {}", val))?;
+                }
+                "WKST" => {
+                    rule.week_start =
+                        parse_weekday(val).ok_or_else(|| format!("Invalid WKST: {}", val))?;
+                }
+                "UNTIL" => {
+                    rule.until = Some(parse_date_value(val)?);
+                }
+                "COUNT" => {
+                    rule.count = Some(
+                        val.parse()
+                            .ok()
+                            .filter(|count| (1..=MAX_COUNT).contains(count))
+                            .ok_or_else(|| format!("Invalid COUNT: {}", val))?,
+                    );
+                }
+                _ => return Err(format!("Unsupported rule part: {}", key)),
+            }
+        }
+
+        rule.freq = freq.ok_or("Missing FREQ")?;
+
+        if rule.until.is_some() && rule.count.is_some() {
+            return Err("UNTIL and COUNT cannot both be set".to_string());
+        }
+
+        Ok(rule)
+    }
+
+    /// Serialize the rule back into the content-line format used by `repeat_detail`.
+    pub fn to_detail(&self) -> String {
+        let mut parts = vec![format!("FREQ={}", self.freq.as_str())];
+
+        if self.interval > 1 {
+            parts.push(format!("INTERVAL={}", self.interval));
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|rule| match rule.ordinal {
+                    Some(ordinal) => format!("{}{}", ordinal, weekday_code(rule.weekday)),
+                    None => weekday_code(rule.weekday).to_string(),
+                })
+                .collect();
+            parts.push(format!("BYDAY={}", days.join(",")));
+        }
+        if !self.by_month_day.is_empty() {
+            parts.push(format!("BYMONTHDAY={}", join_ints(&self.by_month_day)));
+        }
+        if !self.by_month.is_empty() {
+            let months: Vec<i32> = self.by_month.iter().map(|month| *month as i32).collect();
+            parts.push(format!("BYMONTH={}", join_ints(&months)));
+        }
+        if !self.by_set_pos.is_empty() {
+            parts.push(format!("BYSETPOS={}", join_ints(&self.by_set_pos)));
+        }
+        if self.week_start != Weekday::Mon {
+            parts.push(format!("WKST={}", weekday_code(self.week_start)));
+        }
+        if let Some(until) = self.until {
+            parts.push(format!("UNTIL={}", until.format("%Y%m%d")));
+        }
+        if let Some(count) = self.count {
+            parts.push(format!("COUNT={}", count));
+        }
+
+        let rrule = format!("RRULE:{}", parts.join(";"));
+        match self.dtstart {
+            Some(dtstart) => format!("DTSTART:{}\n{}", dtstart.format("%Y%m%d"), rrule),
+            None => rrule,
+        }
+    }
+
+    /// Pin the rule to a start date if it has none, so intervals are counted
+    /// from the day the rule was saved rather than from an arbitrary epoch.
+    pub fn with_default_start(mut self, start: NaiveDate) -> Self {
+        if self.dtstart.is_none() {
+            self.dtstart = Some(start);
+        }
+        self
+    }
+
+    /// Whether the rule produces an occurrence on `date`.
+    pub fn is_scheduled_on(&self, date: NaiveDate) -> bool {
+        if !self.is_within_bounds(date) {
+            return false;
+        }
+
+        let period = self.period_start(date);
+        if !self.is_active_period(period) {
+            return false;
+        }
+
+        if !self.occurrences_in_period(period).contains(&date) {
+            return false;
+        }
+
+        self.is_before_count_end(date)
+    }
+
+    /// First occurrence strictly after `date`, or `None` once the series has ended.
+    pub fn next_after(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let next = self.next_after_ignoring_count(date)?;
+        self.is_before_count_end(next).then_some(next)
+    }
+
+    fn next_after_ignoring_count(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let search_from = match self.dtstart {
+            Some(dtstart) if dtstart > date => dtstart.pred_opt()?,
+            _ => date,
+        };
+
+        let mut period = self.first_active_period_from(self.period_start(search_from));
+
+        for _ in 0..MAX_PERIOD_SCAN {
+            if let Some(until) = self.until {
+                if period > until {
+                    return None;
+                }
+            }
+
+            let next = self
+                .occurrences_in_period(period)
+                .into_iter()
+                .find(|candidate| *candidate > search_from && self.is_within_bounds(*candidate));
+
+            if next.is_some() {
+                return next;
+            }
+
+            period = self.add_periods(period, self.interval)?;
+        }
+
+        None
+    }
+
+    /// `nth_occurrence` of the rule, from the cache when it was parsed before
+    fn cached_count_end(&self, dtstart: NaiveDate, count: u32) -> NaiveDate {
+        let key = self.to_detail();
+        if let Some(end) = count_end_cache()
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(&key).copied())
+        {
+            return end;
+        }
+
+        let end = self.nth_occurrence(dtstart, count);
+        if let Ok(mut cache) = count_end_cache().lock() {
+            if cache.len() >= COUNT_END_CACHE_SIZE {
+                cache.clear();
+            }
+            cache.insert(key, end);
+        }
+        end
+    }
+
+    /// Date of the `count`-th occurrence from `dtstart`, or of the last one
+    /// when the series has fewer. Before `dtstart` when it has none.
+    fn nth_occurrence(&self, dtstart: NaiveDate, count: u32) -> NaiveDate {
+        let mut cursor = dtstart.pred_opt().unwrap_or(dtstart);
+        for _ in 0..count {
+            match self.next_after_ignoring_count(cursor) {
+                Some(next) => cursor = next,
+                None => break,
+            }
+        }
+        cursor
+    }
+
+    /// Whether the rule's own UNTIL or COUNT leaves no occurrence after
+    /// `date`. A rule that just finds no next date hasn't ended.
+    pub fn has_ended_after(&self, date: NaiveDate) -> bool {
+        if !self.is_before_count_end(date.succ_opt().unwrap_or(date)) {
+            return true;
+        }
+        let Some(until) = self.until else {
+            return false;
+        };
+        let mut unbounded = self.clone();
+        unbounded.until = None;
+        date >= until || unbounded.next_after(date).is_some_and(|next| next > until)
+    }
+
+    fn is_before_count_end(&self, date: NaiveDate) -> bool {
+        match self.count_end {
+            Some(end) => date <= end,
+            None => true,
+        }
+    }
+
+    fn is_within_bounds(&self, date: NaiveDate) -> bool {
+        if let Some(dtstart) = self.dtstart {
+            if date < dtstart {
+                return false;
+            }
+        }
+        if let Some(until) = self.until {
+            if date > until {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Reference date for interval counting and for fields implied by DTSTART.
+    fn anchor(&self) -> NaiveDate {
+        self.dtstart
+            .unwrap_or_else(|| NaiveDate::from_ymd_opt(1970, 1, 1).unwrap_or(NaiveDate::MIN))
+    }
+
+    fn period_start(&self, date: NaiveDate) -> NaiveDate {
+        match self.freq {
+            Frequency::Daily => date,
+            Frequency::Weekly => {
+                let offset = (7 + date.weekday().num_days_from_monday()
+                    - self.week_start.num_days_from_monday())
+                    % 7;
+                date - chrono::Duration::days(offset as i64)
+            }
+            Frequency::Monthly => date.with_day(1).unwrap_or(date),
+            Frequency::Yearly => NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap_or(date),
+        }
+    }
+
+    fn period_index(&self, period: NaiveDate) -> i64 {
+        let anchor = self.period_start(self.anchor());
+        match self.freq {
+            Frequency::Daily => (period - anchor).num_days(),
+            Frequency::Weekly => (period - anchor).num_days() / 7,
+            Frequency::Monthly => {
+                (period.year() as i64 * 12 + period.month0() as i64)
+                    - (anchor.year() as i64 * 12 + anchor.month0() as i64)
+            }
+            Frequency::Yearly => (period.year() - anchor.year()) as i64,
+        }
+    }
+
+    fn is_active_period(&self, period: NaiveDate) -> bool {
+        let index = self.period_index(period);
+        index >= 0 && index % self.interval as i64 == 0
+    }
+
+    fn first_active_period_from(&self, period: NaiveDate) -> NaiveDate {
+        let index = self.period_index(period);
+        if index < 0 {
+            return self.period_start(self.anchor());
+        }
+
+        let remainder = (index % self.interval as i64) as u32;
+        if remainder == 0 {
+            period
+        } else {
+            self.add_periods(period, self.interval - remainder)
+                .unwrap_or(period)
+        }
+    }
+
+    fn add_periods(&self, period: NaiveDate, count: u32) -> Option<NaiveDate> {
+        match self.freq {
+            Frequency::Daily => period.checked_add_signed(chrono::Duration::days(count as i64)),
+            Frequency::Weekly => {
+                period.checked_add_signed(chrono::Duration::days(count as i64 * 7))
+            }
+            Frequency::Monthly => period.checked_add_months(Months::new(count)),
+            Frequency::Yearly => period.checked_add_months(Months::new(count * 12)),
+        }
+    }
+
+    fn period_end(&self, period: NaiveDate) -> NaiveDate {
+        self.add_periods(period, 1).unwrap_or(NaiveDate::MAX)
+    }
+
+    /// All dates produced by the BY* parts inside a single period, sorted ascending.
+    fn occurrences_in_period(&self, period: NaiveDate) -> Vec<NaiveDate> {
+        let anchor = self.anchor();
+        let end = self.period_end(period);
+
+        let mut dates = Vec::new();
+        let mut day = period;
+        while day < end {
+            if self.matches_by_parts(day, anchor) {
+                dates.push(day);
+            }
+            match day.succ_opt() {
+                Some(next) => day = next,
+                None => break,
+            }
+        }
+
+        if self.by_set_pos.is_empty() {
+            return dates;
+        }
+
+        let len = dates.len() as i32;
+        let mut selected: Vec<NaiveDate> = self
+            .by_set_pos
+            .iter()
+            .filter_map(|pos| {
+                let index = if *pos > 0 { pos - 1 } else { len + pos };
+                (0..len).contains(&index).then(|| dates[index as usize])
+            })
+            .collect();
+        selected.sort();
+        selected.dedup();
+        selected
+    }
+
+    fn matches_by_parts(&self, date: NaiveDate, anchor: NaiveDate) -> bool {
+        if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+            return false;
+        }
+
+        if !self.by_month_day.is_empty() && !self.matches_month_day(date) {
+            return false;
+        }
+
+        if !self.by_day.is_empty()
+            && !self
+                .by_day
+                .iter()
+                .any(|rule| self.matches_weekday(date, rule))
+        {
+            return false;
+        }
+
+        // Fields implied by DTSTART when the rule doesn't narrow the period itself.
+        let has_day_filter = !self.by_day.is_empty() || !self.by_month_day.is_empty();
+        match self.freq {
+            Frequency::Daily => true,
+            Frequency::Weekly => has_day_filter || date.weekday() == anchor.weekday(),
+            Frequency::Monthly => has_day_filter || date.day() == anchor.day(),
+            Frequency::Yearly => {
+                if has_day_filter {
+                    true
+                } else if !self.by_month.is_empty() {
+                    date.day() == anchor.day()
+                } else {
+                    date.month() == anchor.month() && date.day() == anchor.day()
+                }
+            }
+        }
+    }
+
+    fn matches_month_day(&self, date: NaiveDate) -> bool {
+        let days_in_month = days_in_month(date) as i32;
+        let day = date.day() as i32;
+        self.by_month_day
+            .iter()
+            .any(|target| *target == day || *target == day - days_in_month - 1)
+    }
+
+    fn matches_weekday(&self, date: NaiveDate, rule: &WeekdayRule) -> bool {
+        if date.weekday() != rule.weekday {
+            return false;
+        }
+
+        let Some(ordinal) = rule.ordinal else {
+            return true;
+        };
+
+        // Ordinals are relative to the month for MONTHLY rules (and YEARLY rules
+        // narrowed by BYMONTH), otherwise to the year.
+        let (scope_position, scope_len) = match self.freq {
+            Frequency::Yearly if self.by_month.is_empty() => {
+                (date.ordinal() as i32, days_in_year(date.year()) as i32)
+            }
+            Frequency::Monthly | Frequency::Yearly => {
+                (date.day() as i32, days_in_month(date) as i32)
+            }
+            Frequency::Daily | Frequency::Weekly => return true,
+        };
+
+        let from_start = (scope_position - 1) / 7 + 1;
+        let from_end = -((scope_len - scope_position) / 7 + 1);
+        ordinal == from_start || ordinal == from_end
+    }
+}
+
+impl Frequency {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+}
+
+fn parse_date_value(value: &str) -> Result<NaiveDate, String> {
+    // Accept both DATE (20260301) and DATE-TIME (20260301T000000Z) forms.
+    let date_part = value.trim().get(..8).unwrap_or_default();
+    NaiveDate::parse_from_str(date_part, "%Y%m%d").map_err(|_| format!("Invalid date: {}", value))
+}
+
+fn parse_int_list(value: &str, is_valid: impl Fn(i32) -> bool) -> Option<Vec<i32>> {
+    value
+        .split(',')
+        .map(|item| item.trim().parse::<i32>().ok().filter(|n| is_valid(*n)))
+        .collect()
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    match code {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_weekday_rule(value: &str) -> Result<WeekdayRule, String> {
+    let value = value.trim();
+    let split_at = value.len().saturating_sub(2);
+    // The weekday code is two ASCII letters; anything else can't be split there
+    if !value.is_char_boundary(split_at) {
+        return Err(format!("Invalid BYDAY: {}", value));
+    }
+    let (ordinal_part, code) = value.split_at(split_at);
+    let weekday = parse_weekday(code).ok_or_else(|| format!("Invalid BYDAY: {}", value))?;
+
+    let ordinal = if ordinal_part.is_empty() {
+        None
+    } else {
+        let ordinal: i32 = ordinal_part
+            .trim_start_matches('+')
+            .parse()
+            .map_err(|_| format!("Invalid BYDAY: {}", value))?;
+        if ordinal == 0 || !(-53..=53).contains(&ordinal) {
+            return Err(format!("Invalid BYDAY: {}", value));
+        }
+        Some(ordinal)
+    };
+
+    Ok(WeekdayRule { ordinal, weekday })
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn join_ints(values: &[i32]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let first = date.with_day(1).unwrap_or(date);
+    let next_month = first.checked_add_months(Months::new(1)).unwrap_or(first);
+    (next_month - first).num_days() as u32
+}
+
+fn days_in_year(year: i32) -> u32 {
+    if NaiveDate::from_ymd_opt(year, 2, 29).is_some() {
+        366
+    } else {
+        365
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecurrenceRule;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn next_dates(rule: &RecurrenceRule, from: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        let mut dates = Vec::new();
+        let mut cursor = from;
+        while dates.len() < n {
+            let Some(next) = rule.next_after(cursor) else {
+                break;
+            };
+            dates.push(next);
+            cursor = next;
+        }
+        dates
+    }
+
+    #[test]
+    fn every_two_weeks_on_monday_and_thursday() {
+        let rule =
+            RecurrenceRule::parse("DTSTART:20260302\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH")
+                .unwrap();
+
+        assert_eq!(
+            next_dates(&rule, date(2026, 3, 1), 4),
+            vec![
+                date(2026, 3, 2),
+                date(2026, 3, 5),
+                date(2026, 3, 16),
+                date(2026, 3, 19)
+            ]
+        );
+        assert!(!rule.is_scheduled_on(date(2026, 3, 9)));
+    }
+
+    #[test]
+    fn second_tuesday_of_the_month() {
+        let rule = RecurrenceRule::parse("RRULE:FREQ=MONTHLY;BYDAY=2TU").unwrap();
+
+        assert_eq!(
+            next_dates(&rule, date(2026, 3, 1), 3),
+            vec![date(2026, 3, 10), date(2026, 4, 14), date(2026, 5, 12)]
+        );
+    }
+
+    #[test]
+    fn last_weekday_of_the_month() {
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1").unwrap();
+
+        assert_eq!(
+            next_dates(&rule, date(2026, 1, 1), 3),
+            vec![date(2026, 1, 30), date(2026, 2, 27), date(2026, 3, 31)]
+        );
+    }
+
+    #[test]
+    fn every_three_days_counts_from_dtstart() {
+        let rule = RecurrenceRule::parse("DTSTART:20260301\nRRULE:FREQ=DAILY;INTERVAL=3").unwrap();
+
+        assert_eq!(rule.next_after(date(2026, 3, 1)), Some(date(2026, 3, 4)));
+        assert_eq!(rule.next_after(date(2026, 3, 5)), Some(date(2026, 3, 7)));
+        assert!(!rule.is_scheduled_on(date(2026, 3, 3)));
+    }
+
+    #[test]
+    fn count_and_until_end_the_series() {
+        let counted = RecurrenceRule::parse("DTSTART:20260301\nRRULE:FREQ=DAILY;COUNT=2").unwrap();
+        assert_eq!(counted.next_after(date(2026, 3, 1)), Some(date(2026, 3, 2)));
+        assert_eq!(counted.next_after(date(2026, 3, 2)), None);
+
+        let until = RecurrenceRule::parse("FREQ=WEEKLY;BYDAY=MO;UNTIL=20260310").unwrap();
+        assert_eq!(until.next_after(date(2026, 3, 1)), Some(date(2026, 3, 2)));
+        assert_eq!(until.next_after(date(2026, 3, 2)), Some(date(2026, 3, 9)));
+        assert_eq!(until.next_after(date(2026, 3, 9)), None);
+    }
+
+    #[test]
+    fn round_trips_through_detail_string() {
+        let detail = "DTSTART:20260302\nRRULE:FREQ=MONTHLY;INTERVAL=2;BYDAY=-1FR";
+        let rule = RecurrenceRule::parse(detail).unwrap();
+
+        assert_eq!(rule.to_detail(), detail);
+    }
+
+    #[test]
+    fn rejects_malformed_rules() {
+        assert!(RecurrenceRule::parse("RRULE:INTERVAL=2").is_err());
+        assert!(RecurrenceRule::parse("RRULE:FREQ=HOURLY").is_err());
+        assert!(RecurrenceRule::parse("RRULE:FREQ=WEEKLY;BYDAY=XX").is_err());
+        assert!(RecurrenceRule::parse("RRULE:FREQ=DAILY;COUNT=3").is_err());
+        assert!(
+            RecurrenceRule::parse("DTSTART:20260301\nRRULE:FREQ=DAILY;COUNT=4000000000").is_err()
+        );
+        assert!(RecurrenceRule::parse("DTSTART:20260301\nRRULE:FREQ=DAILY;COUNT=10001").is_err());
+        assert!(RecurrenceRule::parse("RRULE:FREQ=WEEKLY;BYDAY=éA").is_err());
+        assert!(RecurrenceRule::parse("RRULE:FREQ=WEEKLY;BYDAY=1é").is_err());
+    }
+
+    #[test]
+    fn counted_series_ends_after_count_occurrences() {
+        let rule = RecurrenceRule::parse("DTSTART:20260302\nRRULE:FREQ=WEEKLY;BYDAY=MO,TH;COUNT=3")
+            .unwrap();
+
+        assert!(rule.is_scheduled_on(date(2026, 3, 9)));
+        assert!(!rule.is_scheduled_on(date(2026, 3, 12)));
+        assert_eq!(rule.next_after(date(2026, 3, 5)), Some(date(2026, 3, 9)));
+        assert_eq!(rule.next_after(date(2026, 3, 9)), None);
+    }
+
+    #[test]
+    fn largest_count_ends_where_the_series_does() {
+        let detail = "DTSTART:20260301\nRRULE:FREQ=DAILY;COUNT=10000";
+        let last = date(2026, 3, 1) + chrono::Duration::days(9999);
+
+        for _ in 0..2 {
+            let rule = RecurrenceRule::parse(detail).unwrap();
+            assert!(rule.is_scheduled_on(last));
+            assert_eq!(rule.next_after(last), None);
+        }
+    }
+}
@@ -1,7 +1,12 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
 use rusqlite::Connection;
 
-use crate::repository::{SettingsRepository, TodoRepository};
-use crate::service::repeat_service::get_logical_date;
+use crate::models::{Category, ResetPolicy};
+use crate::repository::{CategoryRepository, SettingsRepository, TodoRepository};
+use crate::service::TimeZoneService;
+
+/// Format of `categories.last_reset_at`; sorts chronologically as text
+const RESET_AT_FORMAT: &str = "%Y-%m-%dT%H:%M";
 
 pub struct ResetService;
 
@@ -9,35 +14,72 @@ impl ResetService {
     /// Reset all items in a category (or all items if category_id is None)
     /// and update the last reset date
     pub fn reset_items(conn: &Connection, category_id: Option<i64>) -> Result<(), rusqlite::Error> {
-        TodoRepository::reset_all(conn, category_id)?;
+        // Use the logical date in the user's time zone
+        let today = TimeZoneService::logical_today(conn)?
+            .format("%Y-%m-%d")
+            .to_string();
+        TodoRepository::reset_all(conn, category_id, &today)?;
 
-        // Get reset time from settings and use logical date
-        let reset_time =
-            SettingsRepository::get(conn, "reset_time")?.unwrap_or_else(|| "00:00".to_string());
-        let logical_date = get_logical_date(&reset_time);
-        let today = logical_date.format("%Y-%m-%d").to_string();
+        match category_id {
+            Some(id) => Self::mark_category_reset(conn, id)?,
+            None => {
+                SettingsRepository::set(conn, "last_reset_date", &today)?;
 
-        SettingsRepository::set(conn, "last_reset_date", &today)?;
+                for category in CategoryRepository::get_all(conn)? {
+                    Self::mark_category_reset(conn, category.id)?;
+                }
+            }
+        }
 
         Ok(())
     }
 
-    /// Check if a new day has started and auto-reset if needed
-    /// Returns true if reset was performed, false otherwise
+    /// Treat the category as reset right now, so auto-reset waits for the
+    /// next boundary of its policy
+    pub fn mark_category_reset(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        let now = TimeZoneService::now(conn)?
+            .naive_local()
+            .format(RESET_AT_FORMAT)
+            .to_string();
+        CategoryRepository::set_last_reset_at(conn, id, &now)
+    }
+
+    /// Check each category's reset policy (and the global reset time for
+    /// uncategorized items) and auto-reset where a boundary has passed
+    /// Returns true if any reset was performed, false otherwise
     pub fn check_and_auto_reset(conn: &Connection) -> Result<bool, rusqlite::Error> {
-        // Get reset time from settings and use logical date
+        let mut reset = Self::reset_uncategorized_if_due(conn)?;
+
         let reset_time =
             SettingsRepository::get(conn, "reset_time")?.unwrap_or_else(|| "00:00".to_string());
-        let logical_date = get_logical_date(&reset_time);
+        let global_reset_time =
+            NaiveTime::parse_from_str(&reset_time, "%H:%M").unwrap_or(NaiveTime::MIN);
+        let now = TimeZoneService::now(conn)?.naive_local();
+
+        let today = TimeZoneService::logical_today(conn)?
+            .format("%Y-%m-%d")
+            .to_string();
+        for category in CategoryRepository::get_all(conn)? {
+            reset |= Self::reset_category_if_due(conn, &category, global_reset_time, now, &today)?;
+        }
+
+        Ok(reset)
+    }
+
+    fn reset_uncategorized_if_due(conn: &Connection) -> Result<bool, rusqlite::Error> {
+        // Use the logical date in the user's time zone
+        let logical_date = TimeZoneService::logical_today(conn)?;
         let today = logical_date.format("%Y-%m-%d").to_string();
 
         let last_reset = SettingsRepository::get(conn, "last_reset_date")?;
 
         match last_reset {
             Some(last_date) => {
-                if last_date != today {
-                    // New day, reset all items
-                    TodoRepository::reset_all(conn, None)?;
+                // Only move forward: after a zone change puts the logical
+                // date behind the last reset, wait instead of resetting again
+                if today > last_date {
+                    // New day, reset uncategorized items
+                    TodoRepository::reset_uncategorized(conn, &today)?;
                     SettingsRepository::set(conn, "last_reset_date", &today)?;
                     Ok(true)
                 } else {
@@ -51,4 +93,238 @@ impl ResetService {
             }
         }
     }
+
+    fn reset_category_if_due(
+        conn: &Connection,
+        category: &Category,
+        global_reset_time: NaiveTime,
+        now: NaiveDateTime,
+        today: &str,
+    ) -> Result<bool, rusqlite::Error> {
+        let Some(boundary) = Self::latest_reset_boundary(category, global_reset_time, now) else {
+            return Ok(false);
+        };
+        let boundary = boundary.format(RESET_AT_FORMAT).to_string();
+
+        match category.last_reset_at.as_deref() {
+            // Same forward-only rule as the global reset
+            Some(last) if boundary.as_str() <= last => Ok(false),
+            Some(_) => {
+                TodoRepository::reset_all(conn, Some(category.id), today)?;
+                CategoryRepository::set_last_reset_at(conn, category.id, &boundary)?;
+                Ok(true)
+            }
+            None => {
+                // First time, remember the boundary without resetting
+                CategoryRepository::set_last_reset_at(conn, category.id, &boundary)?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Latest moment at or before `now` at which the category's policy
+    /// resets it, or None for manual-only categories
+    pub fn latest_reset_boundary(
+        category: &Category,
+        global_reset_time: NaiveTime,
+        now: NaiveDateTime,
+    ) -> Option<NaiveDateTime> {
+        let (times, weekdays) = Self::reset_slots(category, global_reset_time)?;
+
+        (0..=7)
+            .map(|offset| now.date() - chrono::Duration::days(offset))
+            .filter(|date| Self::resets_on(weekdays.as_deref(), *date))
+            .find_map(|date| {
+                times
+                    .iter()
+                    .map(|time| date.and_time(*time))
+                    .filter(|at| *at <= now)
+                    .max()
+            })
+    }
+
+    /// Earliest moment after `now` at which the category's policy resets
+    /// it, or None for manual-only categories
+    pub fn next_reset_boundary(
+        category: &Category,
+        global_reset_time: NaiveTime,
+        now: NaiveDateTime,
+    ) -> Option<NaiveDateTime> {
+        let (times, weekdays) = Self::reset_slots(category, global_reset_time)?;
+
+        (0..=7)
+            .map(|offset| now.date() + chrono::Duration::days(offset))
+            .filter(|date| Self::resets_on(weekdays.as_deref(), *date))
+            .find_map(|date| {
+                times
+                    .iter()
+                    .map(|time| date.and_time(*time))
+                    .filter(|at| *at > now)
+                    .min()
+            })
+    }
+
+    /// Reset times of a category and the weekdays they apply on (None for
+    /// every day), or None for manual-only categories
+    fn reset_slots(
+        category: &Category,
+        global_reset_time: NaiveTime,
+    ) -> Option<(Vec<NaiveTime>, Option<Vec<u32>>)> {
+        let times: Vec<NaiveTime> = category
+            .reset_times
+            .as_deref()
+            .and_then(|s| serde_json::from_str::<Vec<String>>(s).ok())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|time| NaiveTime::parse_from_str(time, "%H:%M").ok())
+            .collect();
+        let times = if times.is_empty() || category.reset_policy == ResetPolicy::Global {
+            vec![global_reset_time]
+        } else {
+            times
+        };
+
+        let weekdays: Option<Vec<u32>> = match category.reset_policy {
+            ResetPolicy::Never => return None,
+            ResetPolicy::Global | ResetPolicy::Daily => None,
+            ResetPolicy::Weekdays => {
+                // reset_weekdays is JSON array of weekdays [0-6], 0=Sunday
+                let days: Vec<u32> = category
+                    .reset_weekdays
+                    .as_deref()
+                    .and_then(|s| serde_json::from_str(s).ok())
+                    .unwrap_or_default();
+                if days.is_empty() {
+                    return None;
+                }
+                Some(days)
+            }
+        };
+
+        Some((times, weekdays))
+    }
+
+    fn resets_on(weekdays: Option<&[u32]>, date: NaiveDate) -> bool {
+        weekdays.is_none_or(|days| days.contains(&date.weekday().num_days_from_sunday()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SyncStatus;
+
+    fn category(policy: ResetPolicy, times: Option<&str>, weekdays: Option<&str>) -> Category {
+        Category {
+            id: 1,
+            name: "Test".to_string(),
+            display_order: 1000,
+            reset_policy: policy,
+            reset_times: times.map(str::to_string),
+            reset_weekdays: weekdays.map(str::to_string),
+            last_reset_at: None,
+            sync_id: None,
+            created_at: None,
+            updated_at: None,
+            sync_status: SyncStatus::Pending,
+        }
+    }
+
+    fn at(date: &str, time: &str) -> NaiveDateTime {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .unwrap()
+            .and_time(NaiveTime::parse_from_str(time, "%H:%M").unwrap())
+    }
+
+    #[test]
+    fn daily_policy_uses_latest_passed_time() {
+        let category = category(
+            ResetPolicy::Daily,
+            Some(r#"["07:00","12:00","18:00"]"#),
+            None,
+        );
+        let global = NaiveTime::MIN;
+
+        assert_eq!(
+            ResetService::latest_reset_boundary(&category, global, at("2026-03-04", "13:30")),
+            Some(at("2026-03-04", "12:00"))
+        );
+        assert_eq!(
+            ResetService::latest_reset_boundary(&category, global, at("2026-03-04", "06:00")),
+            Some(at("2026-03-03", "18:00"))
+        );
+    }
+
+    #[test]
+    fn weekday_policy_waits_for_listed_days() {
+        // Saturdays at 09:00; 2026-03-04 is a Wednesday
+        let category = category(ResetPolicy::Weekdays, Some(r#"["09:00"]"#), Some("[6]"));
+        let global = NaiveTime::MIN;
+
+        assert_eq!(
+            ResetService::latest_reset_boundary(&category, global, at("2026-03-04", "10:00")),
+            Some(at("2026-02-28", "09:00"))
+        );
+        assert_eq!(
+            ResetService::latest_reset_boundary(&category, global, at("2026-03-07", "08:59")),
+            Some(at("2026-02-28", "09:00"))
+        );
+        assert_eq!(
+            ResetService::latest_reset_boundary(&category, global, at("2026-03-07", "09:00")),
+            Some(at("2026-03-07", "09:00"))
+        );
+    }
+
+    #[test]
+    fn next_boundary_is_first_reset_after_now() {
+        let category = category(ResetPolicy::Weekdays, Some(r#"["09:00"]"#), Some("[6]"));
+        let global = NaiveTime::MIN;
+
+        assert_eq!(
+            ResetService::next_reset_boundary(&category, global, at("2026-03-04", "10:00")),
+            Some(at("2026-03-07", "09:00"))
+        );
+        assert_eq!(
+            ResetService::next_reset_boundary(&category, global, at("2026-03-07", "09:00")),
+            Some(at("2026-03-14", "09:00"))
+        );
+    }
+
+    #[test]
+    fn never_policy_has_no_boundary() {
+        let category = category(ResetPolicy::Never, None, None);
+        assert_eq!(
+            ResetService::latest_reset_boundary(
+                &category,
+                NaiveTime::MIN,
+                at("2026-03-04", "10:00")
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn reset_keeps_snoozed_items_done() {
+        use crate::models::{RepeatSchedule, RepeatType};
+        use crate::service::{RepeatService, TodoService};
+
+        let conn = crate::repository::open_in_memory();
+        let item = TodoService::create_item(
+            &conn,
+            "Water plants",
+            None,
+            RepeatSchedule::new(RepeatType::Daily, None),
+            false,
+            None,
+        )
+        .unwrap();
+        let today = TimeZoneService::logical_today(&conn).unwrap();
+        RepeatService::snooze_occurrence(&conn, item.id, today + chrono::Duration::days(3))
+            .unwrap();
+
+        ResetService::reset_items(&conn, None).unwrap();
+
+        let item = TodoRepository::get_by_id(&conn, item.id).unwrap().unwrap();
+        assert!(item.done);
+    }
 }
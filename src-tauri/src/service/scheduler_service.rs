@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use chrono::{NaiveDateTime, NaiveTime};
+use rusqlite::Connection;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::models::{SchedulerEvent, TodoItem};
+use crate::repository::{CategoryRepository, SettingsRepository, TodoRepository};
+use crate::service::repeat_service::parse_due_at;
+use crate::service::{
+    RepeatService, ResetService, StreakService, TimeZoneService, TrashService, WidgetService,
+};
+use crate::AppState;
+
+/// Event name the frontend listens on; keep in sync with SCHEDULER_EVENT
+/// in src/types.ts
+pub const SCHEDULER_EVENT: &str = "scheduler-tick";
+
+/// Longest sleep between runs, so edits, setting changes and clock jumps
+/// are picked up even when no instant is planned
+const MAX_SLEEP_SECS: i64 = 15 * 60;
+
+pub struct SchedulerService;
+
+impl SchedulerService {
+    /// Start the background loop. It sleeps until the next reset time, due
+    /// slot or reminder, runs the auto-reset and repeat processing, then
+    /// refreshes the widget cache and notifies every window.
+    pub fn start(app: AppHandle) {
+        tauri::async_runtime::spawn(async move {
+            let mut last_run =
+                Self::with_conn(&app, |conn| Ok(TimeZoneService::now(conn)?.naive_local())).ok();
+
+            loop {
+                let wait =
+                    Self::with_conn(&app, Self::time_until_next_wake).unwrap_or_else(|error| {
+                        log::error!("Failed to plan next scheduler run: {}", error);
+                        Duration::from_secs(MAX_SLEEP_SECS as u64)
+                    });
+                tokio::time::sleep(wait).await;
+
+                match Self::run(&app, last_run) {
+                    Ok(now) => last_run = Some(now),
+                    Err(error) => log::error!("Scheduler run failed: {}", error),
+                }
+            }
+        });
+    }
+
+    fn with_conn<T>(
+        app: &AppHandle,
+        action: impl FnOnce(&Connection) -> Result<T, rusqlite::Error>,
+    ) -> Result<T, String> {
+        let state = app.state::<AppState>();
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+        action(&db).map_err(|e| e.to_string())
+    }
+
+    /// Process due work and emit the event when anything happened.
+    /// Returns the instant the run covered up to.
+    fn run(app: &AppHandle, since: Option<NaiveDateTime>) -> Result<NaiveDateTime, String> {
+        let state = app.state::<AppState>();
+        let db = state.db.lock().map_err(|e| e.to_string())?;
+
+        let now = TimeZoneService::now(&db)
+            .map_err(|e| e.to_string())?
+            .naive_local();
+        let event = Self::process_due(&db, since.unwrap_or(now), now).map_err(|e| e.to_string())?;
+        if event.is_empty() {
+            return Ok(now);
+        }
+
+        if let Err(error) = WidgetService::refresh_cache(&db, app, None) {
+            log::error!("Failed to refresh widget cache from scheduler: {}", error);
+        }
+        drop(db);
+
+        app.emit(SCHEDULER_EVENT, event)
+            .map_err(|e| e.to_string())?;
+        Ok(now)
+    }
+
+    /// Purge the trash, run the auto-reset and repeat processing, and
+    /// collect open items whose reminder or due time fell in `(since, now]`
+    pub fn process_due(
+        conn: &Connection,
+        since: NaiveDateTime,
+        now: NaiveDateTime,
+    ) -> Result<SchedulerEvent, rusqlite::Error> {
+        let purged = TrashService::purge_expired(conn)?;
+        if purged > 0 {
+            log::info!("Purged {} expired trash entries", purged);
+        }
+        let reset = ResetService::check_and_auto_reset(conn)?;
+        let reactivated = RepeatService::process_repeats(conn)?;
+        StreakService::record_freezes(conn)?;
+
+        let due_item_ids = TodoRepository::get_all(conn)?
+            .iter()
+            .filter(|item| !item.done)
+            .filter(|item| {
+                Self::reminder_instant(item, now).is_some_and(|at| since < at && at <= now)
+            })
+            .map(|item| item.id)
+            .collect();
+
+        Ok(SchedulerEvent {
+            reset,
+            reactivated,
+            due_item_ids,
+        })
+    }
+
+    fn time_until_next_wake(conn: &Connection) -> Result<Duration, rusqlite::Error> {
+        let now = TimeZoneService::now(conn)?.naive_local();
+        let wake = Self::next_wake(conn, now)?;
+        let secs = (wake - now).num_seconds().clamp(1, MAX_SLEEP_SECS);
+        Ok(Duration::from_secs(secs as u64))
+    }
+
+    /// Next instant after `now` at which something may change: the global
+    /// reset time (logical day change), a category reset, a due slot or a
+    /// reminder. Capped at MAX_SLEEP_SECS from now.
+    pub fn next_wake(
+        conn: &Connection,
+        now: NaiveDateTime,
+    ) -> Result<NaiveDateTime, rusqlite::Error> {
+        let reset_time =
+            SettingsRepository::get(conn, "reset_time")?.unwrap_or_else(|| "00:00".to_string());
+        let global_reset_time =
+            NaiveTime::parse_from_str(&reset_time, "%H:%M").unwrap_or(NaiveTime::MIN);
+
+        let mut wake = now + chrono::Duration::seconds(MAX_SLEEP_SECS);
+        let mut consider = |at: NaiveDateTime| {
+            if at > now && at < wake {
+                wake = at;
+            }
+        };
+
+        // Day-based repeats come back when the logical date changes
+        consider(Self::next_at_time(global_reset_time, now));
+
+        for category in CategoryRepository::get_all(conn)? {
+            if let Some(at) = ResetService::next_reset_boundary(&category, global_reset_time, now) {
+                consider(at);
+            }
+        }
+
+        for item in TodoRepository::get_all(conn)? {
+            // Done items with due times come back at their slot
+            if let Some(at) = item.due_at.as_deref().and_then(parse_due_at) {
+                consider(at);
+            }
+            if !item.done {
+                if let Some(at) = Self::reminder_instant(&item, now) {
+                    consider(if at > now {
+                        at
+                    } else {
+                        Self::next_at_time(at.time(), now)
+                    });
+                }
+            }
+        }
+
+        Ok(wake)
+    }
+
+    /// When an open item should remind: its due slot if it has one,
+    /// otherwise its daily reminder time on the date of `now`
+    fn reminder_instant(item: &TodoItem, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        if let Some(due_at) = item.due_at.as_deref() {
+            return parse_due_at(due_at);
+        }
+        let time = NaiveTime::parse_from_str(item.reminder_at.as_deref()?, "%H:%M").ok()?;
+        Some(now.date().and_time(time))
+    }
+
+    /// First moment after `now` with the given wall-clock time
+    fn next_at_time(time: NaiveTime, now: NaiveDateTime) -> NaiveDateTime {
+        let today = now.date().and_time(time);
+        if today > now {
+            today
+        } else {
+            today + chrono::Duration::days(1)
+        }
+    }
+}
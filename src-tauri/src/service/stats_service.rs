@@ -0,0 +1,157 @@
+use chrono::{Duration, NaiveDate};
+use rusqlite::Connection;
+
+use crate::models::{CategoryTrend, CompletionHourStats, MissedItem, WeekdayStats};
+use crate::repository::StatsRepository;
+use crate::service::TimeZoneService;
+
+/// How many items the most-missed list returns by default
+const DEFAULT_MISSED_LIMIT: i64 = 10;
+
+pub struct StatsService;
+
+impl StatsService {
+    /// Completion rate per weekday over the last `days` days or all time.
+    /// Skipped slots count neither way.
+    pub fn get_weekday_stats(
+        conn: &Connection,
+        days: Option<i32>,
+    ) -> Result<Vec<WeekdayStats>, rusqlite::Error> {
+        let since = Self::since(conn, days)?;
+        let rows = StatsRepository::slots_by_weekday(conn, &since)?;
+        Ok(Self::weekday_stats(&rows))
+    }
+
+    /// Completions per hour of day and the most common hour
+    pub fn get_completion_hour_stats(
+        conn: &Connection,
+        days: Option<i32>,
+    ) -> Result<CompletionHourStats, rusqlite::Error> {
+        let since = Self::since(conn, days)?;
+        let rows = StatsRepository::completions_by_hour(conn, &since)?;
+        Ok(Self::hour_stats(&rows))
+    }
+
+    /// 30- and 90-day completion trend of each category
+    pub fn get_category_trends(conn: &Connection) -> Result<Vec<CategoryTrend>, rusqlite::Error> {
+        let today = TimeZoneService::logical_today(conn)?;
+        let start = |days: i64| Self::format_date(today - Duration::days(days - 1));
+
+        let mut trends = StatsRepository::category_completions(
+            conn,
+            &start(30),
+            &start(60),
+            &start(90),
+            &start(180),
+        )?;
+        for trend in &mut trends {
+            trend.change_30_days = Self::change(trend.last_30_days, trend.previous_30_days);
+            trend.change_90_days = Self::change(trend.last_90_days, trend.previous_90_days);
+        }
+        Ok(trends)
+    }
+
+    /// Items with the most missed slots over the last `days` days or all time
+    pub fn get_most_missed_items(
+        conn: &Connection,
+        days: Option<i32>,
+        limit: Option<i64>,
+    ) -> Result<Vec<MissedItem>, rusqlite::Error> {
+        let since = Self::since(conn, days)?;
+        let limit = limit
+            .filter(|limit| *limit > 0)
+            .unwrap_or(DEFAULT_MISSED_LIMIT);
+        StatsRepository::most_missed(conn, &since, limit)
+    }
+
+    /// First date of the window; an empty string takes in all history
+    fn since(conn: &Connection, days: Option<i32>) -> Result<String, rusqlite::Error> {
+        match days.filter(|days| *days > 0) {
+            Some(days) => {
+                let today = TimeZoneService::logical_today(conn)?;
+                Ok(Self::format_date(today - Duration::days(days as i64 - 1)))
+            }
+            None => Ok(String::new()),
+        }
+    }
+
+    fn format_date(date: NaiveDate) -> String {
+        date.format("%Y-%m-%d").to_string()
+    }
+
+    /// All seven weekdays, with zero counts for those without slots
+    fn weekday_stats(rows: &[(u32, i64, i64)]) -> Vec<WeekdayStats> {
+        (0..7)
+            .map(|weekday| {
+                let (completed, missed) = rows
+                    .iter()
+                    .find(|(day, _, _)| *day == weekday)
+                    .map(|(_, completed, missed)| (*completed, *missed))
+                    .unwrap_or((0, 0));
+                let due = completed + missed;
+                WeekdayStats {
+                    weekday,
+                    completed,
+                    missed,
+                    rate: (due > 0).then(|| completed as f64 / due as f64),
+                }
+            })
+            .collect()
+    }
+
+    /// Ties go to the earlier hour
+    fn hour_stats(rows: &[(u32, i64)]) -> CompletionHourStats {
+        let mut hour_counts = vec![0; 24];
+        for (hour, count) in rows {
+            if let Some(slot) = hour_counts.get_mut(*hour as usize) {
+                *slot += count;
+            }
+        }
+        let typical_hour = hour_counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .max_by(|(a_hour, a), (b_hour, b)| a.cmp(b).then(b_hour.cmp(a_hour)))
+            .map(|(hour, _)| hour as u32);
+
+        CompletionHourStats {
+            total: hour_counts.iter().sum(),
+            hour_counts,
+            typical_hour,
+        }
+    }
+
+    fn change(current: i64, previous: i64) -> Option<f64> {
+        (previous > 0).then(|| (current - previous) as f64 / previous as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekday_stats_fill_missing_days() {
+        let stats = StatsService::weekday_stats(&[(1, 3, 1), (5, 0, 2)]);
+
+        assert_eq!(stats.len(), 7);
+        assert_eq!(stats[0].rate, None);
+        assert_eq!(stats[1].rate, Some(0.75));
+        assert_eq!(stats[5].rate, Some(0.0));
+    }
+
+    #[test]
+    fn typical_hour_prefers_earlier_hour_on_ties() {
+        let stats = StatsService::hour_stats(&[(7, 4), (21, 4), (12, 1)]);
+
+        assert_eq!(stats.total, 9);
+        assert_eq!(stats.typical_hour, Some(7));
+        assert_eq!(StatsService::hour_stats(&[]).typical_hour, None);
+    }
+
+    #[test]
+    fn change_needs_an_earlier_period() {
+        assert_eq!(StatsService::change(15, 10), Some(0.5));
+        assert_eq!(StatsService::change(3, 0), None);
+    }
+}
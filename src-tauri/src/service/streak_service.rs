@@ -1,8 +1,21 @@
-use chrono::{Datelike, Local, NaiveDate, NaiveTime};
+use chrono::{Datelike, IsoWeek, NaiveDate};
 use rusqlite::Connection;
-
-use crate::models::{CompletionLog, HeatmapData, HeatmapIntensity, RepeatType, TrackedItem};
-use crate::repository::{CompletionLogRepository, SettingsRepository, TodoRepository};
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{
+    CompletionLog, FreezeKind, GoalPeriod, HeatmapData, HeatmapIntensity, RepeatSchedule,
+    RepeatType, ScheduleVersion, StreakFreeze, TodoItem, TrackedItem,
+};
+use crate::repository::{
+    CompletionLogRepository, ScheduleVersionRepository, SettingsRepository, SkipLogRepository,
+    StreakFreezeRepository, TodoRepository,
+};
+use crate::service::repeat_service::RecurrenceRule;
+use crate::service::{RepeatService, TimeZoneService};
+
+mod aggregate;
+mod goal;
+mod history;
 
 pub struct StreakService;
 
@@ -10,6 +23,15 @@ enum StreakCadence {
     Daily,
     Weekly([bool; 7]),
     Monthly([bool; 32]),
+    Rule(RecurrenceRule),
+    /// Completion-relative: each completion must follow the previous one
+    /// within this many days.
+    AfterCompletion(i64),
+    /// Another cadence whose skipped slots are neither expected nor missed
+    Skipping(Box<StreakCadence>, HashSet<NaiveDate>),
+    /// Cadences of successive schedule versions, by effective-from date.
+    /// The first one also covers the dates before it.
+    Versioned(Vec<(NaiveDate, StreakCadence)>),
 }
 
 #[derive(Default)]
@@ -21,6 +43,19 @@ struct StreakStats {
 }
 
 impl StreakCadence {
+    /// Cadence for an item's full repeat settings. Yearly repeats and
+    /// intervals above one are only expressible as a recurrence rule.
+    fn from_schedule(schedule: &RepeatSchedule) -> Self {
+        if schedule.repeat_type == RepeatType::AfterCompletion {
+            return Self::AfterCompletion(schedule.repeat_interval.max(1) as i64);
+        }
+
+        match RepeatService::schedule_rule(schedule) {
+            Some(rule) => Self::Rule(rule),
+            None => Self::from_repeat(&schedule.repeat_type, schedule.repeat_detail.as_deref()),
+        }
+    }
+
     fn from_repeat(repeat_type: &RepeatType, repeat_detail: Option<&str>) -> Self {
         match repeat_type {
             RepeatType::None | RepeatType::Daily => Self::Daily,
@@ -56,6 +91,59 @@ impl StreakCadence {
                     Self::Daily
                 }
             }
+            RepeatType::Yearly => {
+                RepeatService::schedule_rule(&RepeatSchedule::new(RepeatType::Yearly, repeat_detail))
+                    .map(Self::Rule)
+                    .unwrap_or(Self::Daily)
+            }
+            RepeatType::Rule => repeat_detail
+                .and_then(|detail| RecurrenceRule::parse(detail).ok())
+                .map(Self::Rule)
+                .unwrap_or(Self::Daily),
+            RepeatType::AfterCompletion => Self::AfterCompletion(1),
+        }
+    }
+
+    /// Cadence of an item that changed its schedule over time. `current`
+    /// is authoritative from the latest version on, so a schedule pulled
+    /// from another device still applies.
+    fn from_versions(versions: &[ScheduleVersion], current: &RepeatSchedule) -> Self {
+        let mut cadences: Vec<(NaiveDate, StreakCadence)> = versions
+            .iter()
+            .filter_map(|version| {
+                let from = NaiveDate::parse_from_str(&version.effective_from, "%Y-%m-%d").ok()?;
+                Some((from, Self::from_schedule(&version.schedule())))
+            })
+            .collect();
+
+        if cadences.len() < 2 {
+            return Self::from_schedule(current);
+        }
+        if let Some(last) = cadences.last_mut() {
+            last.1 = Self::from_schedule(current);
+        }
+        Self::Versioned(cadences)
+    }
+
+    /// The version active on `date`
+    fn active_on(&self, date: NaiveDate) -> &StreakCadence {
+        match self {
+            Self::Versioned(versions) => versions
+                .iter()
+                .rev()
+                .find(|(from, _)| *from <= date)
+                .or(versions.first())
+                .map(|(_, cadence)| cadence)
+                .unwrap_or(self),
+            _ => self,
+        }
+    }
+
+    fn with_skips(self, skipped: HashSet<NaiveDate>) -> Self {
+        if skipped.is_empty() {
+            self
+        } else {
+            Self::Skipping(Box::new(self), skipped)
         }
     }
 
@@ -65,11 +153,67 @@ impl StreakCadence {
             .unwrap_or_default()
     }
 
+    /// Whether a slot on `date` is expected at all; skipped slots are not
+    fn expects_slot_on(&self, date: NaiveDate) -> bool {
+        match self {
+            Self::Skipping(inner, skipped) => {
+                !skipped.contains(&date) && inner.expects_slot_on(date)
+            }
+            _ => self.is_scheduled_on(date),
+        }
+    }
+
+    fn is_completion_relative(&self) -> bool {
+        match self {
+            Self::AfterCompletion(_) => true,
+            Self::Skipping(inner, _) => inner.is_completion_relative(),
+            Self::Versioned(versions) => versions
+                .last()
+                .is_some_and(|(_, cadence)| cadence.is_completion_relative()),
+            _ => false,
+        }
+    }
+
     fn is_scheduled_on(&self, date: NaiveDate) -> bool {
         match self {
-            Self::Daily => true,
+            Self::Daily | Self::AfterCompletion(_) => true,
             Self::Weekly(weekdays) => weekdays[date.weekday().num_days_from_sunday() as usize],
             Self::Monthly(month_days) => month_days[date.day() as usize],
+            Self::Rule(rule) => rule.is_scheduled_on(date),
+            Self::Skipping(inner, _) => inner.is_scheduled_on(date),
+            Self::Versioned(_) => self.active_on(date).is_scheduled_on(date),
+        }
+    }
+
+    /// Whether a completion on `date` extends a streak whose previous
+    /// completion was on `prev_date`.
+    fn continues_streak(&self, prev_date: NaiveDate, date: NaiveDate) -> bool {
+        match self {
+            // Doing it early is fine, only running past the deadline breaks it
+            Self::AfterCompletion(days) => {
+                date > prev_date && date <= prev_date + chrono::Duration::days(*days)
+            }
+            // A skipped slot carries the streak over to the slot after it
+            Self::Skipping(inner, skipped) => {
+                let mut anchor = prev_date;
+                for _ in 0..400 {
+                    if inner.continues_streak(anchor, date) {
+                        return true;
+                    }
+                    let next = inner.next_scheduled_after(anchor);
+                    if next >= date || !skipped.contains(&next) {
+                        return false;
+                    }
+                    anchor = next;
+                }
+                false
+            }
+            // Completion-relative versions keep their deadline; otherwise the
+            // next slot may come from the version that took over meanwhile
+            Self::Versioned(_) if self.active_on(date).is_completion_relative() => {
+                self.active_on(date).continues_streak(prev_date, date)
+            }
+            _ => date == self.next_scheduled_after(prev_date),
         }
     }
 
@@ -78,6 +222,41 @@ impl StreakCadence {
             return date + chrono::Duration::days(1);
         }
 
+        if let Self::AfterCompletion(days) = self {
+            return date + chrono::Duration::days(*days);
+        }
+
+        if let Self::Skipping(inner, skipped) = self {
+            let mut candidate = inner.next_scheduled_after(date);
+            for _ in 0..400 {
+                if !skipped.contains(&candidate) {
+                    break;
+                }
+                candidate = inner.next_scheduled_after(candidate);
+            }
+            return candidate;
+        }
+
+        // The active version proposes the next slot; a version starting before
+        // that slot takes over from its first day
+        if let Self::Versioned(versions) = self {
+            let mut candidate = self.active_on(date).next_scheduled_after(date);
+            for (from, cadence) in versions.iter().filter(|(from, _)| *from > date) {
+                if *from > candidate {
+                    break;
+                }
+                candidate = cadence.next_scheduled_after(*from - chrono::Duration::days(1));
+            }
+            return candidate;
+        }
+
+        // Rules can skip months or years, so let the rule find its own next slot.
+        if let Self::Rule(rule) = self {
+            return rule
+                .next_after(date)
+                .unwrap_or(date + chrono::Duration::days(1));
+        }
+
         let mut candidate = date + chrono::Duration::days(1);
         for _ in 0..400 {
             if self.is_scheduled_on(candidate) {
@@ -92,29 +271,27 @@ impl StreakCadence {
 
 impl StreakService {
     fn get_logical_date(conn: &Connection) -> Result<NaiveDate, rusqlite::Error> {
-        let reset_time =
-            SettingsRepository::get(conn, "reset_time")?.unwrap_or_else(|| "00:00".to_string());
-        let now = Local::now();
-        let today = now.date_naive();
-
-        let parts: Vec<&str> = reset_time.split(':').collect();
-        let hour: u32 = parts.first().and_then(|s| s.parse().ok()).unwrap_or(0);
-        let min: u32 = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(0);
-
-        let reset_time_today = NaiveTime::from_hms_opt(hour, min, 0).unwrap_or(NaiveTime::MIN);
-        let current_time = now.time();
+        TimeZoneService::logical_today(conn)
+    }
 
-        if current_time < reset_time_today {
-            Ok(today - chrono::Duration::days(1))
-        } else {
-            Ok(today)
-        }
+    /// Cadence of an item that judges each date by the schedule version
+    /// active on it
+    fn cadence_for(
+        conn: &Connection,
+        item_id: i64,
+        schedule: &RepeatSchedule,
+    ) -> Result<StreakCadence, rusqlite::Error> {
+        let versions = ScheduleVersionRepository::get_for_item(conn, item_id)?;
+        Ok(StreakCadence::from_versions(&versions, schedule))
     }
 
     /// Log a completion for a specific item for today
     pub fn log_completion(conn: &Connection, item_id: i64) -> Result<(), rusqlite::Error> {
         let today = Self::get_logical_date(conn)?.format("%Y-%m-%d").to_string();
-        CompletionLogRepository::increment(conn, item_id, &today)
+        let now = TimeZoneService::now(conn)?
+            .format("%Y-%m-%dT%H:%M:%S%:z")
+            .to_string();
+        CompletionLogRepository::increment(conn, item_id, &today, Some(&now))
     }
 
     /// Remove a completion for a specific item for today (when unchecking)
@@ -142,11 +319,56 @@ impl StreakService {
         let logs = CompletionLogRepository::get_logs_for_item(conn, item_id, 365)?;
         let all_logs = CompletionLogRepository::get_all_logs_for_item(conn, item_id)?;
         let logical_today = Self::get_logical_date(conn)?;
-        let cadence = StreakCadence::from_repeat(&item.repeat_type, item.repeat_detail.as_deref());
+        let skipped_dates = SkipLogRepository::get_dates_for_item(conn, item_id)?;
+        let schedule = RepeatService::schedule_for(conn, &item)?;
+        // Skipped and excluded days are both neutral for the streak
+        let mut neutral_dates: HashSet<NaiveDate> = skipped_dates
+            .iter()
+            .filter_map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+            .collect();
+        let first_log = all_logs
+            .iter()
+            .filter_map(|log| NaiveDate::parse_from_str(&log.completed_on, "%Y-%m-%d").ok())
+            .min()
+            .unwrap_or(logical_today);
+        neutral_dates.extend(Self::excluded_dates(&schedule, first_log, logical_today));
+
+        // Frozen slots are neutral too
+        let freezes = StreakFreezeRepository::get_for_item(conn, item_id)?;
+        neutral_dates.extend(Self::freeze_dates(&freezes));
+        let has_goal = item.goal_period != GoalPeriod::None && item.goal_target > 0;
+
+        // Frequency goals count streaks in periods that met the target
+        let cadence = Self::cadence_for(conn, item_id, &schedule)?.with_skips(neutral_dates);
         let completion_dates = Self::scheduled_completion_dates(&all_logs, &cadence);
-        let streak_segments = Self::build_streak_segments(&completion_dates, &cadence);
-        let streak_stats = Self::calculate_streaks(&streak_segments, &cadence, logical_today);
-        let combo_intensity = Self::build_combo_intensity(&streak_segments);
+        let mut goal_progress = Vec::new();
+        let (streak_stats, streak_segments) = if has_goal {
+            goal_progress = Self::goal_progress(
+                &all_logs,
+                &item.goal_period,
+                item.goal_target,
+                logical_today,
+                Self::week_start(conn)?,
+            );
+            let streaks = Self::goal_streaks(&goal_progress, &all_logs);
+            // Only periods overlapping the heatmap window are reported
+            let window_start = (logical_today - chrono::Duration::days(364))
+                .format("%Y-%m-%d")
+                .to_string();
+            goal_progress.retain(|period| period.period_end >= window_start);
+            streaks
+        } else {
+            let segments = Self::build_streak_segments(&completion_dates, &cadence);
+            let stats = Self::calculate_streaks(&segments, &cadence, logical_today);
+            (stats, segments)
+        };
+        let mut combo_intensity = Self::build_combo_intensity(&streak_segments);
+        let mut frozen_dates: Vec<NaiveDate> = Self::freeze_dates(&freezes).collect();
+        frozen_dates.sort();
+        combo_intensity.extend(frozen_dates.iter().map(|date| HeatmapIntensity {
+            completed_on: date.format("%Y-%m-%d").to_string(),
+            level: HeatmapIntensity::FROZEN_LEVEL,
+        }));
 
         let total_days = all_logs.len() as i32;
 
@@ -160,9 +382,201 @@ impl StreakService {
             longest_streak: streak_stats.longest_streak,
             current_streak_dates: Self::format_dates(&streak_stats.current_streak_dates),
             longest_streak_dates: Self::format_dates(&streak_stats.longest_streak_dates),
+            skipped_dates,
+            frozen_dates: Self::format_dates(&frozen_dates),
+            goal_period: item.goal_period,
+            goal_target: item.goal_target,
+            goal_progress,
         }))
     }
 
+    /// Set how many missed slots a month may be frozen and how many misses
+    /// a week the streak tolerates
+    pub fn update_streak_grace(
+        conn: &Connection,
+        item_id: i64,
+        freezes_per_month: i32,
+        grace_per_week: i32,
+    ) -> Result<(), String> {
+        if !(0..=31).contains(&freezes_per_month) {
+            return Err("Freezes per month must be between 0 and 31".to_string());
+        }
+        if !(0..=7).contains(&grace_per_week) {
+            return Err("Grace days per week must be between 0 and 7".to_string());
+        }
+
+        TodoRepository::update_streak_grace(conn, item_id, freezes_per_month, grace_per_week)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Spend the allowances of tracked items on the missed slots they can
+    /// bridge. Runs once per logical day, since slots only become missed
+    /// when a day ends; the freezes stay pending until pushed.
+    pub fn record_freezes(conn: &Connection) -> Result<usize, rusqlite::Error> {
+        let today = Self::get_logical_date(conn)?;
+        let today_str = today.format("%Y-%m-%d").to_string();
+        if SettingsRepository::get(conn, "last_freeze_date")?.as_deref() == Some(&today_str) {
+            return Ok(0);
+        }
+
+        let mut recorded = 0;
+        for item in TodoRepository::get_all(conn)? {
+            recorded += Self::record_item_freezes(conn, &item, today)?;
+        }
+        SettingsRepository::set(conn, "last_freeze_date", &today_str)?;
+        Ok(recorded)
+    }
+
+    /// Store the freezes an item's allowances bridge as of `today`
+    fn record_item_freezes(
+        conn: &Connection,
+        item: &TodoItem,
+        today: NaiveDate,
+    ) -> Result<usize, rusqlite::Error> {
+        let has_goal = item.goal_period != GoalPeriod::None && item.goal_target > 0;
+        if !item.track_streak
+            || has_goal
+            || (item.streak_freezes_per_month <= 0 && item.streak_grace_per_week <= 0)
+        {
+            return Ok(0);
+        }
+
+        let all_logs = CompletionLogRepository::get_all_logs_for_item(conn, item.id)?;
+        let schedule = RepeatService::schedule_for(conn, item)?;
+        let freezes = StreakFreezeRepository::get_for_item(conn, item.id)?;
+        let mut neutral_dates: HashSet<NaiveDate> =
+            SkipLogRepository::get_dates_for_item(conn, item.id)?
+                .iter()
+                .filter_map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+                .collect();
+        let first_log = all_logs
+            .iter()
+            .filter_map(|log| NaiveDate::parse_from_str(&log.completed_on, "%Y-%m-%d").ok())
+            .min()
+            .unwrap_or(today);
+        neutral_dates.extend(Self::excluded_dates(&schedule, first_log, today));
+        neutral_dates.extend(Self::freeze_dates(&freezes));
+
+        let cadence = Self::cadence_for(conn, item.id, &schedule)?.with_skips(neutral_dates);
+        let completion_dates = Self::scheduled_completion_dates(&all_logs, &cadence);
+        let planned = Self::plan_freezes(
+            &completion_dates,
+            &cadence,
+            &freezes,
+            item.streak_freezes_per_month,
+            item.streak_grace_per_week,
+            today,
+        );
+        for (date, kind) in &planned {
+            let frozen_on = date.format("%Y-%m-%d").to_string();
+            StreakFreezeRepository::add(conn, item.id, &frozen_on, kind.clone())?;
+        }
+        Ok(planned.len())
+    }
+
+    fn freeze_dates(freezes: &[StreakFreeze]) -> impl Iterator<Item = NaiveDate> + '_ {
+        freezes
+            .iter()
+            .filter_map(|freeze| NaiveDate::parse_from_str(&freeze.frozen_on, "%Y-%m-%d").ok())
+    }
+
+    /// Missed slots that grace days and freezes can bridge, oldest gap first.
+    /// Grace days are used before freezes, and a gap is only bridged as a
+    /// whole so allowances are not spent on a streak that breaks anyway.
+    fn plan_freezes(
+        completion_dates: &[NaiveDate],
+        cadence: &StreakCadence,
+        used: &[StreakFreeze],
+        freezes_per_month: i32,
+        grace_per_week: i32,
+        today: NaiveDate,
+    ) -> Vec<(NaiveDate, FreezeKind)> {
+        let mut monthly: HashMap<(i32, u32), i32> = HashMap::new();
+        let mut weekly: HashMap<IsoWeek, i32> = HashMap::new();
+        for freeze in used {
+            let Ok(date) = NaiveDate::parse_from_str(&freeze.frozen_on, "%Y-%m-%d") else {
+                continue;
+            };
+            match freeze.kind {
+                FreezeKind::Freeze => *monthly.entry((date.year(), date.month())).or_default() += 1,
+                FreezeKind::Grace => *weekly.entry(date.iso_week()).or_default() += 1,
+            }
+        }
+
+        let mut gaps: Vec<Vec<NaiveDate>> = completion_dates
+            .windows(2)
+            .filter(|pair| !cadence.continues_streak(pair[0], pair[1]))
+            .map(|pair| Self::missed_slots(cadence, pair[0], pair[1]))
+            .collect();
+        // Today's slot is still open, only earlier ones count as missed
+        if let Some(last) = completion_dates.last() {
+            gaps.push(Self::missed_slots(cadence, *last, today));
+        }
+
+        let mut planned = Vec::new();
+        for gap in gaps {
+            let mut gap_monthly = monthly.clone();
+            let mut gap_weekly = weekly.clone();
+            let mut gap_planned = Vec::new();
+
+            for date in &gap {
+                let week = gap_weekly.entry(date.iso_week()).or_default();
+                if *week < grace_per_week {
+                    *week += 1;
+                    gap_planned.push((*date, FreezeKind::Grace));
+                    continue;
+                }
+                let month = gap_monthly.entry((date.year(), date.month())).or_default();
+                if *month < freezes_per_month {
+                    *month += 1;
+                    gap_planned.push((*date, FreezeKind::Freeze));
+                }
+            }
+
+            if !gap.is_empty() && gap_planned.len() == gap.len() {
+                monthly = gap_monthly;
+                weekly = gap_weekly;
+                planned.extend(gap_planned);
+            }
+        }
+
+        planned
+    }
+
+    /// Scheduled slots after `from` and before `until`
+    fn missed_slots(cadence: &StreakCadence, from: NaiveDate, until: NaiveDate) -> Vec<NaiveDate> {
+        let mut slots = Vec::new();
+        let mut slot = cadence.next_scheduled_after(from);
+        while slot < until && slots.len() < 400 {
+            slots.push(slot);
+            slot = cadence.next_scheduled_after(slot);
+        }
+        slots
+    }
+
+    /// Excluded days of the schedule between `from` and `to` (inclusive)
+    fn excluded_dates(
+        schedule: &RepeatSchedule,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> HashSet<NaiveDate> {
+        let mut dates = HashSet::new();
+        for range in &schedule.exclusions {
+            let (Ok(start), Ok(end)) = (
+                NaiveDate::parse_from_str(&range.start_date, "%Y-%m-%d"),
+                NaiveDate::parse_from_str(&range.end_date, "%Y-%m-%d"),
+            ) else {
+                continue;
+            };
+            let mut date = start.max(from);
+            while date <= end.min(to) {
+                dates.insert(date);
+                date += chrono::Duration::days(1);
+            }
+        }
+        dates
+    }
+
     /// Update track_streak setting for an item
     pub fn update_track_streak(
         conn: &Connection,
@@ -233,8 +647,7 @@ impl StreakService {
         let mut prev_date = completion_dates[0];
 
         for date in completion_dates.iter().copied().skip(1) {
-            let expected_next = cadence.next_scheduled_after(prev_date);
-            if date == expected_next {
+            if cadence.continues_streak(prev_date, date) {
                 current_segment.push(date);
             } else {
                 segments.push(current_segment);
@@ -291,7 +704,10 @@ impl StreakService {
 #[cfg(test)]
 mod tests {
     use super::{StreakCadence, StreakService};
-    use crate::models::{CompletionLog, RepeatType};
+    use crate::models::{
+        CompletionLog, FreezeKind, RepeatSchedule, RepeatType, ScheduleVersion, StreakFreeze,
+        SyncStatus,
+    };
     use chrono::NaiveDate;
 
     fn log(date: &str) -> CompletionLog {
@@ -299,6 +715,7 @@ mod tests {
             item_id: 1,
             completed_on: date.to_string(),
             completed_count: 1,
+            completed_at: None,
         }
     }
 
@@ -351,6 +768,39 @@ mod tests {
         assert!(stats.current_streak_dates.is_empty());
     }
 
+    #[test]
+    fn schedule_change_keeps_the_streak_under_the_old_rule() {
+        let version = |effective_from: &str, detail: &str| ScheduleVersion {
+            item_id: 1,
+            effective_from: effective_from.to_string(),
+            repeat_type: RepeatType::Weekly,
+            repeat_detail: Some(detail.to_string()),
+            repeat_interval: 1,
+            repeat_start: None,
+            repeat_until: None,
+            repeat_count: None,
+        };
+        // Mon/Wed until Monday 2026-03-09, Tue/Thu from then on
+        let versions = vec![
+            version("2026-03-01", "[1,3]"),
+            version("2026-03-09", "[2,4]"),
+        ];
+        let current = RepeatSchedule::new(RepeatType::Weekly, Some("[2,4]"));
+        let cadence = StreakCadence::from_versions(&versions, &current);
+        let logs = vec![
+            log("2026-03-02"),
+            log("2026-03-04"),
+            log("2026-03-10"),
+            log("2026-03-12"),
+        ];
+        let completion_dates = StreakService::scheduled_completion_dates(&logs, &cadence);
+        let streak_segments = StreakService::build_streak_segments(&completion_dates, &cadence);
+
+        let stats = StreakService::calculate_streaks(&streak_segments, &cadence, date(2026, 3, 12));
+
+        assert_eq!((stats.current_streak, stats.longest_streak), (4, 4));
+    }
+
     #[test]
     fn monthly_streak_skips_months_without_target_day() {
         let logs = vec![log("2026-01-31"), log("2026-03-31")];
@@ -363,6 +813,83 @@ mod tests {
         assert_eq!((stats.current_streak, stats.longest_streak), (2, 2));
     }
 
+    #[test]
+    fn rule_streak_follows_biweekly_slots() {
+        let logs = vec![log("2026-03-02"), log("2026-03-05"), log("2026-03-16")]; // Mon, Thu, Mon
+        let cadence = StreakCadence::from_repeat(
+            &RepeatType::Rule,
+            Some("DTSTART:20260302\nRRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH"),
+        );
+        let completion_dates = StreakService::scheduled_completion_dates(&logs, &cadence);
+        let streak_segments = StreakService::build_streak_segments(&completion_dates, &cadence);
+
+        let stats = StreakService::calculate_streaks(&streak_segments, &cadence, date(2026, 3, 18));
+
+        assert_eq!((stats.current_streak, stats.longest_streak), (3, 3));
+    }
+
+    #[test]
+    fn interval_streak_only_expects_every_third_day() {
+        let logs = vec![log("2026-03-01"), log("2026-03-04"), log("2026-03-07")];
+        let mut schedule = RepeatSchedule::new(RepeatType::Daily, None).with_interval(3);
+        schedule.repeat_start = Some("2026-03-01".to_string());
+        let cadence = StreakCadence::from_schedule(&schedule);
+        let completion_dates = StreakService::scheduled_completion_dates(&logs, &cadence);
+        let streak_segments = StreakService::build_streak_segments(&completion_dates, &cadence);
+
+        let stats = StreakService::calculate_streaks(&streak_segments, &cadence, date(2026, 3, 9));
+
+        assert_eq!((stats.current_streak, stats.longest_streak), (3, 3));
+    }
+
+    #[test]
+    fn yearly_streak_spans_years() {
+        let logs = vec![log("2024-02-14"), log("2025-02-14")];
+        let cadence = StreakCadence::from_repeat(&RepeatType::Yearly, Some("[2,14]"));
+        let completion_dates = StreakService::scheduled_completion_dates(&logs, &cadence);
+        let streak_segments = StreakService::build_streak_segments(&completion_dates, &cadence);
+
+        let stats = StreakService::calculate_streaks(&streak_segments, &cadence, date(2026, 1, 10));
+
+        assert_eq!((stats.current_streak, stats.longest_streak), (2, 2));
+    }
+
+    #[test]
+    fn after_completion_streak_allows_early_and_breaks_when_overdue() {
+        // Every 4 days: 3 days, 4 days, then 6 days between completions
+        let logs = vec![
+            log("2026-03-01"),
+            log("2026-03-04"),
+            log("2026-03-08"),
+            log("2026-03-14"),
+        ];
+        let mut schedule = RepeatSchedule::new(RepeatType::AfterCompletion, None).with_interval(4);
+        schedule.repeat_start = Some("2026-03-01".to_string());
+        let cadence = StreakCadence::from_schedule(&schedule);
+        let completion_dates = StreakService::scheduled_completion_dates(&logs, &cadence);
+        let streak_segments = StreakService::build_streak_segments(&completion_dates, &cadence);
+
+        let stats = StreakService::calculate_streaks(&streak_segments, &cadence, date(2026, 3, 18));
+        assert_eq!((stats.current_streak, stats.longest_streak), (1, 3));
+
+        let stats = StreakService::calculate_streaks(&streak_segments, &cadence, date(2026, 3, 19));
+        assert_eq!((stats.current_streak, stats.longest_streak), (0, 3));
+    }
+
+    #[test]
+    fn skipped_slots_are_neutral_for_streaks() {
+        let logs = vec![log("2026-03-02"), log("2026-03-06"), log("2026-03-09")]; // Mon, Fri, Mon
+        let skipped = [date(2026, 3, 4)].into_iter().collect(); // Wed
+        let cadence =
+            StreakCadence::from_repeat(&RepeatType::Weekly, Some("[1,3,5]")).with_skips(skipped);
+        let completion_dates = StreakService::scheduled_completion_dates(&logs, &cadence);
+        let streak_segments = StreakService::build_streak_segments(&completion_dates, &cadence);
+
+        let stats = StreakService::calculate_streaks(&streak_segments, &cadence, date(2026, 3, 10));
+
+        assert_eq!((stats.current_streak, stats.longest_streak), (3, 3));
+    }
+
     #[test]
     fn unscheduled_completions_do_not_count_for_repeating_streak() {
         let logs = vec![log("2026-03-03")]; // Tue
@@ -428,4 +955,104 @@ mod tests {
         assert!(third_day_level > first_day_level);
         assert_eq!(reset_day_level, first_day_level);
     }
+
+    #[test]
+    fn grace_and_freezes_bridge_whole_gaps_only() {
+        // Daily; missed 3-03 and 3-04, then 3-07 to 3-09
+        let completion_dates = vec![
+            date(2026, 3, 2),
+            date(2026, 3, 5),
+            date(2026, 3, 6),
+            date(2026, 3, 10),
+        ];
+        let cadence = StreakCadence::from_repeat(&RepeatType::Daily, None);
+
+        let planned =
+            StreakService::plan_freezes(&completion_dates, &cadence, &[], 1, 1, date(2026, 3, 10));
+
+        // One grace day for the week of 3-02, one freeze for March; the
+        // three-day gap stays open instead of using up the next week's grace
+        assert_eq!(
+            planned,
+            vec![
+                (date(2026, 3, 3), FreezeKind::Grace),
+                (date(2026, 3, 4), FreezeKind::Freeze),
+            ]
+        );
+    }
+
+    #[test]
+    fn used_freezes_count_against_the_month() {
+        let completion_dates = vec![date(2026, 3, 20), date(2026, 3, 22)];
+        let cadence = StreakCadence::from_repeat(&RepeatType::Daily, None);
+        let used = vec![StreakFreeze {
+            item_id: 1,
+            frozen_on: "2026-03-05".to_string(),
+            kind: FreezeKind::Freeze,
+            sync_status: SyncStatus::Synced,
+        }];
+
+        let planned = StreakService::plan_freezes(
+            &completion_dates,
+            &cadence,
+            &used,
+            1,
+            0,
+            date(2026, 3, 22),
+        );
+
+        assert!(planned.is_empty());
+    }
+
+    #[test]
+    fn bridged_gaps_are_stored_once_a_day_and_read_back() {
+        use crate::repository::{CompletionLogRepository, StreakFreezeRepository};
+        use crate::service::{TimeZoneService, TodoService};
+
+        let conn = crate::repository::open_in_memory();
+        let item = TodoService::create_item(
+            &conn,
+            "Read",
+            None,
+            RepeatSchedule::new(RepeatType::Daily, None),
+            true,
+            None,
+        )
+        .unwrap();
+        StreakService::update_streak_grace(&conn, item.id, 1, 0).unwrap();
+        let today = TimeZoneService::logical_today(&conn).unwrap();
+        let day = |offset: i64| {
+            (today - chrono::Duration::days(offset))
+                .format("%Y-%m-%d")
+                .to_string()
+        };
+        CompletionLogRepository::increment(&conn, item.id, &day(3), None).unwrap();
+        CompletionLogRepository::increment(&conn, item.id, &day(1), None).unwrap();
+
+        // Viewing the streak spends nothing
+        let heatmap = StreakService::get_item_heatmap_data(&conn, item.id)
+            .unwrap()
+            .unwrap();
+        assert!(heatmap.frozen_dates.is_empty());
+        assert!(StreakFreezeRepository::get_for_item(&conn, item.id)
+            .unwrap()
+            .is_empty());
+
+        assert_eq!(StreakService::record_freezes(&conn).unwrap(), 1);
+        let freezes = StreakFreezeRepository::get_pending(&conn).unwrap();
+        assert_eq!(freezes.len(), 1);
+        assert_eq!(freezes[0].frozen_on, day(2));
+        let heatmap = StreakService::get_item_heatmap_data(&conn, item.id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(heatmap.frozen_dates, vec![day(2)]);
+        assert_eq!(heatmap.current_streak, 2);
+
+        // Pushed freezes are not pushed again, and the day is done
+        StreakFreezeRepository::mark_synced(&conn, item.id, &day(2)).unwrap();
+        assert!(StreakFreezeRepository::get_pending(&conn)
+            .unwrap()
+            .is_empty());
+        assert_eq!(StreakService::record_freezes(&conn).unwrap(), 0);
+    }
 }
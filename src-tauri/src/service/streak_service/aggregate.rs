@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+
+use chrono::Duration;
+
+use super::*;
+use crate::models::{AggregateHeatmapData, HeatmapDayRatio, TodoItem};
+use crate::repository::TodoTagRepository;
+
+/// Days covered by aggregate heatmaps, like the per-item heatmap
+const AGGREGATE_DAYS: i64 = 365;
+
+impl StreakService {
+    /// Aggregate heatmap over the tracked items of a category
+    pub fn get_category_heatmap_data(
+        conn: &Connection,
+        category_id: i64,
+    ) -> Result<AggregateHeatmapData, rusqlite::Error> {
+        let items = TodoRepository::get_by_category(conn, Some(category_id))?;
+        Self::aggregate_heatmap(conn, &items)
+    }
+
+    /// Aggregate heatmap over the tracked items carrying a tag
+    pub fn get_tag_heatmap_data(
+        conn: &Connection,
+        tag_id: i64,
+    ) -> Result<AggregateHeatmapData, rusqlite::Error> {
+        let mut items = Vec::new();
+        for id in TodoTagRepository::get_todo_ids_by_tag(conn, tag_id)? {
+            items.extend(TodoRepository::get_by_id(conn, id)?);
+        }
+        Self::aggregate_heatmap(conn, &items)
+    }
+
+    /// Aggregate heatmap over every tracked item
+    pub fn get_all_heatmap_data(
+        conn: &Connection,
+    ) -> Result<AggregateHeatmapData, rusqlite::Error> {
+        let items = TodoRepository::get_all(conn)?;
+        Self::aggregate_heatmap(conn, &items)
+    }
+
+    fn aggregate_heatmap(
+        conn: &Connection,
+        items: &[TodoItem],
+    ) -> Result<AggregateHeatmapData, rusqlite::Error> {
+        let today = Self::get_logical_date(conn)?;
+        let from = today - Duration::days(AGGREGATE_DAYS - 1);
+
+        // date -> (completed slots, scheduled slots)
+        let mut totals: BTreeMap<NaiveDate, (i32, i32)> = BTreeMap::new();
+        let mut item_ids = Vec::new();
+        for item in items.iter().filter(|item| item.track_streak) {
+            item_ids.push(item.id);
+
+            let logs = CompletionLogRepository::get_all_logs_for_item(conn, item.id)?;
+            let cadence = Self::item_cadence(conn, item, &logs, today)?;
+            let completed: HashSet<NaiveDate> = Self::scheduled_completion_dates(&logs, &cadence)
+                .into_iter()
+                .collect();
+            let start = Self::tracking_start(item, &completed, today).max(from);
+
+            for slot in Self::scheduled_slots(&cadence, &completed, start, today) {
+                let entry = totals.entry(slot).or_default();
+                entry.1 += 1;
+                if completed.contains(&slot) {
+                    entry.0 += 1;
+                }
+            }
+        }
+
+        let stats = Self::perfect_day_streaks(&totals, today);
+        let days = totals
+            .into_iter()
+            .map(|(date, (completed, scheduled))| HeatmapDayRatio {
+                date: date.format("%Y-%m-%d").to_string(),
+                completed,
+                scheduled,
+                ratio: completed as f64 / scheduled as f64,
+            })
+            .collect();
+
+        Ok(AggregateHeatmapData {
+            item_ids,
+            days,
+            current_streak: stats.current_streak,
+            longest_streak: stats.longest_streak,
+            current_streak_dates: Self::format_dates(&stats.current_streak_dates),
+            longest_streak_dates: Self::format_dates(&stats.longest_streak_dates),
+        })
+    }
+
+    /// Cadence of an item with its skipped, excluded and frozen days neutral
+    fn item_cadence(
+        conn: &Connection,
+        item: &TodoItem,
+        logs: &[CompletionLog],
+        today: NaiveDate,
+    ) -> Result<StreakCadence, rusqlite::Error> {
+        let schedule = RepeatService::schedule_for(conn, item)?;
+        let mut neutral_dates: HashSet<NaiveDate> =
+            SkipLogRepository::get_dates_for_item(conn, item.id)?
+                .iter()
+                .filter_map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+                .collect();
+        let first_log = logs
+            .iter()
+            .filter_map(|log| NaiveDate::parse_from_str(&log.completed_on, "%Y-%m-%d").ok())
+            .min()
+            .unwrap_or(today);
+        neutral_dates.extend(Self::excluded_dates(&schedule, first_log, today));
+        neutral_dates.extend(Self::freeze_dates(&StreakFreezeRepository::get_for_item(
+            conn, item.id,
+        )?));
+
+        Ok(Self::cadence_for(conn, item.id, &schedule)?.with_skips(neutral_dates))
+    }
+
+    /// First day an item counts towards aggregates: its repeat start or
+    /// creation date, or an earlier completion
+    fn tracking_start(
+        item: &TodoItem,
+        completed: &HashSet<NaiveDate>,
+        today: NaiveDate,
+    ) -> NaiveDate {
+        let started = item
+            .repeat_start
+            .as_deref()
+            .or(item.created_at.as_deref())
+            .and_then(|s| s.get(..10))
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+            .unwrap_or(today);
+        completed.iter().copied().fold(started, NaiveDate::min)
+    }
+
+    /// Slots between `from` and `to` (inclusive) the item was expected on.
+    /// Completion-relative cadences only expect their completion days and
+    /// the deadlines that passed between them.
+    fn scheduled_slots(
+        cadence: &StreakCadence,
+        completed: &HashSet<NaiveDate>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Vec<NaiveDate> {
+        if !cadence.is_completion_relative() {
+            return from
+                .iter_days()
+                .take_while(|date| *date <= to)
+                .filter(|date| cadence.expects_slot_on(*date))
+                .collect();
+        }
+
+        let mut dates: Vec<NaiveDate> = completed.iter().copied().collect();
+        dates.sort();
+        let mut slots = dates.clone();
+        for pair in dates.windows(2) {
+            slots.extend(Self::missed_slots(cadence, pair[0], pair[1]));
+        }
+        if let Some(last) = dates.last() {
+            slots.extend(Self::missed_slots(cadence, *last, to));
+        }
+        slots.retain(|date| from <= *date && *date <= to);
+        slots
+    }
+
+    /// Runs of perfect days. Days without scheduled slots are neutral, and
+    /// today's open slots don't break the current run yet.
+    fn perfect_day_streaks(
+        totals: &BTreeMap<NaiveDate, (i32, i32)>,
+        today: NaiveDate,
+    ) -> StreakStats {
+        let mut run: Vec<NaiveDate> = Vec::new();
+        let mut longest: Vec<NaiveDate> = Vec::new();
+
+        for (date, (completed, scheduled)) in totals {
+            if completed >= scheduled {
+                run.push(*date);
+                // Ties go to the latest run, like the per-item streaks
+                if run.len() >= longest.len() {
+                    longest = run.clone();
+                }
+            } else if *date < today {
+                run.clear();
+            }
+        }
+
+        StreakStats {
+            current_streak: run.len() as i32,
+            longest_streak: longest.len() as i32,
+            current_streak_dates: run,
+            longest_streak_dates: longest,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn perfect_days_skip_neutral_days_and_wait_for_today() {
+        let totals = BTreeMap::from([
+            (date(2026, 3, 1), (2, 2)),
+            (date(2026, 3, 2), (1, 2)),
+            (date(2026, 3, 3), (1, 1)),
+            // 3-04 has nothing scheduled
+            (date(2026, 3, 5), (2, 2)),
+            (date(2026, 3, 6), (0, 2)),
+        ]);
+
+        let stats = StreakService::perfect_day_streaks(&totals, date(2026, 3, 6));
+
+        assert_eq!(
+            stats.current_streak_dates,
+            vec![date(2026, 3, 3), date(2026, 3, 5)]
+        );
+        assert_eq!(stats.longest_streak, 2);
+    }
+
+    #[test]
+    fn completion_relative_slots_are_completions_and_passed_deadlines() {
+        let cadence = StreakCadence::AfterCompletion(3);
+        let completed = HashSet::from([date(2026, 3, 1), date(2026, 3, 9)]);
+
+        let mut slots = StreakService::scheduled_slots(
+            &cadence,
+            &completed,
+            date(2026, 3, 1),
+            date(2026, 3, 10),
+        );
+        slots.sort();
+
+        assert_eq!(
+            slots,
+            vec![
+                date(2026, 3, 1),
+                date(2026, 3, 4),
+                date(2026, 3, 7),
+                date(2026, 3, 9)
+            ]
+        );
+    }
+}
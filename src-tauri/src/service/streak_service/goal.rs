@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+
+use chrono::{Duration, Months, Weekday};
+
+use super::*;
+use crate::models::{GoalPeriod, GoalPeriodProgress};
+
+/// Setting holding the first day of goal weeks, counted from Sunday as
+/// in `repeat_detail`
+const WEEK_START_KEY: &str = "week_start";
+
+impl StreakService {
+    /// First day of goal weeks; Monday unless the user picked another day
+    pub(super) fn week_start(conn: &Connection) -> Result<Weekday, rusqlite::Error> {
+        let day = SettingsRepository::get(conn, WEEK_START_KEY)?
+            .and_then(|value| value.parse::<u8>().ok())
+            .filter(|day| *day < 7);
+        // The setting counts from Sunday, chrono from Monday
+        Ok(day
+            .and_then(|day| Weekday::try_from((day + 6) % 7).ok())
+            .unwrap_or(Weekday::Mon))
+    }
+
+    /// Progress of every goal period from the first completion up to the
+    /// period containing `today`
+    pub(super) fn goal_progress(
+        logs: &[CompletionLog],
+        period: &GoalPeriod,
+        target: i32,
+        today: NaiveDate,
+        week_start: Weekday,
+    ) -> Vec<GoalPeriodProgress> {
+        let mut counts: BTreeMap<NaiveDate, i32> = BTreeMap::new();
+        for log in logs {
+            if let Ok(date) = NaiveDate::parse_from_str(&log.completed_on, "%Y-%m-%d") {
+                *counts
+                    .entry(Self::period_start(period, date, week_start))
+                    .or_default() += log.completed_count;
+            }
+        }
+        let Some(mut start) = counts.keys().next().copied() else {
+            return Vec::new();
+        };
+
+        let current = Self::period_start(period, today, week_start);
+        let mut progress = Vec::new();
+        while start <= current {
+            let next = Self::next_period_start(period, start);
+            let completed = counts.get(&start).copied().unwrap_or(0);
+            progress.push(GoalPeriodProgress {
+                period_start: start.format("%Y-%m-%d").to_string(),
+                period_end: (next - Duration::days(1)).format("%Y-%m-%d").to_string(),
+                completed,
+                target,
+                met: completed >= target,
+            });
+            start = next;
+        }
+        progress
+    }
+
+    /// Streaks counted in consecutive periods that met the goal. The running
+    /// period doesn't break the current streak before it has ended. The
+    /// streak dates are the completions inside the streak periods.
+    pub(super) fn goal_streaks(
+        progress: &[GoalPeriodProgress],
+        logs: &[CompletionLog],
+    ) -> (StreakStats, Vec<Vec<NaiveDate>>) {
+        let mut completion_dates: Vec<NaiveDate> = logs
+            .iter()
+            .filter_map(|log| NaiveDate::parse_from_str(&log.completed_on, "%Y-%m-%d").ok())
+            .collect();
+        completion_dates.sort();
+        let mut segments = Vec::new();
+        let mut run: (i32, Vec<NaiveDate>) = (0, Vec::new());
+        let mut longest: (i32, Vec<NaiveDate>) = (0, Vec::new());
+
+        for (index, period) in progress.iter().enumerate() {
+            if period.met {
+                run.0 += 1;
+                run.1.extend(completion_dates.iter().filter(|date| {
+                    let date = date.format("%Y-%m-%d").to_string();
+                    period.period_start <= date && date <= period.period_end
+                }));
+                // Ties go to the latest run, like the per-item streaks
+                if run.0 >= longest.0 {
+                    longest = run.clone();
+                }
+            } else if index + 1 < progress.len() && run.0 > 0 {
+                segments.push(std::mem::take(&mut run).1);
+            }
+        }
+        if run.0 > 0 {
+            segments.push(run.1.clone());
+        }
+
+        let stats = StreakStats {
+            current_streak: run.0,
+            longest_streak: longest.0,
+            current_streak_dates: run.1,
+            longest_streak_dates: longest.1,
+        };
+        (stats, segments)
+    }
+
+    /// First day of the week or month containing `date`
+    fn period_start(period: &GoalPeriod, date: NaiveDate, week_start: Weekday) -> NaiveDate {
+        match period {
+            GoalPeriod::Month => date.with_day(1).unwrap_or(date),
+            _ => {
+                let offset = (7 + date.weekday().num_days_from_monday()
+                    - week_start.num_days_from_monday())
+                    % 7;
+                date - Duration::days(offset as i64)
+            }
+        }
+    }
+
+    fn next_period_start(period: &GoalPeriod, start: NaiveDate) -> NaiveDate {
+        match period {
+            GoalPeriod::Month => start
+                .checked_add_months(Months::new(1))
+                .unwrap_or(start + Duration::days(31)),
+            _ => start + Duration::days(7),
+        }
+    }
+
+    /// Set an item's frequency goal. No period clears the target.
+    pub fn update_goal(
+        conn: &Connection,
+        item_id: i64,
+        goal_period: &str,
+        goal_target: i32,
+    ) -> Result<(), String> {
+        let goal_period = GoalPeriod::from_str(goal_period);
+        let goal_target = if goal_period == GoalPeriod::None {
+            0
+        } else if (1..=100).contains(&goal_target) {
+            goal_target
+        } else {
+            return Err("Goal target must be between 1 and 100".to_string());
+        };
+
+        TodoRepository::update_goal(conn, item_id, &goal_period, goal_target)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn log(completed_on: &str, completed_count: i32) -> CompletionLog {
+        CompletionLog {
+            item_id: 1,
+            completed_on: completed_on.to_string(),
+            completed_count,
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn weekly_goal_counts_completions_per_week() {
+        // Weeks starting 2026-03-02, 03-09, 03-16 and the running 03-23
+        let logs = vec![
+            log("2026-03-02", 1),
+            log("2026-03-04", 2),
+            log("2026-03-10", 1),
+            log("2026-03-16", 3),
+            log("2026-03-23", 1),
+        ];
+
+        let progress = StreakService::goal_progress(
+            &logs,
+            &GoalPeriod::Week,
+            3,
+            date(2026, 3, 25),
+            Weekday::Mon,
+        );
+
+        let completed: Vec<i32> = progress.iter().map(|p| p.completed).collect();
+        assert_eq!(completed, vec![3, 1, 3, 1]);
+        assert_eq!(progress[1].period_start, "2026-03-09");
+        assert_eq!(progress[1].period_end, "2026-03-15");
+    }
+
+    #[test]
+    fn weekly_goal_follows_the_week_start_setting() {
+        let conn = crate::repository::open_in_memory();
+        assert_eq!(StreakService::week_start(&conn).unwrap(), Weekday::Mon);
+        SettingsRepository::set(&conn, WEEK_START_KEY, "0").unwrap();
+        let week_start = StreakService::week_start(&conn).unwrap();
+        assert_eq!(week_start, Weekday::Sun);
+
+        // Sunday 2026-03-08 opens a new week, Monday 2026-03-09 doesn't
+        let logs = vec![
+            log("2026-03-07", 1),
+            log("2026-03-08", 1),
+            log("2026-03-09", 1),
+        ];
+        let progress = StreakService::goal_progress(
+            &logs,
+            &GoalPeriod::Week,
+            2,
+            date(2026, 3, 10),
+            week_start,
+        );
+
+        let completed: Vec<i32> = progress.iter().map(|p| p.completed).collect();
+        assert_eq!(completed, vec![1, 2]);
+        assert_eq!(progress[1].period_start, "2026-03-08");
+        assert_eq!(progress[1].period_end, "2026-03-14");
+    }
+
+    #[test]
+    fn running_period_keeps_the_period_streak() {
+        let logs = vec![
+            log("2026-01-05", 2),
+            log("2026-02-03", 2),
+            log("2026-03-10", 2),
+            log("2026-04-01", 1),
+        ];
+        let dates: Vec<NaiveDate> = logs
+            .iter()
+            .map(|l| NaiveDate::parse_from_str(&l.completed_on, "%Y-%m-%d").unwrap())
+            .collect();
+        let progress = StreakService::goal_progress(
+            &logs,
+            &GoalPeriod::Month,
+            2,
+            date(2026, 4, 15),
+            Weekday::Mon,
+        );
+
+        let (stats, segments) = StreakService::goal_streaks(&progress, &logs);
+
+        assert_eq!(stats.current_streak, 3);
+        assert_eq!(stats.longest_streak, 3);
+        assert_eq!(stats.current_streak_dates, dates[..3].to_vec());
+        assert_eq!(segments.len(), 1);
+    }
+}
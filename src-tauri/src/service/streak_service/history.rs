@@ -0,0 +1,242 @@
+use chrono::Duration;
+
+use super::*;
+
+/// How far back completions may be added or removed
+const BACKFILL_DAYS: i64 = 30;
+
+impl StreakService {
+    /// Record a forgotten completion on a past date. The date must lie
+    /// within the last BACKFILL_DAYS days, after the item started, and be
+    /// a day its schedule expects it on. Returns the recalculated heatmap.
+    pub fn backfill_completion(
+        conn: &Connection,
+        item_id: i64,
+        date: &str,
+    ) -> Result<Option<HeatmapData>, String> {
+        let Some(date) = Self::validate_history_date(conn, item_id, date)? else {
+            return Ok(None);
+        };
+
+        CompletionLogRepository::increment(conn, item_id, &date, None)
+            .map_err(|e| e.to_string())?;
+        Self::finish_history_edit(conn, item_id)
+    }
+
+    /// Remove one completion from a past date within the same limits.
+    /// Returns the recalculated heatmap.
+    pub fn remove_past_completion(
+        conn: &Connection,
+        item_id: i64,
+        date: &str,
+    ) -> Result<Option<HeatmapData>, String> {
+        let Some(date) = Self::validate_history_date(conn, item_id, date)? else {
+            return Ok(None);
+        };
+
+        let logged = CompletionLogRepository::get_all_logs_for_item(conn, item_id)
+            .map_err(|e| e.to_string())?
+            .iter()
+            .any(|log| log.completed_on == date);
+        if !logged {
+            return Err("No completion on that date".to_string());
+        }
+
+        CompletionLogRepository::decrement(conn, item_id, &date).map_err(|e| e.to_string())?;
+        Self::finish_history_edit(conn, item_id)
+    }
+
+    /// Check that `date` may be edited for the item. None if the item is gone.
+    fn validate_history_date(
+        conn: &Connection,
+        item_id: i64,
+        date: &str,
+    ) -> Result<Option<String>, String> {
+        let Some(item) = TodoRepository::get_by_id(conn, item_id).map_err(|e| e.to_string())?
+        else {
+            return Ok(None);
+        };
+        let parsed = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| "Date must be YYYY-MM-DD".to_string())?;
+
+        let today = Self::get_logical_date(conn).map_err(|e| e.to_string())?;
+        if parsed >= today {
+            return Err("Only past dates can be edited".to_string());
+        }
+        if parsed < today - Duration::days(BACKFILL_DAYS) {
+            return Err(format!(
+                "Only the last {} days can be edited",
+                BACKFILL_DAYS
+            ));
+        }
+
+        // A schedule change moves repeat_start to the day of the change, so
+        // the item started with the first schedule it followed
+        let versions =
+            ScheduleVersionRepository::get_for_item(conn, item_id).map_err(|e| e.to_string())?;
+        let started = match versions.first() {
+            Some(first) => first
+                .repeat_start
+                .as_deref()
+                .or(Some(first.effective_from.as_str())),
+            None => item.repeat_start.as_deref().or(item.created_at.as_deref()),
+        }
+        .and_then(|s| s.get(..10))
+        .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+        if started.is_some_and(|started| parsed < started) {
+            return Err("Date is before the item started".to_string());
+        }
+
+        let schedule = RepeatService::schedule_for(conn, &item).map_err(|e| e.to_string())?;
+        let date = parsed.format("%Y-%m-%d").to_string();
+        if schedule.is_excluded(&date)
+            || !StreakCadence::from_versions(&versions, &schedule).is_scheduled_on(parsed)
+        {
+            return Err("Item is not scheduled on that date".to_string());
+        }
+
+        Ok(Some(date))
+    }
+
+    /// Recalculate after an edit. The edited log is pending sync on its own.
+    fn finish_history_edit(conn: &Connection, item_id: i64) -> Result<Option<HeatmapData>, String> {
+        Self::get_item_heatmap_data(conn, item_id).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::TodoService;
+
+    /// An item created `days_ago` days before the logical today
+    fn item_since(conn: &Connection, repeat: RepeatSchedule, days_ago: i64) -> (i64, NaiveDate) {
+        let today = StreakService::get_logical_date(conn).unwrap();
+        let item = TodoService::create_item(conn, "Stretch", None, repeat, true, None).unwrap();
+        let started = (today - Duration::days(days_ago)).format("%Y-%m-%d");
+        conn.execute(
+            "UPDATE todos SET created_at = ?1, repeat_start = ?2 WHERE id = ?3",
+            rusqlite::params![format!("{started}T08:00:00Z"), started.to_string(), item.id],
+        )
+        .unwrap();
+        (item.id, today)
+    }
+
+    fn day(date: NaiveDate) -> String {
+        date.format("%Y-%m-%d").to_string()
+    }
+
+    #[test]
+    fn only_recent_past_dates_can_be_edited() {
+        let conn = crate::repository::open_in_memory();
+        let (id, today) = item_since(&conn, RepeatSchedule::new(RepeatType::Daily, None), 60);
+
+        for date in [
+            day(today),
+            day(today + Duration::days(1)),
+            day(today - Duration::days(BACKFILL_DAYS + 1)),
+            "yesterday".to_string(),
+        ] {
+            assert!(StreakService::backfill_completion(&conn, id, &date).is_err());
+        }
+
+        let yesterday = day(today - Duration::days(1));
+        let data = StreakService::backfill_completion(&conn, id, &yesterday)
+            .unwrap()
+            .unwrap();
+        assert_eq!(data.total_days, 1);
+    }
+
+    #[test]
+    fn dates_follow_the_item_schedule() {
+        let conn = crate::repository::open_in_memory();
+        let today = StreakService::get_logical_date(&conn).unwrap();
+        let yesterday = today - Duration::days(1);
+        let weekday = yesterday.weekday().num_days_from_sunday();
+        let other_day = (weekday + 1) % 7;
+        let repeat = RepeatSchedule::new(RepeatType::Weekly, Some(&format!("[{other_day}]")));
+        let (id, _) = item_since(&conn, repeat, 20);
+
+        let result = StreakService::backfill_completion(&conn, id, &day(yesterday));
+
+        assert_eq!(
+            result.unwrap_err(),
+            "Item is not scheduled on that date".to_string()
+        );
+    }
+
+    #[test]
+    fn schedule_change_keeps_earlier_days_editable() {
+        let conn = crate::repository::open_in_memory();
+        let (id, today) = item_since(&conn, RepeatSchedule::new(RepeatType::Daily, None), 10);
+        let mut every_other_day = RepeatSchedule::new(RepeatType::Daily, None);
+        every_other_day.repeat_interval = 2;
+        TodoService::update_repeat(&conn, id, every_other_day).unwrap();
+
+        assert!(
+            StreakService::backfill_completion(&conn, id, &day(today - Duration::days(5))).is_ok()
+        );
+        assert_eq!(
+            StreakService::backfill_completion(&conn, id, &day(today - Duration::days(11)))
+                .unwrap_err(),
+            "Date is before the item started".to_string()
+        );
+    }
+
+    #[test]
+    fn removed_completion_survives_an_older_pull() {
+        let conn = crate::repository::open_in_memory();
+        let (id, today) = item_since(&conn, RepeatSchedule::new(RepeatType::Daily, None), 10);
+        let yesterday = day(today - Duration::days(1));
+        StreakService::backfill_completion(&conn, id, &yesterday).unwrap();
+        let (_, pushed_at) = CompletionLogRepository::get_pending(&conn)
+            .unwrap()
+            .remove(0);
+        CompletionLogRepository::mark_synced(&conn, id, &yesterday, &pushed_at).unwrap();
+
+        StreakService::remove_past_completion(&conn, id, &yesterday).unwrap();
+        // The server still has the pushed count until the removal goes out
+        CompletionLogRepository::upsert(
+            &conn,
+            id,
+            &yesterday,
+            1,
+            None,
+            Some("2000-01-01T00:00:00Z"),
+        )
+        .unwrap();
+        let pending = CompletionLogRepository::get_pending(&conn).unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].0.completed_count, 0);
+
+        // Once pushed, only a later change from another device counts
+        CompletionLogRepository::mark_synced(&conn, id, &yesterday, &pending[0].1).unwrap();
+        CompletionLogRepository::upsert(
+            &conn,
+            id,
+            &yesterday,
+            1,
+            None,
+            Some("2000-01-01T00:00:00Z"),
+        )
+        .unwrap();
+        assert!(CompletionLogRepository::get_all_logs_for_item(&conn, id)
+            .unwrap()
+            .is_empty());
+        CompletionLogRepository::upsert(
+            &conn,
+            id,
+            &yesterday,
+            1,
+            None,
+            Some("2999-01-01T00:00:00Z"),
+        )
+        .unwrap();
+        assert_eq!(
+            CompletionLogRepository::get_all_logs_for_item(&conn, id)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
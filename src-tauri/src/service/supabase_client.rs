@@ -2,6 +2,8 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::models::TemplateItem;
+
 #[derive(Debug, Clone)]
 pub struct SupabaseConfig {
     pub url: String,
@@ -51,6 +53,12 @@ pub struct RemoteCategory {
     pub user_id: String,
     pub name: String,
     pub display_order: i32,
+    #[serde(default)]
+    pub reset_policy: String,
+    #[serde(default)]
+    pub reset_times: Option<String>,
+    #[serde(default)]
+    pub reset_weekdays: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -66,6 +74,39 @@ pub struct RemoteTodo {
     pub memo: Option<String>,
     pub repeat_type: String,
     pub repeat_detail: Option<String>,
+    #[serde(default)]
+    pub repeat_interval: i32,
+    #[serde(default)]
+    pub repeat_start: Option<String>,
+    #[serde(default)]
+    pub repeat_until: Option<String>,
+    #[serde(default)]
+    pub repeat_count: Option<i32>,
+    #[serde(default)]
+    pub repeat_completed: i32,
+    #[serde(default)]
+    pub repeat_ended: bool,
+    #[serde(default)]
+    pub due_at: Option<String>,
+    #[serde(default)]
+    pub due_times: Option<String>,
+    #[serde(default)]
+    pub streak_freezes_per_month: i32,
+    #[serde(default)]
+    pub streak_grace_per_week: i32,
+    #[serde(default)]
+    pub goal_period: String,
+    #[serde(default)]
+    pub goal_target: i32,
+    #[serde(default)]
+    pub quantity_target: i32,
+    #[serde(default)]
+    pub quantity_progress: i32,
+    /// Sync id of the parent todo
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub auto_complete: bool,
     pub next_due_at: Option<String>,
     pub last_completed_at: Option<String>,
     pub track_streak: bool,
@@ -82,6 +123,66 @@ pub struct RemoteCompletionLog {
     pub todo_id: String,
     pub completed_on: String,
     pub completed_count: i32,
+    /// Local time of the day's latest completion
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    /// When the count last changed; missing on logs from older versions
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// Repeat settings a todo followed from `effective_from` on
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteScheduleVersion {
+    pub id: String,
+    pub user_id: String,
+    pub todo_id: String,
+    pub effective_from: String,
+    pub repeat_type: String,
+    pub repeat_detail: Option<String>,
+    pub repeat_interval: i32,
+    pub repeat_start: Option<String>,
+    pub repeat_until: Option<String>,
+    pub repeat_count: Option<i32>,
+}
+
+/// Value recorded with one completion
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteMeasurement {
+    pub id: String,
+    pub user_id: String,
+    pub todo_id: String,
+    pub recorded_on: String,
+    pub recorded_at: String,
+    pub value: f64,
+    pub unit: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteSkipLog {
+    pub id: String,
+    pub user_id: String,
+    pub todo_id: String,
+    pub skipped_on: String,
+}
+
+/// A setting shared between devices; one row per user and key
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteSetting {
+    pub id: String,
+    pub user_id: String,
+    pub key: String,
+    pub value: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteStreakFreeze {
+    pub id: String,
+    pub user_id: String,
+    pub todo_id: String,
+    pub frozen_on: String,
+    pub kind: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -93,6 +194,18 @@ pub struct RemoteTag {
     pub updated_at: String,
 }
 
+/// Checklist template with its items stored inline as JSON
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteTemplate {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub items: Vec<TemplateItem>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RemoteTodoTag {
     pub id: String,
@@ -1,4 +1,6 @@
 use super::*;
 
 mod data_ops;
+mod settings_ops;
 mod tag_ops;
+mod template_ops;
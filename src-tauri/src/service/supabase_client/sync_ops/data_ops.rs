@@ -1,2 +1,6 @@
 mod completion_ops;
+mod measurement_ops;
+mod schedule_version_ops;
+mod skip_ops;
+mod streak_freeze_ops;
 mod todo_category_ops;
@@ -0,0 +1,83 @@
+use super::super::*;
+
+impl SupabaseClient {
+    // Upsert a measurement
+    pub async fn upsert_measurement(
+        &self,
+        access_token: &str,
+        measurement: &RemoteMeasurement,
+    ) -> Result<(), String> {
+        let url = format!("{}/measurements", self.rest_url());
+
+        let response = self
+            .client
+            .post(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Content-Type", "application/json")
+            .header("Prefer", "resolution=merge-duplicates")
+            .json(measurement)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Upsert measurement failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+
+    // Fetch all measurements for the current user
+    pub async fn fetch_all_measurements(
+        &self,
+        access_token: &str,
+    ) -> Result<Vec<RemoteMeasurement>, String> {
+        let url = format!("{}/measurements?select=*", self.rest_url());
+
+        let response = self
+            .client
+            .get(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Fetch all measurements failed: {}", error_text));
+        }
+
+        response
+            .json::<Vec<RemoteMeasurement>>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    }
+
+    // Delete a measurement
+    pub async fn delete_measurement(
+        &self,
+        access_token: &str,
+        measurement_id: &str,
+    ) -> Result<(), String> {
+        let url = format!("{}/measurements?id=eq.{}", self.rest_url(), measurement_id);
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Delete measurement failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+}
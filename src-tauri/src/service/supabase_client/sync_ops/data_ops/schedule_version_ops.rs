@@ -0,0 +1,83 @@
+use super::super::*;
+
+impl SupabaseClient {
+    // Upsert a schedule version
+    pub async fn upsert_schedule_version(
+        &self,
+        access_token: &str,
+        version: &RemoteScheduleVersion,
+    ) -> Result<(), String> {
+        let url = format!("{}/schedule_versions", self.rest_url());
+
+        let response = self
+            .client
+            .post(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Content-Type", "application/json")
+            .header("Prefer", "resolution=merge-duplicates")
+            .json(version)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Upsert schedule version failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+
+    // Fetch all schedule versions for the current user
+    pub async fn fetch_all_schedule_versions(
+        &self,
+        access_token: &str,
+    ) -> Result<Vec<RemoteScheduleVersion>, String> {
+        let url = format!("{}/schedule_versions?select=*", self.rest_url());
+
+        let response = self
+            .client
+            .get(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Fetch all schedule versions failed: {}", error_text));
+        }
+
+        response
+            .json::<Vec<RemoteScheduleVersion>>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    }
+
+    // Delete a schedule version
+    pub async fn delete_schedule_version(
+        &self,
+        access_token: &str,
+        version_id: &str,
+    ) -> Result<(), String> {
+        let url = format!("{}/schedule_versions?id=eq.{}", self.rest_url(), version_id);
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Delete schedule version failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use super::super::*;
+
+impl SupabaseClient {
+    // Upsert a skip log
+    pub async fn upsert_skip_log(
+        &self,
+        access_token: &str,
+        log: &RemoteSkipLog,
+    ) -> Result<(), String> {
+        let url = format!("{}/skip_logs", self.rest_url());
+
+        let response = self
+            .client
+            .post(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Content-Type", "application/json")
+            .header("Prefer", "resolution=merge-duplicates")
+            .json(log)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Upsert skip log failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+
+    // Fetch all skip logs for the current user
+    pub async fn fetch_all_skip_logs(
+        &self,
+        access_token: &str,
+    ) -> Result<Vec<RemoteSkipLog>, String> {
+        let url = format!("{}/skip_logs?select=*", self.rest_url());
+
+        let response = self
+            .client
+            .get(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Fetch all skip logs failed: {}", error_text));
+        }
+
+        response
+            .json::<Vec<RemoteSkipLog>>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    }
+
+    // Delete a skip log
+    pub async fn delete_skip_log(&self, access_token: &str, log_id: &str) -> Result<(), String> {
+        let url = format!("{}/skip_logs?id=eq.{}", self.rest_url(), log_id);
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Delete skip log failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use super::super::*;
+
+impl SupabaseClient {
+    // Upsert a streak freeze
+    pub async fn upsert_streak_freeze(
+        &self,
+        access_token: &str,
+        freeze: &RemoteStreakFreeze,
+    ) -> Result<(), String> {
+        let url = format!("{}/streak_freezes", self.rest_url());
+
+        let response = self
+            .client
+            .post(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Content-Type", "application/json")
+            .header("Prefer", "resolution=merge-duplicates")
+            .json(freeze)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Upsert streak freeze failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+
+    // Fetch all streak freezes for the current user
+    pub async fn fetch_all_streak_freezes(
+        &self,
+        access_token: &str,
+    ) -> Result<Vec<RemoteStreakFreeze>, String> {
+        let url = format!("{}/streak_freezes?select=*", self.rest_url());
+
+        let response = self
+            .client
+            .get(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Fetch all streak freezes failed: {}", error_text));
+        }
+
+        response
+            .json::<Vec<RemoteStreakFreeze>>()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))
+    }
+}
@@ -0,0 +1,53 @@
+use super::*;
+
+impl SupabaseClient {
+    pub async fn fetch_settings(&self, access_token: &str) -> Result<Vec<RemoteSetting>, String> {
+        let url = format!("{}/user_settings?select=*", self.rest_url());
+
+        let response = self
+            .client
+            .get(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Fetch settings failed: {}", error_text));
+        }
+
+        response
+            .json::<Vec<RemoteSetting>>()
+            .await
+            .map_err(|e| format!("Failed to parse settings: {}", e))
+    }
+
+    pub async fn upsert_setting(
+        &self,
+        access_token: &str,
+        setting: &RemoteSetting,
+    ) -> Result<(), String> {
+        let url = format!("{}/user_settings", self.rest_url());
+
+        let response = self
+            .client
+            .post(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Content-Type", "application/json")
+            .header("Prefer", "resolution=merge-duplicates")
+            .json(setting)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Upsert setting failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+}
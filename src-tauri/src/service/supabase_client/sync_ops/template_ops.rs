@@ -0,0 +1,73 @@
+use super::*;
+
+impl SupabaseClient {
+    pub async fn fetch_templates(&self, access_token: &str) -> Result<Vec<RemoteTemplate>, String> {
+        let url = format!("{}/checklist_templates?select=*", self.rest_url());
+
+        let response = self
+            .client
+            .get(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Fetch templates failed: {}", error_text));
+        }
+
+        response
+            .json::<Vec<RemoteTemplate>>()
+            .await
+            .map_err(|e| format!("Failed to parse templates: {}", e))
+    }
+
+    pub async fn upsert_template(
+        &self,
+        access_token: &str,
+        template: &RemoteTemplate,
+    ) -> Result<(), String> {
+        let url = format!("{}/checklist_templates", self.rest_url());
+
+        let response = self
+            .client
+            .post(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .header("Content-Type", "application/json")
+            .header("Prefer", "resolution=merge-duplicates")
+            .json(template)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Upsert template failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete_template(&self, access_token: &str, sync_id: &str) -> Result<(), String> {
+        let url = format!("{}/checklist_templates?id=eq.{}", self.rest_url(), sync_id);
+
+        let response = self
+            .client
+            .delete(&url)
+            .header("apikey", &self.config.anon_key)
+            .header("Authorization", format!("Bearer {}", access_token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(format!("Delete template failed: {}", error_text));
+        }
+
+        Ok(())
+    }
+}
@@ -1,17 +1,24 @@
 use chrono::Utc;
 use rusqlite::Connection;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
-use crate::models::{Category, SyncResult, SyncStatus, TodoItem};
+use crate::models::{
+    Category, FreezeKind, GoalPeriod, RepeatType, ResetPolicy, ScheduleVersion, SyncResult,
+    SyncStatus, TemplateItem, TodoItem,
+};
 use crate::repository::{
-    CategoryRepository, CompletionLogRepository, SyncRepository, TagRepository, TodoRepository,
-    TodoTagRepository,
+    CategoryRepository, CompletionLogRepository, JournalRepository, MeasurementRepository,
+    ScheduleVersionRepository, SettingsRepository, SkipLogRepository, StreakFreezeRepository,
+    SyncRepository, TagRepository, TemplateRepository, TodoRepository, TodoTagRepository,
 };
 
 use super::supabase_client::{
-    RemoteCategory, RemoteCompletionLog, RemoteTag, RemoteTodo, RemoteTodoTag, SupabaseClient,
+    RemoteCategory, RemoteCompletionLog, RemoteMeasurement, RemoteScheduleVersion, RemoteSetting,
+    RemoteSkipLog, RemoteStreakFreeze, RemoteTag, RemoteTemplate, RemoteTodo, RemoteTodoTag,
+    SupabaseClient,
 };
+use super::TimeZoneService;
 
 mod apply;
 mod collect;
@@ -19,6 +26,9 @@ mod push;
 
 pub struct SyncService;
 
+/// Settings that follow the user across devices
+const SYNCED_SETTINGS: [&str; 1] = [TimeZoneService::SETTING_KEY];
+
 /// Data structures to pass between sync phases (to avoid holding connection across async)
 #[derive(Debug, Clone)]
 struct PendingCategorySync {
@@ -26,6 +36,9 @@ struct PendingCategorySync {
     sync_id: Option<String>,
     name: String,
     display_order: i64,
+    reset_policy: ResetPolicy,
+    reset_times: Option<String>,
+    reset_weekdays: Option<String>,
     created_at: Option<String>,
     updated_at: Option<String>,
     sync_status: SyncStatus,
@@ -42,6 +55,23 @@ struct PendingTodoSync {
     memo: Option<String>,
     repeat_type: String,
     repeat_detail: Option<String>,
+    repeat_interval: i32,
+    repeat_start: Option<String>,
+    repeat_until: Option<String>,
+    repeat_count: Option<i32>,
+    repeat_completed: i32,
+    repeat_ended: bool,
+    due_at: Option<String>,
+    due_times: Option<String>,
+    streak_freezes_per_month: i32,
+    streak_grace_per_week: i32,
+    goal_period: String,
+    goal_target: i32,
+    quantity_target: i32,
+    quantity_progress: i32,
+    parent_id: Option<i64>,
+    parent_sync_id: Option<String>,
+    auto_complete: bool,
     next_due_at: Option<String>,
     last_completed_at: Option<String>,
     track_streak: bool,
@@ -62,6 +92,17 @@ struct PendingTagSync {
     sync_status: SyncStatus,
 }
 
+#[derive(Debug, Clone)]
+struct PendingTemplateSync {
+    id: i64,
+    sync_id: Option<String>,
+    name: String,
+    items: Vec<TemplateItem>,
+    created_at: Option<String>,
+    updated_at: Option<String>,
+    sync_status: SyncStatus,
+}
+
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 struct PendingTodoTagSync {
@@ -81,6 +122,50 @@ struct LocalCompletionLogSync {
     todo_sync_id: String,
     completed_on: String,
     completed_count: i32,
+    completed_at: Option<String>,
+    updated_at: String,
+}
+
+#[derive(Debug, Clone)]
+struct LocalMeasurementSync {
+    id: i64,
+    sync_id: String,
+    todo_sync_id: String,
+    recorded_on: String,
+    recorded_at: String,
+    value: f64,
+    unit: Option<String>,
+    sync_status: SyncStatus,
+}
+
+#[derive(Debug, Clone)]
+struct LocalSkipLogSync {
+    item_id: i64,
+    todo_sync_id: String,
+    skipped_on: String,
+    sync_status: SyncStatus,
+}
+
+#[derive(Debug, Clone)]
+struct PendingSettingSync {
+    key: String,
+    value: String,
+    updated_at: String,
+}
+
+#[derive(Debug, Clone)]
+struct LocalScheduleVersionSync {
+    todo_sync_id: String,
+    version: ScheduleVersion,
+    sync_status: SyncStatus,
+}
+
+#[derive(Debug, Clone)]
+struct LocalStreakFreezeSync {
+    item_id: i64,
+    todo_sync_id: String,
+    frozen_on: String,
+    kind: FreezeKind,
 }
 
 impl SyncService {
@@ -117,7 +202,14 @@ impl SyncService {
             }
         }
 
-        let pending_todos = Self::collect_pending_todos_with_map(conn, &cat_id_to_sync_id)?;
+        let mut pending_todos = Self::collect_pending_todos_with_map(conn, &cat_id_to_sync_id)?;
+        // New todos get their sync id up front so sub-items can point at
+        // a parent pushed in the same run
+        for todo in &mut pending_todos {
+            if todo.sync_id.is_none() && todo.sync_status == SyncStatus::Pending {
+                todo.sync_id = Some(Uuid::new_v4().to_string());
+            }
+        }
 
         let all_todos = TodoRepository::get_all(conn).map_err(|e| e.to_string())?;
         let mut todo_id_to_sync_id: HashMap<i64, String> = HashMap::new();
@@ -131,8 +223,18 @@ impl SyncService {
                 todo_id_to_sync_id.insert(todo.id, sync_id.clone());
             }
         }
+        for todo in &mut pending_todos {
+            todo.parent_sync_id = todo
+                .parent_id
+                .and_then(|id| todo_id_to_sync_id.get(&id).cloned());
+        }
 
         let local_completion_logs = Self::collect_completion_logs(conn, &todo_id_to_sync_id)?;
+        let local_measurements = Self::collect_measurements(conn, &todo_id_to_sync_id)?;
+        let local_skip_logs = Self::collect_skip_logs(conn, &todo_id_to_sync_id)?;
+        let local_streak_freezes = Self::collect_streak_freezes(conn, &todo_id_to_sync_id)?;
+        let local_schedule_versions =
+            Self::collect_schedule_versions(conn, &todo_id_to_sync_id)?;
 
         let pending_tags = Self::collect_pending_tags(conn)?;
 
@@ -151,6 +253,8 @@ impl SyncService {
 
         let pending_todo_tags =
             Self::collect_pending_todo_tags(conn, &todo_id_to_sync_id, &tag_id_to_sync_id)?;
+        let pending_templates = Self::collect_pending_templates(conn)?;
+        let pending_settings = Self::collect_pending_settings(conn)?;
 
         let result = rt.block_on(async {
             let mut result = SyncResult::default();
@@ -163,26 +267,71 @@ impl SyncService {
             let pushed_logs =
                 Self::push_completion_logs_async(client, access_token, user_id, &local_completion_logs)
                     .await?;
+            let pushed_measurements =
+                Self::push_measurements_async(client, access_token, user_id, &local_measurements)
+                    .await?;
+            let pushed_skips =
+                Self::push_skip_logs_async(client, access_token, user_id, &local_skip_logs).await?;
+            let pushed_freezes = Self::push_streak_freezes_async(
+                client,
+                access_token,
+                user_id,
+                &local_streak_freezes,
+            )
+            .await?;
+            let pushed_versions = Self::push_schedule_versions_async(
+                client,
+                access_token,
+                user_id,
+                &local_schedule_versions,
+            )
+            .await?;
             let pushed_tags =
                 Self::push_tags_async(client, access_token, user_id, &pending_tags).await?;
             let pushed_todo_tags =
                 Self::push_todo_tags_async(client, access_token, user_id, &pending_todo_tags)
                     .await?;
+            let pushed_templates =
+                Self::push_templates_async(client, access_token, user_id, &pending_templates)
+                    .await?;
+            let pushed_settings =
+                Self::push_settings_async(client, access_token, user_id, &pending_settings).await?;
 
             result.pushed = pushed_cats.len()
                 + pushed_todos.len()
                 + pushed_logs
+                + pushed_measurements
+                + pushed_skips
+                + pushed_freezes
+                + pushed_versions
                 + pushed_tags.len()
-                + pushed_todo_tags;
+                + pushed_todo_tags
+                + pushed_templates.len()
+                + pushed_settings;
 
             let remote_categories = client.fetch_categories(access_token).await?;
             let remote_todos = client.fetch_todos(access_token).await?;
             let remote_completion_logs = client.fetch_all_completion_logs(access_token).await?;
+            let remote_measurements = client.fetch_all_measurements(access_token).await?;
+            // Skips missing remotely are removed locally, so a failed
+            // fetch must not read as an empty list
+            let remote_skip_logs = client.fetch_all_skip_logs(access_token).await?;
+            let remote_streak_freezes = client
+                .fetch_all_streak_freezes(access_token)
+                .await
+                .unwrap_or_default();
+            // Versions missing remotely are removed locally, so a failed
+            // fetch must not read as an empty list
+            let remote_schedule_versions = client.fetch_all_schedule_versions(access_token).await?;
             let remote_tags = client.fetch_tags(access_token).await.unwrap_or_default();
             let remote_todo_tags = client
                 .fetch_todo_tags(access_token)
                 .await
                 .unwrap_or_default();
+            // Templates missing remotely are deleted locally, so a failed
+            // fetch must not read as an empty list
+            let remote_templates = client.fetch_templates(access_token).await?;
+            let remote_settings = client.fetch_settings(access_token).await?;
 
             Ok::<_, String>(
                 (
@@ -190,11 +339,18 @@ impl SyncService {
                     pushed_cats,
                     pushed_todos,
                     pushed_tags,
+                    pushed_templates,
                     remote_categories,
                     remote_todos,
                     remote_completion_logs,
+                    remote_measurements,
+                    remote_skip_logs,
+                    remote_streak_freezes,
+                    remote_schedule_versions,
                     remote_tags,
                     remote_todo_tags,
+                    remote_templates,
+                    remote_settings,
                 ),
             )
         })?;
@@ -204,11 +360,18 @@ impl SyncService {
             pushed_cats,
             pushed_todos,
             pushed_tags,
+            pushed_templates,
             remote_categories,
             remote_todos,
             remote_completion_logs,
+            remote_measurements,
+            remote_skip_logs,
+            remote_streak_freezes,
+            remote_schedule_versions,
             remote_tags,
             remote_todo_tags,
+            remote_templates,
+            remote_settings,
         ) = result;
 
         for (local_id, sync_id) in pushed_cats {
@@ -253,9 +416,79 @@ impl SyncService {
             }
         }
 
-        let updated_local_categories = CategoryRepository::get_all(conn).map_err(|e| e.to_string())?;
-        let updated_local_todos = TodoRepository::get_all(conn).map_err(|e| e.to_string())?;
+        for (local_id, sync_id) in pushed_templates {
+            if let Some(template) = pending_templates.iter().find(|t| t.id == local_id) {
+                if template.sync_id.is_none() {
+                    TemplateRepository::update_sync_id(conn, local_id, &sync_id)
+                        .map_err(|e| e.to_string())?;
+                }
+                if template.sync_status == SyncStatus::Deleted {
+                    TemplateRepository::delete(conn, local_id).map_err(|e| e.to_string())?;
+                } else {
+                    TemplateRepository::mark_synced(conn, local_id).map_err(|e| e.to_string())?;
+                }
+            }
+        }
+
+        for setting in &pending_settings {
+            SettingsRepository::mark_synced(conn, &setting.key).map_err(|e| e.to_string())?;
+        }
+
+        for log in &local_completion_logs {
+            CompletionLogRepository::mark_synced(
+                conn,
+                log.item_id,
+                &log.completed_on,
+                &log.updated_at,
+            )
+            .map_err(|e| e.to_string())?;
+        }
+
+        for local in &local_schedule_versions {
+            let version = &local.version;
+            if local.sync_status == SyncStatus::Deleted {
+                ScheduleVersionRepository::delete(conn, version.item_id, &version.effective_from)
+            } else {
+                ScheduleVersionRepository::mark_synced(
+                    conn,
+                    version.item_id,
+                    &version.effective_from,
+                )
+            }
+            .map_err(|e| e.to_string())?;
+        }
+
+        for measurement in &local_measurements {
+            if measurement.sync_status == SyncStatus::Deleted {
+                MeasurementRepository::delete(conn, measurement.id)
+            } else {
+                MeasurementRepository::update_sync_id(conn, measurement.id, &measurement.sync_id)
+            }
+            .map_err(|e| e.to_string())?;
+        }
+
+        for freeze in &local_streak_freezes {
+            StreakFreezeRepository::mark_synced(conn, freeze.item_id, &freeze.frozen_on)
+                .map_err(|e| e.to_string())?;
+        }
 
+        for log in &local_skip_logs {
+            if log.sync_status == SyncStatus::Deleted {
+                SkipLogRepository::purge(conn, log.item_id, &log.skipped_on)
+            } else {
+                SkipLogRepository::mark_synced(conn, log.item_id, &log.skipped_on)
+            }
+            .map_err(|e| e.to_string())?;
+        }
+
+        // Trashed rows are still on the server, so they must be known here
+        // to be neither re-inserted nor overwritten
+        let updated_local_categories =
+            CategoryRepository::get_all_including_deleted(conn).map_err(|e| e.to_string())?;
+        let updated_local_todos =
+            TodoRepository::get_all_including_deleted(conn).map_err(|e| e.to_string())?;
+
+        let changes_before_pull = conn.total_changes();
         let pulled = Self::apply_remote_changes(
             conn,
             &updated_local_categories,
@@ -266,10 +499,37 @@ impl SyncService {
 
         let pulled_logs =
             Self::apply_remote_completion_logs(conn, &updated_local_todos, remote_completion_logs)?;
+        let pulled_measurements =
+            Self::apply_remote_measurements(conn, &updated_local_todos, remote_measurements)?;
+        let pulled_skips =
+            Self::apply_remote_skip_logs(conn, &updated_local_todos, remote_skip_logs)?;
+        let pulled_freezes =
+            Self::apply_remote_streak_freezes(conn, &updated_local_todos, remote_streak_freezes)?;
+        let pulled_versions = Self::apply_remote_schedule_versions(
+            conn,
+            &updated_local_todos,
+            remote_schedule_versions,
+        )?;
         let pulled_tags = Self::apply_remote_tags(conn, remote_tags)?;
         let pulled_todo_tags = Self::apply_remote_todo_tags(conn, remote_todo_tags)?;
+        let pulled_templates = Self::apply_remote_templates(conn, remote_templates)?;
+        let pulled_settings = Self::apply_remote_settings(conn, remote_settings)?;
+
+        // Undo would write back rows from before the remote changes
+        if conn.total_changes() != changes_before_pull {
+            JournalRepository::clear(conn).map_err(|e| e.to_string())?;
+        }
 
-        sync_result.pulled = pulled + pulled_logs + pulled_tags + pulled_todo_tags;
+        sync_result.pulled = pulled
+            + pulled_logs
+            + pulled_measurements
+            + pulled_skips
+            + pulled_freezes
+            + pulled_versions
+            + pulled_tags
+            + pulled_todo_tags
+            + pulled_templates
+            + pulled_settings;
 
         let now = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
         SyncRepository::set_last_synced_at(conn, &now).map_err(|e| e.to_string())?;
@@ -18,7 +18,9 @@ impl SyncService {
         for remote in &remote_categories {
             if let Some(&local_id) = category_sync_map.get(&remote.id) {
                 if let Some(local) = local_categories.iter().find(|c| c.id == local_id) {
-                    if Self::is_remote_newer(&local.updated_at, &remote.updated_at) {
+                    if local.sync_status != SyncStatus::Deleted
+                        && Self::is_remote_newer(&local.updated_at, &remote.updated_at)
+                    {
                         Self::update_local_category(conn, local, remote)?;
                         count += 1;
                     }
@@ -29,7 +31,8 @@ impl SyncService {
             }
         }
 
-        let updated_categories = CategoryRepository::get_all(conn).map_err(|e| e.to_string())?;
+        let updated_categories =
+            CategoryRepository::get_all_including_deleted(conn).map_err(|e| e.to_string())?;
         let category_sync_map: HashMap<String, i64> = updated_categories
             .iter()
             .filter_map(|c| c.sync_id.as_ref().map(|s| (s.clone(), c.id)))
@@ -40,6 +43,7 @@ impl SyncService {
             .filter_map(|t| t.sync_id.as_ref().map(|s| (s.clone(), t.id)))
             .collect();
 
+        let mut applied_todos = Vec::new();
         for remote in &remote_todos {
             let local_category_id = remote
                 .category_id
@@ -48,20 +52,49 @@ impl SyncService {
 
             if let Some(&local_id) = todo_sync_map.get(&remote.id) {
                 if let Some(local) = local_todos.iter().find(|t| t.id == local_id) {
-                    if Self::is_remote_newer(&local.updated_at, &remote.updated_at) {
+                    if local.sync_status != SyncStatus::Deleted
+                        && Self::is_remote_newer(&local.updated_at, &remote.updated_at)
+                    {
                         Self::update_local_todo(conn, local, remote, local_category_id)?;
+                        applied_todos.push(remote);
                         count += 1;
                     }
                 }
             } else {
                 Self::insert_todo_from_remote(conn, remote, local_category_id)?;
+                applied_todos.push(remote);
                 count += 1;
             }
         }
 
+        Self::link_remote_parents(conn, &applied_todos)?;
+
         Ok(count)
     }
 
+    /// Point applied sub-items at their local parent. Runs after all todos
+    /// are in, since a parent may arrive after its sub-items.
+    fn link_remote_parents(conn: &Connection, applied: &[&RemoteTodo]) -> Result<(), String> {
+        let todo_sync_map: HashMap<String, i64> = TodoRepository::get_all(conn)
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter_map(|t| t.sync_id.map(|s| (s, t.id)))
+            .collect();
+
+        for remote in applied {
+            let parent_id = remote
+                .parent_id
+                .as_ref()
+                .and_then(|sync_id| todo_sync_map.get(sync_id).copied());
+            conn.execute(
+                "UPDATE todos SET parent_id = ?1 WHERE sync_id = ?2",
+                rusqlite::params![parent_id, remote.id],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
     fn is_remote_newer(local_updated: &Option<String>, remote_updated: &str) -> bool {
         match local_updated {
             Some(local) => {
@@ -83,8 +116,17 @@ impl SyncService {
         remote: &RemoteCategory,
     ) -> Result<(), String> {
         conn.execute(
-            "UPDATE categories SET name = ?1, display_order = ?2, updated_at = ?3, sync_status = 'synced' WHERE id = ?4",
-            rusqlite::params![remote.name, remote.display_order, remote.updated_at, local.id],
+            "UPDATE categories SET name = ?1, display_order = ?2, reset_policy = ?3, reset_times = ?4,
+             reset_weekdays = ?5, updated_at = ?6, sync_status = 'synced' WHERE id = ?7",
+            rusqlite::params![
+                remote.name,
+                remote.display_order,
+                ResetPolicy::from_str(&remote.reset_policy).to_str(),
+                remote.reset_times,
+                remote.reset_weekdays,
+                remote.updated_at,
+                local.id
+            ],
         )
         .map_err(|e| e.to_string())?;
         Ok(())
@@ -95,16 +137,23 @@ impl SyncService {
         remote: &RemoteCategory,
     ) -> Result<(), String> {
         conn.execute(
-            "INSERT INTO categories (name, display_order, sync_id, created_at, updated_at, sync_status)
-             VALUES (?1, ?2, ?3, ?4, ?5, 'synced')
+            "INSERT INTO categories (name, display_order, reset_policy, reset_times, reset_weekdays,
+                sync_id, created_at, updated_at, sync_status)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 'synced')
              ON CONFLICT(sync_id) DO UPDATE SET
                 name = excluded.name,
                 display_order = excluded.display_order,
+                reset_policy = excluded.reset_policy,
+                reset_times = excluded.reset_times,
+                reset_weekdays = excluded.reset_weekdays,
                 updated_at = excluded.updated_at,
                 sync_status = 'synced'",
             rusqlite::params![
                 remote.name,
                 remote.display_order,
+                ResetPolicy::from_str(&remote.reset_policy).to_str(),
+                remote.reset_times,
+                remote.reset_weekdays,
                 remote.id,
                 remote.created_at,
                 remote.updated_at
@@ -123,7 +172,12 @@ impl SyncService {
         conn.execute(
             "UPDATE todos SET text = ?1, done = ?2, category_id = ?3, display_order = ?4, memo = ?5,
              repeat_type = ?6, repeat_detail = ?7, next_due_at = ?8, last_completed_at = ?9,
-             track_streak = ?10, reminder_at = ?11, linked_app = ?12, updated_at = ?13, sync_status = 'synced' WHERE id = ?14",
+             track_streak = ?10, reminder_at = ?11, linked_app = ?12, updated_at = ?13,
+             repeat_interval = ?14, repeat_start = ?15, repeat_until = ?16, repeat_count = ?17,
+             repeat_completed = ?18, due_at = ?19, due_times = ?20, streak_freezes_per_month = ?21,
+             streak_grace_per_week = ?22, goal_period = ?23, goal_target = ?24, quantity_target = ?25,
+             quantity_progress = ?26, auto_complete = ?27, repeat_ended = ?28, sync_status = 'synced'
+             WHERE id = ?29",
             rusqlite::params![
                 remote.text,
                 remote.done,
@@ -138,6 +192,21 @@ impl SyncService {
                 remote.reminder_at,
                 remote.linked_app,
                 remote.updated_at,
+                remote.repeat_interval.max(1),
+                remote.repeat_start,
+                remote.repeat_until,
+                remote.repeat_count,
+                remote.repeat_completed,
+                remote.due_at,
+                remote.due_times,
+                remote.streak_freezes_per_month,
+                remote.streak_grace_per_week,
+                GoalPeriod::from_str(&remote.goal_period).to_str(),
+                remote.goal_target,
+                remote.quantity_target,
+                remote.quantity_progress,
+                remote.auto_complete,
+                remote.repeat_ended,
                 local.id
             ],
         )
@@ -152,8 +221,12 @@ impl SyncService {
     ) -> Result<(), String> {
         conn.execute(
             "INSERT INTO todos (text, done, category_id, display_order, memo, repeat_type, repeat_detail,
-             next_due_at, last_completed_at, track_streak, reminder_at, linked_app, sync_id, created_at, updated_at, sync_status)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, 'synced')
+             next_due_at, last_completed_at, track_streak, reminder_at, linked_app, sync_id, created_at, updated_at,
+             repeat_interval, repeat_start, repeat_until, repeat_count, repeat_completed, due_at, due_times,
+             streak_freezes_per_month, streak_grace_per_week, goal_period, goal_target, quantity_target,
+             quantity_progress, auto_complete, repeat_ended, sync_status)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24,
+             ?25, ?26, ?27, ?28, ?29, ?30, 'synced')
              ON CONFLICT(sync_id) DO UPDATE SET
                 text = excluded.text,
                 done = excluded.done,
@@ -168,6 +241,21 @@ impl SyncService {
                 reminder_at = excluded.reminder_at,
                 linked_app = excluded.linked_app,
                 updated_at = excluded.updated_at,
+                repeat_interval = excluded.repeat_interval,
+                repeat_start = excluded.repeat_start,
+                repeat_until = excluded.repeat_until,
+                repeat_count = excluded.repeat_count,
+                repeat_completed = excluded.repeat_completed,
+                due_at = excluded.due_at,
+                due_times = excluded.due_times,
+                streak_freezes_per_month = excluded.streak_freezes_per_month,
+                streak_grace_per_week = excluded.streak_grace_per_week,
+                goal_period = excluded.goal_period,
+                goal_target = excluded.goal_target,
+                quantity_target = excluded.quantity_target,
+                quantity_progress = excluded.quantity_progress,
+                auto_complete = excluded.auto_complete,
+                repeat_ended = excluded.repeat_ended,
                 sync_status = 'synced'",
             rusqlite::params![
                 remote.text,
@@ -184,7 +272,22 @@ impl SyncService {
                 remote.linked_app,
                 remote.id,
                 remote.created_at,
-                remote.updated_at
+                remote.updated_at,
+                remote.repeat_interval.max(1),
+                remote.repeat_start,
+                remote.repeat_until,
+                remote.repeat_count,
+                remote.repeat_completed,
+                remote.due_at,
+                remote.due_times,
+                remote.streak_freezes_per_month,
+                remote.streak_grace_per_week,
+                GoalPeriod::from_str(&remote.goal_period).to_str(),
+                remote.goal_target,
+                remote.quantity_target,
+                remote.quantity_progress,
+                remote.auto_complete,
+                remote.repeat_ended
             ],
         )
         .map_err(|e| format!("Failed to insert todo: {}", e))?;
@@ -200,7 +303,9 @@ impl SyncService {
         for remote in remote_tags {
             let existing = TagRepository::get_by_sync_id(conn, &remote.id).map_err(|e| e.to_string())?;
             if let Some(local) = existing {
-                if Self::is_remote_newer(&local.updated_at, &remote.updated_at) {
+                if local.sync_status != SyncStatus::Deleted
+                    && Self::is_remote_newer(&local.updated_at, &remote.updated_at)
+                {
                     conn.execute(
                         "UPDATE tags SET name = ?1, updated_at = ?2, sync_status = 'synced' WHERE id = ?3",
                         rusqlite::params![remote.name, remote.updated_at, local.id],
@@ -228,6 +333,55 @@ impl SyncService {
         Ok(count)
     }
 
+    pub(super) fn apply_remote_templates(
+        conn: &Connection,
+        remote_templates: Vec<RemoteTemplate>,
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        let remote_ids: HashSet<String> = remote_templates.iter().map(|t| t.id.clone()).collect();
+        for remote in remote_templates {
+            let existing =
+                TemplateRepository::get_by_sync_id(conn, &remote.id).map_err(|e| e.to_string())?;
+            let template_id = if let Some(local) = existing {
+                if !Self::is_remote_newer(&local.updated_at, &remote.updated_at) {
+                    continue;
+                }
+                conn.execute(
+                    "UPDATE checklist_templates SET name = ?1, updated_at = ?2, sync_status = 'synced' WHERE id = ?3",
+                    rusqlite::params![remote.name, remote.updated_at, local.id],
+                )
+                .map_err(|e| e.to_string())?;
+                local.id
+            } else {
+                conn.execute(
+                    "INSERT INTO checklist_templates (name, sync_id, created_at, updated_at, sync_status) VALUES (?1, ?2, ?3, ?4, 'synced')",
+                    rusqlite::params![remote.name, remote.id, remote.created_at, remote.updated_at],
+                )
+                .map_err(|e| e.to_string())?;
+                conn.last_insert_rowid()
+            };
+            TemplateRepository::replace_items(conn, template_id, &remote.items)
+                .map_err(|e| e.to_string())?;
+            count += 1;
+        }
+
+        // A synced template that is gone remotely was deleted on another device
+        let local_templates = TemplateRepository::get_all(conn).map_err(|e| e.to_string())?;
+        for template in local_templates {
+            if template.sync_status != SyncStatus::Synced {
+                continue;
+            }
+            if let Some(sync_id) = &template.sync_id {
+                if !remote_ids.contains(sync_id) {
+                    TemplateRepository::delete(conn, template.id).map_err(|e| e.to_string())?;
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
     pub(super) fn apply_remote_todo_tags(
         conn: &Connection,
         remote_todo_tags: Vec<RemoteTodoTag>,
@@ -279,11 +433,136 @@ impl SyncService {
 
         for remote in remote_logs {
             if let Some(&local_id) = sync_id_to_local_id.get(&remote.todo_id) {
+                // Same format as local change times, so they compare as text
+                let updated_at = remote
+                    .updated_at
+                    .as_deref()
+                    .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                    .map(|dt| {
+                        dt.with_timezone(&Utc)
+                            .format("%Y-%m-%dT%H:%M:%SZ")
+                            .to_string()
+                    });
                 CompletionLogRepository::upsert(
                     conn,
                     local_id,
                     &remote.completed_on,
                     remote.completed_count,
+                    remote.completed_at.as_deref(),
+                    updated_at.as_deref(),
+                )
+                .map_err(|e| e.to_string())?;
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    pub(super) fn apply_remote_measurements(
+        conn: &Connection,
+        local_todos: &[TodoItem],
+        remote_measurements: Vec<RemoteMeasurement>,
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        let sync_id_to_local_id: HashMap<String, i64> = local_todos
+            .iter()
+            .filter_map(|t| t.sync_id.as_ref().map(|s| (s.clone(), t.id)))
+            .collect();
+
+        let mut remote_ids = HashSet::new();
+        for remote in remote_measurements {
+            if let Some(&local_id) = sync_id_to_local_id.get(&remote.todo_id) {
+                let inserted = MeasurementRepository::insert_synced(
+                    conn,
+                    local_id,
+                    &remote.recorded_on,
+                    &remote.recorded_at,
+                    remote.value,
+                    remote.unit.as_deref(),
+                    &remote.id,
+                )
+                .map_err(|e| e.to_string())?;
+                if inserted {
+                    count += 1;
+                }
+            }
+            remote_ids.insert(remote.id);
+        }
+
+        // A synced value that is gone remotely was removed on another device
+        let local_measurements = MeasurementRepository::get_all(conn).map_err(|e| e.to_string())?;
+        for measurement in local_measurements {
+            if measurement.sync_status != SyncStatus::Synced {
+                continue;
+            }
+            if let Some(sync_id) = &measurement.sync_id {
+                if !remote_ids.contains(sync_id) {
+                    MeasurementRepository::delete(conn, measurement.id)
+                        .map_err(|e| e.to_string())?;
+                }
+            }
+        }
+
+        Ok(count)
+    }
+
+    pub(super) fn apply_remote_skip_logs(
+        conn: &Connection,
+        local_todos: &[TodoItem],
+        remote_logs: Vec<RemoteSkipLog>,
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        let sync_id_to_local_id: HashMap<String, i64> = local_todos
+            .iter()
+            .filter_map(|t| t.sync_id.as_ref().map(|s| (s.clone(), t.id)))
+            .collect();
+
+        let mut remote_keys = HashSet::new();
+        for remote in remote_logs {
+            if let Some(&local_id) = sync_id_to_local_id.get(&remote.todo_id) {
+                SkipLogRepository::add_synced(conn, local_id, &remote.skipped_on)
+                    .map_err(|e| e.to_string())?;
+                remote_keys.insert((local_id, remote.skipped_on));
+                count += 1;
+            }
+        }
+
+        // A synced skip that is gone remotely was removed on another device
+        let local_logs = SkipLogRepository::get_all(conn).map_err(|e| e.to_string())?;
+        for log in local_logs {
+            if log.sync_status == SyncStatus::Synced
+                && !remote_keys.contains(&(log.item_id, log.skipped_on.clone()))
+            {
+                SkipLogRepository::purge(conn, log.item_id, &log.skipped_on)
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        Ok(count)
+    }
+
+    pub(super) fn apply_remote_streak_freezes(
+        conn: &Connection,
+        local_todos: &[TodoItem],
+        remote_freezes: Vec<RemoteStreakFreeze>,
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        let sync_id_to_local_id: HashMap<String, i64> = local_todos
+            .iter()
+            .filter_map(|t| t.sync_id.as_ref().map(|s| (s.clone(), t.id)))
+            .collect();
+
+        for remote in remote_freezes {
+            if let Some(&local_id) = sync_id_to_local_id.get(&remote.todo_id) {
+                StreakFreezeRepository::add_synced(
+                    conn,
+                    local_id,
+                    &remote.frozen_on,
+                    FreezeKind::from_str(&remote.kind),
                 )
                 .map_err(|e| e.to_string())?;
                 count += 1;
@@ -292,4 +571,133 @@ impl SyncService {
 
         Ok(count)
     }
+
+    pub(super) fn apply_remote_schedule_versions(
+        conn: &Connection,
+        local_todos: &[TodoItem],
+        remote_versions: Vec<RemoteScheduleVersion>,
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        let sync_id_to_local_id: HashMap<String, i64> = local_todos
+            .iter()
+            .filter_map(|t| t.sync_id.as_ref().map(|s| (s.clone(), t.id)))
+            .collect();
+
+        let mut remote_keys = HashSet::new();
+        for remote in remote_versions {
+            if let Some(&local_id) = sync_id_to_local_id.get(&remote.todo_id) {
+                remote_keys.insert((local_id, remote.effective_from.clone()));
+                let version = ScheduleVersion {
+                    item_id: local_id,
+                    effective_from: remote.effective_from,
+                    repeat_type: RepeatType::from_str(&remote.repeat_type),
+                    repeat_detail: remote.repeat_detail,
+                    repeat_interval: remote.repeat_interval,
+                    repeat_start: remote.repeat_start,
+                    repeat_until: remote.repeat_until,
+                    repeat_count: remote.repeat_count,
+                };
+                ScheduleVersionRepository::upsert_synced(conn, &version)
+                    .map_err(|e| e.to_string())?;
+                count += 1;
+            }
+        }
+
+        // A synced version that is gone remotely was removed on another device
+        let local_versions =
+            ScheduleVersionRepository::get_synced(conn).map_err(|e| e.to_string())?;
+        for version in local_versions {
+            if !remote_keys.contains(&(version.item_id, version.effective_from.clone())) {
+                ScheduleVersionRepository::delete(conn, version.item_id, &version.effective_from)
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Newest value wins; a local change not pushed yet is kept
+    pub(super) fn apply_remote_settings(
+        conn: &Connection,
+        remote_settings: Vec<RemoteSetting>,
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        for remote in remote_settings {
+            if !SYNCED_SETTINGS.contains(&remote.key.as_str()) {
+                continue;
+            }
+            if remote.key == TimeZoneService::SETTING_KEY
+                && !TimeZoneService::is_valid(&remote.value)
+            {
+                log::warn!("Ignoring unknown synced time zone: {}", remote.value);
+                continue;
+            }
+
+            let local =
+                SettingsRepository::get_with_sync(conn, &remote.key).map_err(|e| e.to_string())?;
+            let apply = match local {
+                Some((_, _, SyncStatus::Pending)) => false,
+                Some((value, updated_at, _)) => {
+                    value != remote.value && Self::is_remote_newer(&updated_at, &remote.updated_at)
+                }
+                None => true,
+            };
+            if apply {
+                SettingsRepository::set_synced(
+                    conn,
+                    &remote.key,
+                    &remote.value,
+                    &remote.updated_at,
+                )
+                .map_err(|e| e.to_string())?;
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remote_template(id: &str, name: &str) -> RemoteTemplate {
+        RemoteTemplate {
+            id: id.to_string(),
+            user_id: "user".to_string(),
+            name: name.to_string(),
+            items: Vec::new(),
+            created_at: "2026-03-01T08:00:00Z".to_string(),
+            updated_at: "2026-03-01T08:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn templates_deleted_remotely_are_deleted_locally() {
+        let conn = crate::repository::open_in_memory();
+        SyncService::apply_remote_templates(
+            &conn,
+            vec![remote_template("a", "Gym"), remote_template("b", "Trip")],
+        )
+        .unwrap();
+        let unpushed = TemplateRepository::create(&conn, "Groceries", &[]).unwrap();
+
+        SyncService::apply_remote_templates(&conn, vec![remote_template("b", "Trip")]).unwrap();
+
+        let names: Vec<String> = TemplateRepository::get_all(&conn)
+            .unwrap()
+            .into_iter()
+            .map(|template| template.name)
+            .collect();
+        assert_eq!(names, vec!["Groceries".to_string(), "Trip".to_string()]);
+        assert!(TemplateRepository::get_by_sync_id(&conn, "a")
+            .unwrap()
+            .is_none());
+        assert!(TemplateRepository::get_by_id(&conn, unpushed.id)
+            .unwrap()
+            .is_some());
+    }
 }
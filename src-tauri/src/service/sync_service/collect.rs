@@ -12,6 +12,9 @@ impl SyncService {
                 sync_id: c.sync_id,
                 name: c.name,
                 display_order: c.display_order,
+                reset_policy: c.reset_policy,
+                reset_times: c.reset_times,
+                reset_weekdays: c.reset_weekdays,
                 created_at: c.created_at,
                 updated_at: c.updated_at,
                 sync_status: c.sync_status,
@@ -50,6 +53,23 @@ impl SyncService {
                 memo: t.memo,
                 repeat_type: t.repeat_type.to_str().to_string(),
                 repeat_detail: t.repeat_detail,
+                repeat_interval: t.repeat_interval,
+                repeat_start: t.repeat_start,
+                repeat_until: t.repeat_until,
+                repeat_count: t.repeat_count,
+                repeat_completed: t.repeat_completed,
+                repeat_ended: t.repeat_ended,
+                due_at: t.due_at,
+                due_times: t.due_times,
+                streak_freezes_per_month: t.streak_freezes_per_month,
+                streak_grace_per_week: t.streak_grace_per_week,
+                goal_period: t.goal_period.to_str().to_string(),
+                goal_target: t.goal_target,
+                quantity_target: t.quantity_target,
+                quantity_progress: t.quantity_progress,
+                parent_id: t.parent_id,
+                parent_sync_id: None,
+                auto_complete: t.auto_complete,
                 next_due_at: t.next_due_at,
                 last_completed_at: t.last_completed_at,
                 track_streak: t.track_streak,
@@ -80,6 +100,24 @@ impl SyncService {
             .collect())
     }
 
+    pub(super) fn collect_pending_templates(
+        conn: &Connection,
+    ) -> Result<Vec<PendingTemplateSync>, String> {
+        let templates = TemplateRepository::get_pending_sync(conn).map_err(|e| e.to_string())?;
+        Ok(templates
+            .into_iter()
+            .map(|t| PendingTemplateSync {
+                id: t.id,
+                sync_id: t.sync_id,
+                name: t.name,
+                items: t.items,
+                created_at: t.created_at,
+                updated_at: t.updated_at,
+                sync_status: t.sync_status,
+            })
+            .collect())
+    }
+
     pub(super) fn collect_pending_todo_tags(
         conn: &Connection,
         todo_id_to_sync_id: &HashMap<i64, String>,
@@ -114,11 +152,11 @@ impl SyncService {
         conn: &Connection,
         todo_id_to_sync_id: &HashMap<i64, String>,
     ) -> Result<Vec<LocalCompletionLogSync>, String> {
-        let logs = CompletionLogRepository::get_all(conn).map_err(|e| e.to_string())?;
+        let logs = CompletionLogRepository::get_pending(conn).map_err(|e| e.to_string())?;
 
         Ok(logs
             .into_iter()
-            .filter_map(|log| {
+            .filter_map(|(log, updated_at)| {
                 todo_id_to_sync_id
                     .get(&log.item_id)
                     .map(|sync_id| LocalCompletionLogSync {
@@ -126,8 +164,120 @@ impl SyncService {
                         todo_sync_id: sync_id.clone(),
                         completed_on: log.completed_on,
                         completed_count: log.completed_count,
+                        completed_at: log.completed_at,
+                        updated_at,
+                    })
+            })
+            .collect())
+    }
+
+    /// Values not pushed yet, new ones with a fresh sync id
+    pub(super) fn collect_measurements(
+        conn: &Connection,
+        todo_id_to_sync_id: &HashMap<i64, String>,
+    ) -> Result<Vec<LocalMeasurementSync>, String> {
+        let measurements = MeasurementRepository::get_all(conn).map_err(|e| e.to_string())?;
+
+        Ok(measurements
+            .into_iter()
+            .filter(|measurement| measurement.sync_status != SyncStatus::Synced)
+            .filter_map(|measurement| {
+                todo_id_to_sync_id
+                    .get(&measurement.item_id)
+                    .map(|todo_sync_id| LocalMeasurementSync {
+                        id: measurement.id,
+                        sync_id: measurement
+                            .sync_id
+                            .unwrap_or_else(|| Uuid::new_v4().to_string()),
+                        todo_sync_id: todo_sync_id.clone(),
+                        recorded_on: measurement.recorded_on,
+                        recorded_at: measurement.recorded_at,
+                        value: measurement.value,
+                        unit: measurement.unit,
+                        sync_status: measurement.sync_status,
                     })
             })
             .collect())
     }
+
+    pub(super) fn collect_skip_logs(
+        conn: &Connection,
+        todo_id_to_sync_id: &HashMap<i64, String>,
+    ) -> Result<Vec<LocalSkipLogSync>, String> {
+        let logs = SkipLogRepository::get_all(conn).map_err(|e| e.to_string())?;
+
+        Ok(logs
+            .into_iter()
+            .filter(|log| log.sync_status != SyncStatus::Synced)
+            .filter_map(|log| {
+                todo_id_to_sync_id
+                    .get(&log.item_id)
+                    .map(|sync_id| LocalSkipLogSync {
+                        item_id: log.item_id,
+                        todo_sync_id: sync_id.clone(),
+                        skipped_on: log.skipped_on,
+                        sync_status: log.sync_status,
+                    })
+            })
+            .collect())
+    }
+
+    pub(super) fn collect_streak_freezes(
+        conn: &Connection,
+        todo_id_to_sync_id: &HashMap<i64, String>,
+    ) -> Result<Vec<LocalStreakFreezeSync>, String> {
+        let freezes = StreakFreezeRepository::get_pending(conn).map_err(|e| e.to_string())?;
+
+        Ok(freezes
+            .into_iter()
+            .filter_map(|freeze| {
+                todo_id_to_sync_id
+                    .get(&freeze.item_id)
+                    .map(|sync_id| LocalStreakFreezeSync {
+                        item_id: freeze.item_id,
+                        todo_sync_id: sync_id.clone(),
+                        frozen_on: freeze.frozen_on,
+                        kind: freeze.kind,
+                    })
+            })
+            .collect())
+    }
+
+    pub(super) fn collect_schedule_versions(
+        conn: &Connection,
+        todo_id_to_sync_id: &HashMap<i64, String>,
+    ) -> Result<Vec<LocalScheduleVersionSync>, String> {
+        let versions = ScheduleVersionRepository::get_pending(conn).map_err(|e| e.to_string())?;
+
+        Ok(versions
+            .into_iter()
+            .filter_map(|(version, sync_status)| {
+                todo_id_to_sync_id
+                    .get(&version.item_id)
+                    .map(|sync_id| LocalScheduleVersionSync {
+                        todo_sync_id: sync_id.clone(),
+                        version,
+                        sync_status,
+                    })
+            })
+            .collect())
+    }
+
+    pub(super) fn collect_pending_settings(
+        conn: &Connection,
+    ) -> Result<Vec<PendingSettingSync>, String> {
+        let mut pending = Vec::new();
+        for key in SYNCED_SETTINGS {
+            if let Some((value, Some(updated_at), SyncStatus::Pending)) =
+                SettingsRepository::get_with_sync(conn, key).map_err(|e| e.to_string())?
+            {
+                pending.push(PendingSettingSync {
+                    key: key.to_string(),
+                    value,
+                    updated_at,
+                });
+            }
+        }
+        Ok(pending)
+    }
 }
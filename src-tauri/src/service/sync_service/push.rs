@@ -22,6 +22,9 @@ impl SyncService {
                         user_id: user_id.to_string(),
                         name: cat.name.clone(),
                         display_order: cat.display_order as i32,
+                        reset_policy: cat.reset_policy.to_str().to_string(),
+                        reset_times: cat.reset_times.clone(),
+                        reset_weekdays: cat.reset_weekdays.clone(),
                         created_at: cat
                             .created_at
                             .clone()
@@ -74,6 +77,22 @@ impl SyncService {
                         memo: todo.memo.clone(),
                         repeat_type: todo.repeat_type.clone(),
                         repeat_detail: todo.repeat_detail.clone(),
+                        repeat_interval: todo.repeat_interval,
+                        repeat_start: todo.repeat_start.clone(),
+                        repeat_until: todo.repeat_until.clone(),
+                        repeat_count: todo.repeat_count,
+                        repeat_completed: todo.repeat_completed,
+                        repeat_ended: todo.repeat_ended,
+                        due_at: todo.due_at.clone(),
+                        due_times: todo.due_times.clone(),
+                        streak_freezes_per_month: todo.streak_freezes_per_month,
+                        streak_grace_per_week: todo.streak_grace_per_week,
+                        goal_period: todo.goal_period.clone(),
+                        goal_target: todo.goal_target,
+                        quantity_target: todo.quantity_target,
+                        quantity_progress: todo.quantity_progress,
+                        parent_id: todo.parent_sync_id.clone(),
+                        auto_complete: todo.auto_complete,
                         next_due_at: todo.next_due_at.clone(),
                         last_completed_at: todo.last_completed_at.clone(),
                         track_streak: todo.track_streak,
@@ -150,6 +169,51 @@ impl SyncService {
         Ok(results)
     }
 
+    pub(super) async fn push_templates_async(
+        client: &SupabaseClient,
+        access_token: &str,
+        user_id: &str,
+        templates: &[PendingTemplateSync],
+    ) -> Result<Vec<(i64, String)>, String> {
+        let mut results = Vec::new();
+
+        for template in templates {
+            match template.sync_status {
+                SyncStatus::Pending => {
+                    let sync_id = template
+                        .sync_id
+                        .clone()
+                        .unwrap_or_else(|| Uuid::new_v4().to_string());
+                    let remote = RemoteTemplate {
+                        id: sync_id.clone(),
+                        user_id: user_id.to_string(),
+                        name: template.name.clone(),
+                        items: template.items.clone(),
+                        created_at: template
+                            .created_at
+                            .clone()
+                            .unwrap_or_else(|| Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+                        updated_at: template
+                            .updated_at
+                            .clone()
+                            .unwrap_or_else(|| Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+                    };
+                    client.upsert_template(access_token, &remote).await?;
+                    results.push((template.id, sync_id));
+                }
+                SyncStatus::Deleted => {
+                    if let Some(sync_id) = &template.sync_id {
+                        client.delete_template(access_token, sync_id).await?;
+                        results.push((template.id, sync_id.clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(results)
+    }
+
     pub(super) async fn push_todo_tags_async(
         client: &SupabaseClient,
         access_token: &str,
@@ -212,6 +276,8 @@ impl SyncService {
                 todo_id: log.todo_sync_id.clone(),
                 completed_on: log.completed_on.clone(),
                 completed_count: log.completed_count as i32,
+                completed_at: log.completed_at.clone(),
+                updated_at: Some(log.updated_at.clone()),
             };
 
             client.upsert_completion_log(access_token, &remote).await?;
@@ -220,4 +286,148 @@ impl SyncService {
 
         Ok(count)
     }
+
+    pub(super) async fn push_measurements_async(
+        client: &SupabaseClient,
+        access_token: &str,
+        user_id: &str,
+        measurements: &[LocalMeasurementSync],
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        for measurement in measurements {
+            if measurement.sync_status == SyncStatus::Deleted {
+                client
+                    .delete_measurement(access_token, &measurement.sync_id)
+                    .await?;
+                count += 1;
+                continue;
+            }
+            let remote = RemoteMeasurement {
+                id: measurement.sync_id.clone(),
+                user_id: user_id.to_string(),
+                todo_id: measurement.todo_sync_id.clone(),
+                recorded_on: measurement.recorded_on.clone(),
+                recorded_at: measurement.recorded_at.clone(),
+                value: measurement.value,
+                unit: measurement.unit.clone(),
+            };
+
+            client.upsert_measurement(access_token, &remote).await?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    pub(super) async fn push_skip_logs_async(
+        client: &SupabaseClient,
+        access_token: &str,
+        user_id: &str,
+        logs: &[LocalSkipLogSync],
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        for log in logs {
+            let id = format!("{}_{}", log.todo_sync_id, log.skipped_on);
+            if log.sync_status == SyncStatus::Deleted {
+                client.delete_skip_log(access_token, &id).await?;
+                count += 1;
+                continue;
+            }
+            let remote = RemoteSkipLog {
+                id,
+                user_id: user_id.to_string(),
+                todo_id: log.todo_sync_id.clone(),
+                skipped_on: log.skipped_on.clone(),
+            };
+
+            client.upsert_skip_log(access_token, &remote).await?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    pub(super) async fn push_streak_freezes_async(
+        client: &SupabaseClient,
+        access_token: &str,
+        user_id: &str,
+        freezes: &[LocalStreakFreezeSync],
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        for freeze in freezes {
+            let remote = RemoteStreakFreeze {
+                id: format!("{}_{}", freeze.todo_sync_id, freeze.frozen_on),
+                user_id: user_id.to_string(),
+                todo_id: freeze.todo_sync_id.clone(),
+                frozen_on: freeze.frozen_on.clone(),
+                kind: freeze.kind.to_str().to_string(),
+            };
+
+            client.upsert_streak_freeze(access_token, &remote).await?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    pub(super) async fn push_schedule_versions_async(
+        client: &SupabaseClient,
+        access_token: &str,
+        user_id: &str,
+        versions: &[LocalScheduleVersionSync],
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        for local in versions {
+            let version = &local.version;
+            let id = format!("{}_{}", local.todo_sync_id, version.effective_from);
+            if local.sync_status == SyncStatus::Deleted {
+                client.delete_schedule_version(access_token, &id).await?;
+                count += 1;
+                continue;
+            }
+            let remote = RemoteScheduleVersion {
+                id,
+                user_id: user_id.to_string(),
+                todo_id: local.todo_sync_id.clone(),
+                effective_from: version.effective_from.clone(),
+                repeat_type: version.repeat_type.to_str().to_string(),
+                repeat_detail: version.repeat_detail.clone(),
+                repeat_interval: version.repeat_interval,
+                repeat_start: version.repeat_start.clone(),
+                repeat_until: version.repeat_until.clone(),
+                repeat_count: version.repeat_count,
+            };
+
+            client
+                .upsert_schedule_version(access_token, &remote)
+                .await?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    pub(super) async fn push_settings_async(
+        client: &SupabaseClient,
+        access_token: &str,
+        user_id: &str,
+        settings: &[PendingSettingSync],
+    ) -> Result<usize, String> {
+        for setting in settings {
+            let remote = RemoteSetting {
+                id: format!("{}_{}", user_id, setting.key),
+                user_id: user_id.to_string(),
+                key: setting.key.clone(),
+                value: setting.value.clone(),
+                updated_at: setting.updated_at.clone(),
+            };
+            client.upsert_setting(access_token, &remote).await?;
+        }
+
+        Ok(settings.len())
+    }
 }
@@ -1,7 +1,8 @@
 use rusqlite::Connection;
 
-use crate::models::{Tag, TodoItem};
-use crate::repository::{TagRepository, TodoRepository, TodoTagRepository};
+use crate::models::{Tag, TodoItem, TrashKind};
+use crate::repository::{TagRepository, TodoRepository, TodoTagRepository, TrashRepository};
+use crate::service::TrashService;
 
 pub struct TagService;
 
@@ -10,22 +11,29 @@ impl TagService {
         TagRepository::get_all(conn)
     }
 
-    /// Create a tag. If a tag with the same name exists, return the existing one.
+    /// Create a tag. If a tag with the same name exists, return the existing one;
+    /// one in the trash is restored, since tag names are unique.
     pub fn create_tag(conn: &Connection, name: &str) -> Result<Tag, rusqlite::Error> {
         let trimmed = name.trim();
         if let Some(existing) = TagRepository::get_by_name(conn, trimmed)? {
             return Ok(existing);
         }
+        // The name still belongs to a trashed tag. It comes back as a new tag
+        // without its old item links; only restore_tag brings those back.
+        if let Some(trashed) = TagRepository::get_trashed_by_name(conn, trimmed)? {
+            TodoTagRepository::remove_all_for_tag(conn, trashed.id)?;
+            TrashRepository::restore(conn, TrashKind::Tag, trashed.id)?;
+            if let Some(restored) = TagRepository::get_by_id(conn, trashed.id)? {
+                return Ok(restored);
+            }
+        }
         TagRepository::create(conn, trimmed)
     }
 
+    /// Move a tag to the trash
     pub fn delete_tag(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
-        if let Some(tag) = TagRepository::get_by_id(conn, id)? {
-            if tag.sync_id.is_some() {
-                TagRepository::mark_deleted(conn, id)
-            } else {
-                TagRepository::delete(conn, id)
-            }
+        if TagRepository::get_by_id(conn, id)?.is_some() {
+            TrashService::trash_tag(conn, id)
         } else {
             Ok(())
         }
@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+
+use rusqlite::Connection;
+
+use crate::models::{ChecklistTemplate, RepeatSchedule, TemplateItem, TodoItem};
+use crate::repository::{
+    CategoryRepository, TemplateRepository, TodoRepository, TodoTagRepository,
+};
+use crate::service::{CategoryService, TagService, TodoService};
+
+pub struct TemplateService;
+
+impl TemplateService {
+    pub fn get_templates(conn: &Connection) -> Result<Vec<ChecklistTemplate>, rusqlite::Error> {
+        TemplateRepository::get_all(conn)
+    }
+
+    /// Save the items of a category, with their tags, memo, schedule, due
+    /// times and reminder, as a template
+    pub fn create_from_category(
+        conn: &Connection,
+        category_id: i64,
+        name: &str,
+    ) -> Result<ChecklistTemplate, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Template name can't be empty".to_string());
+        }
+        if CategoryRepository::get_by_id(conn, category_id)
+            .map_err(|e| e.to_string())?
+            .is_none()
+        {
+            return Err("Category not found".to_string());
+        }
+
+        let todos =
+            TodoRepository::get_by_category(conn, Some(category_id)).map_err(|e| e.to_string())?;
+        let items =
+            Self::snapshot(conn, &Self::template_order(todos)).map_err(|e| e.to_string())?;
+
+        TemplateRepository::create(conn, name, &items).map_err(|e| e.to_string())
+    }
+
+    /// Items in display order, each parent followed by its sub-items
+    fn template_order(mut todos: Vec<TodoItem>) -> Vec<TodoItem> {
+        todos.sort_by_key(|todo| todo.display_order);
+        let ids: Vec<i64> = todos.iter().map(|todo| todo.id).collect();
+        let (children, top): (Vec<TodoItem>, Vec<TodoItem>) = todos
+            .into_iter()
+            .partition(|todo| todo.parent_id.is_some_and(|id| ids.contains(&id)));
+
+        let mut ordered = Vec::new();
+        for parent in top {
+            let parent_id = parent.id;
+            ordered.push(parent);
+            ordered.extend(
+                children
+                    .iter()
+                    .filter(|child| child.parent_id == Some(parent_id))
+                    .cloned(),
+            );
+        }
+        ordered
+    }
+
+    /// Template items for `todos`, in the same order
+    fn snapshot(
+        conn: &Connection,
+        todos: &[TodoItem],
+    ) -> Result<Vec<TemplateItem>, rusqlite::Error> {
+        let positions: HashMap<i64, usize> = todos
+            .iter()
+            .enumerate()
+            .map(|(position, todo)| (todo.id, position))
+            .collect();
+
+        todos
+            .iter()
+            .map(|todo| {
+                let tags = TodoTagRepository::get_tags_for_item(conn, todo.id)?
+                    .into_iter()
+                    .map(|tag| tag.name)
+                    .collect();
+                Ok(TemplateItem {
+                    text: todo.text.clone(),
+                    memo: todo.memo.clone(),
+                    repeat_type: todo.repeat_type.clone(),
+                    repeat_detail: todo.repeat_detail.clone(),
+                    repeat_interval: todo.repeat_interval,
+                    repeat_start: todo.repeat_start.clone(),
+                    repeat_until: todo.repeat_until.clone(),
+                    repeat_count: todo.repeat_count,
+                    due_times: todo.due_times.clone(),
+                    reminder_at: todo.reminder_at.clone(),
+                    tags,
+                    parent_position: todo
+                        .parent_id
+                        .and_then(|parent_id| positions.get(&parent_id).copied()),
+                })
+            })
+            .collect()
+    }
+
+    /// Create the items of a template in an existing category, or in a new
+    /// category when `new_category_name` is given. Returns the category id.
+    /// Nothing is created if any item fails.
+    pub fn instantiate(
+        conn: &Connection,
+        template_id: i64,
+        category_id: Option<i64>,
+        new_category_name: Option<&str>,
+    ) -> Result<i64, String> {
+        let template = TemplateRepository::get_by_id(conn, template_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Template not found".to_string())?;
+
+        conn.execute("BEGIN TRANSACTION", [])
+            .map_err(|e| e.to_string())?;
+        match Self::instantiate_items(conn, &template, category_id, new_category_name) {
+            Ok(category_id) => {
+                conn.execute("COMMIT", []).map_err(|e| e.to_string())?;
+                Ok(category_id)
+            }
+            Err(e) => {
+                let _ = conn.execute("ROLLBACK", []);
+                Err(e)
+            }
+        }
+    }
+
+    fn instantiate_items(
+        conn: &Connection,
+        template: &ChecklistTemplate,
+        category_id: Option<i64>,
+        new_category_name: Option<&str>,
+    ) -> Result<i64, String> {
+        let category_id = match new_category_name.map(str::trim) {
+            Some(name) if !name.is_empty() => {
+                CategoryService::create(conn, name)
+                    .map_err(|e| e.to_string())?
+                    .id
+            }
+            _ => {
+                let category_id =
+                    category_id.ok_or_else(|| "Choose a category for the template".to_string())?;
+                CategoryRepository::get_by_id(conn, category_id)
+                    .map_err(|e| e.to_string())?
+                    .ok_or_else(|| "Category not found".to_string())?
+                    .id
+            }
+        };
+
+        let mut created: Vec<i64> = Vec::new();
+        for item in &template.items {
+            let mut repeat =
+                RepeatSchedule::new(item.repeat_type.clone(), item.repeat_detail.as_deref())
+                    .with_interval(item.repeat_interval)
+                    .with_end(item.repeat_until.as_deref(), item.repeat_count);
+            repeat.repeat_start = item.repeat_start.clone();
+            let todo = TodoService::create_item(
+                conn,
+                &item.text,
+                Some(category_id),
+                repeat,
+                false,
+                item.reminder_at.as_deref(),
+            )?;
+
+            if let Some(memo) = item.memo.as_deref() {
+                TodoRepository::update_memo(conn, todo.id, Some(memo))
+                    .map_err(|e| e.to_string())?;
+            }
+            let due_times: Vec<String> = item
+                .due_times
+                .as_deref()
+                .and_then(|json| serde_json::from_str(json).ok())
+                .unwrap_or_default();
+            if !due_times.is_empty() {
+                TodoService::update_due(conn, todo.id, None, &due_times)?;
+            }
+            for tag in &item.tags {
+                TagService::add_tag_to_item(conn, todo.id, tag).map_err(|e| e.to_string())?;
+            }
+            // Parents come before their sub-items in template order
+            if let Some(parent_id) = item.parent_position.and_then(|p| created.get(p).copied()) {
+                TodoRepository::set_parent(conn, todo.id, Some(parent_id), Some(category_id))
+                    .map_err(|e| e.to_string())?;
+            }
+            created.push(todo.id);
+        }
+
+        Ok(category_id)
+    }
+
+    pub fn delete_template(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        if let Some(template) = TemplateRepository::get_by_id(conn, id)? {
+            if template.sync_id.is_some() {
+                TemplateRepository::mark_deleted(conn, id)
+            } else {
+                TemplateRepository::delete(conn, id)
+            }
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RepeatType;
+
+    /// A category with a weekly item that has tags, a memo, an end, due
+    /// times and a sub-item
+    fn gym_category(conn: &Connection) -> (i64, TodoItem) {
+        let category = CategoryService::create(conn, "Gym").unwrap();
+        let mut repeat = RepeatSchedule::new(RepeatType::Weekly, Some("[1,4]"))
+            .with_interval(2)
+            .with_end(Some("2026-12-31"), Some(10));
+        repeat.repeat_start = Some("2026-03-02".to_string());
+        let bag =
+            TodoService::create_item(conn, "Pack the bag", Some(category.id), repeat, false, None)
+                .unwrap();
+        TodoRepository::update_memo(conn, bag.id, Some("Check the lock")).unwrap();
+        TodoService::update_due(conn, bag.id, None, &["07:30".to_string()]).unwrap();
+        TagService::add_tag_to_item(conn, bag.id, "sport").unwrap();
+        TodoService::create_subtask(conn, bag.id, "Towel").unwrap();
+        let bag = TodoRepository::get_by_id(conn, bag.id).unwrap().unwrap();
+        (category.id, bag)
+    }
+
+    #[test]
+    fn snapshot_keeps_the_whole_schedule() {
+        let conn = crate::repository::open_in_memory();
+        let (category_id, bag) = gym_category(&conn);
+
+        let template = TemplateService::create_from_category(&conn, category_id, "Gym").unwrap();
+        let stored = TemplateRepository::get_by_id(&conn, template.id)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(stored.items, template.items);
+        let [parent, child] = &stored.items[..] else {
+            panic!("expected two items, got {:?}", stored.items);
+        };
+        assert_eq!(parent.text, "Pack the bag");
+        assert_eq!(parent.memo.as_deref(), Some("Check the lock"));
+        assert_eq!(parent.repeat_type, RepeatType::Weekly);
+        assert_eq!(parent.repeat_interval, 2);
+        assert_eq!(parent.repeat_start, bag.repeat_start);
+        assert_eq!(parent.repeat_until.as_deref(), Some("2026-12-31"));
+        assert_eq!(parent.repeat_count, Some(10));
+        assert_eq!(parent.due_times.as_deref(), Some(r#"["07:30"]"#));
+        assert_eq!(parent.tags, vec!["sport".to_string()]);
+        assert_eq!(parent.parent_position, None);
+        assert_eq!(child.text, "Towel");
+        assert_eq!(child.parent_position, Some(0));
+    }
+
+    #[test]
+    fn instantiate_recreates_items_in_a_new_category() {
+        let conn = crate::repository::open_in_memory();
+        let (category_id, bag) = gym_category(&conn);
+        let template = TemplateService::create_from_category(&conn, category_id, "Gym").unwrap();
+
+        let new_category_id =
+            TemplateService::instantiate(&conn, template.id, None, Some("Gym again")).unwrap();
+
+        assert_ne!(new_category_id, category_id);
+        let items = TodoRepository::get_by_category(&conn, Some(new_category_id)).unwrap();
+        let [parent, child] = &items[..] else {
+            panic!("expected two items, got {:?}", items);
+        };
+        assert_eq!(parent.text, "Pack the bag");
+        assert_eq!(parent.memo.as_deref(), Some("Check the lock"));
+        assert_eq!(parent.repeat_detail, bag.repeat_detail);
+        assert_eq!(parent.repeat_interval, 2);
+        assert_eq!(parent.repeat_start, bag.repeat_start);
+        assert_eq!(parent.repeat_until, bag.repeat_until);
+        assert_eq!(parent.repeat_count, Some(10));
+        assert_eq!(parent.due_times, bag.due_times);
+        let tags: Vec<String> = TodoTagRepository::get_tags_for_item(&conn, parent.id)
+            .unwrap()
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect();
+        assert_eq!(tags, vec!["sport".to_string()]);
+        assert_eq!(child.text, "Towel");
+        assert_eq!(child.parent_id, Some(parent.id));
+    }
+
+    #[test]
+    fn failed_instantiation_creates_nothing() {
+        let conn = crate::repository::open_in_memory();
+        let (category_id, _) = gym_category(&conn);
+        let mut items = TemplateService::create_from_category(&conn, category_id, "Gym")
+            .unwrap()
+            .items;
+        // A later item with a weekday that doesn't exist
+        items.push(TemplateItem {
+            repeat_detail: Some("[9]".to_string()),
+            parent_position: None,
+            ..items[0].clone()
+        });
+        let template = TemplateRepository::create(&conn, "Broken", &items).unwrap();
+        let todos = TodoRepository::get_all(&conn).unwrap().len();
+        let categories = CategoryRepository::get_all(&conn).unwrap().len();
+
+        let result = TemplateService::instantiate(&conn, template.id, None, Some("Gym again"));
+
+        assert!(result.is_err());
+        assert_eq!(TodoRepository::get_all(&conn).unwrap().len(), todos);
+        assert_eq!(
+            CategoryRepository::get_all(&conn).unwrap().len(),
+            categories
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use rusqlite::Connection;
+
+use crate::models::TimeZoneStatus;
+use crate::repository::SettingsRepository;
+use crate::service::repeat_service::get_logical_date;
+
+const TIME_ZONE_KEY: &str = "time_zone";
+const DEVICE_TIME_ZONE_KEY: &str = "device_time_zone";
+
+pub struct TimeZoneService;
+
+impl TimeZoneService {
+    /// Settings key of the user zone, which is synced between devices
+    pub const SETTING_KEY: &'static str = TIME_ZONE_KEY;
+
+    /// The zone all dates are computed in. On first use it is pinned to the
+    /// device zone, so travelling never moves logical dates on its own.
+    pub fn user_time_zone(conn: &Connection) -> Result<Tz, rusqlite::Error> {
+        if let Some(tz) =
+            SettingsRepository::get(conn, TIME_ZONE_KEY)?.and_then(|name| name.parse::<Tz>().ok())
+        {
+            return Ok(tz);
+        }
+
+        let device = Self::device_time_zone();
+        SettingsRepository::set(conn, TIME_ZONE_KEY, device.name())?;
+        Ok(device)
+    }
+
+    pub fn device_time_zone() -> Tz {
+        iana_time_zone::get_timezone()
+            .ok()
+            .and_then(|name| name.parse::<Tz>().ok())
+            .unwrap_or(Tz::UTC)
+    }
+
+    pub fn now(conn: &Connection) -> Result<DateTime<Tz>, rusqlite::Error> {
+        Ok(Utc::now().with_timezone(&Self::user_time_zone(conn)?))
+    }
+
+    /// Calendar date in the user zone
+    pub fn today(conn: &Connection) -> Result<NaiveDate, rusqlite::Error> {
+        Ok(Self::now(conn)?.date_naive())
+    }
+
+    /// Logical date in the user zone, honouring the reset time setting
+    pub fn logical_today(conn: &Connection) -> Result<NaiveDate, rusqlite::Error> {
+        let reset_time =
+            SettingsRepository::get(conn, "reset_time")?.unwrap_or_else(|| "00:00".to_string());
+        Ok(get_logical_date(
+            &reset_time,
+            Self::now(conn)?.naive_local(),
+        ))
+    }
+
+    pub fn set_time_zone(conn: &Connection, name: &str) -> Result<(), String> {
+        let tz = name
+            .parse::<Tz>()
+            .map_err(|_| format!("Unknown time zone: {}", name))?;
+        SettingsRepository::set_pending(conn, TIME_ZONE_KEY, tz.name()).map_err(|e| e.to_string())
+    }
+
+    pub fn is_valid(name: &str) -> bool {
+        name.parse::<Tz>().is_ok()
+    }
+
+    /// Compare the device zone with the one seen last time. A change is only
+    /// reported; the user zone stays put until the user switches it, so dates
+    /// don't shift mid-trip and synced devices keep agreeing.
+    pub fn check_device_time_zone(conn: &Connection) -> Result<TimeZoneStatus, rusqlite::Error> {
+        let time_zone = Self::user_time_zone(conn)?;
+        let device = Self::device_time_zone();
+
+        let previous = SettingsRepository::get(conn, DEVICE_TIME_ZONE_KEY)?;
+        let device_changed = previous
+            .as_deref()
+            .is_some_and(|name| name != device.name());
+        if previous.as_deref() != Some(device.name()) {
+            SettingsRepository::set(conn, DEVICE_TIME_ZONE_KEY, device.name())?;
+        }
+
+        Ok(TimeZoneStatus {
+            time_zone: time_zone.name().to_string(),
+            device_time_zone: device.name().to_string(),
+            device_changed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SyncStatus;
+    use chrono::TimeZone;
+
+    #[test]
+    fn same_instant_has_zone_specific_logical_dates() {
+        let instant = Utc.with_ymd_and_hms(2026, 3, 1, 20, 0, 0).unwrap();
+        let seoul = instant.with_timezone(&Tz::Asia__Seoul).naive_local(); // 05:00 on Mar 2
+        let new_york = instant.with_timezone(&Tz::America__New_York).naive_local(); // 15:00 on Mar 1
+
+        assert_eq!(
+            get_logical_date("06:00", seoul),
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+        );
+        assert_eq!(
+            get_logical_date("06:00", new_york),
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+        );
+        assert_eq!(
+            get_logical_date("00:00", seoul),
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn user_zone_is_pinned_to_the_device_on_first_use() {
+        let conn = crate::repository::open_in_memory();
+        SettingsRepository::set(&conn, TIME_ZONE_KEY, "Not/AZone").unwrap();
+        let device = TimeZoneService::device_time_zone();
+
+        assert_eq!(TimeZoneService::user_time_zone(&conn).unwrap(), device);
+        assert_eq!(
+            SettingsRepository::get(&conn, TIME_ZONE_KEY)
+                .unwrap()
+                .as_deref(),
+            Some(device.name())
+        );
+
+        // Once pinned, the stored zone wins over the device
+        SettingsRepository::set(&conn, TIME_ZONE_KEY, "Pacific/Chatham").unwrap();
+        assert_eq!(
+            TimeZoneService::user_time_zone(&conn).unwrap(),
+            Tz::Pacific__Chatham
+        );
+    }
+
+    #[test]
+    fn set_time_zone_rejects_unknown_names_and_marks_the_setting_pending() {
+        let conn = crate::repository::open_in_memory();
+        TimeZoneService::user_time_zone(&conn).unwrap();
+        SettingsRepository::mark_synced(&conn, TIME_ZONE_KEY).unwrap();
+
+        assert_eq!(
+            TimeZoneService::set_time_zone(&conn, "Mars/Olympus"),
+            Err("Unknown time zone: Mars/Olympus".to_string())
+        );
+        let (_, _, status) = SettingsRepository::get_with_sync(&conn, TIME_ZONE_KEY)
+            .unwrap()
+            .unwrap();
+        assert_eq!(status, SyncStatus::Synced);
+
+        TimeZoneService::set_time_zone(&conn, "Asia/Seoul").unwrap();
+        let (value, _, status) = SettingsRepository::get_with_sync(&conn, TIME_ZONE_KEY)
+            .unwrap()
+            .unwrap();
+        assert_eq!(value, "Asia/Seoul");
+        assert_eq!(status, SyncStatus::Pending);
+    }
+
+    #[test]
+    fn device_change_is_reported_once_without_moving_the_user_zone() {
+        let conn = crate::repository::open_in_memory();
+        let device = TimeZoneService::device_time_zone();
+        let elsewhere = if device == Tz::Asia__Seoul {
+            Tz::UTC
+        } else {
+            Tz::Asia__Seoul
+        };
+        SettingsRepository::set(&conn, TIME_ZONE_KEY, elsewhere.name()).unwrap();
+
+        let status = TimeZoneService::check_device_time_zone(&conn).unwrap();
+        assert!(!status.device_changed);
+
+        SettingsRepository::set(&conn, DEVICE_TIME_ZONE_KEY, elsewhere.name()).unwrap();
+        let status = TimeZoneService::check_device_time_zone(&conn).unwrap();
+        assert!(status.device_changed);
+        assert_eq!(status.time_zone, elsewhere.name());
+        assert_eq!(status.device_time_zone, device.name());
+
+        let status = TimeZoneService::check_device_time_zone(&conn).unwrap();
+        assert!(!status.device_changed);
+    }
+}
@@ -1,8 +1,10 @@
 use rusqlite::Connection;
 
-use crate::models::{RepeatType, TodoItem};
-use crate::repository::TodoRepository;
-use crate::service::RepeatService;
+use crate::models::{OccurrenceSource, RepeatSchedule, RepeatType, ScheduleVersion, TodoItem};
+use crate::repository::{
+    CategoryRepository, ExclusionRepository, ScheduleVersionRepository, TodoRepository,
+};
+use crate::service::{RepeatService, TimeZoneService, TrashService};
 
 pub struct TodoService;
 
@@ -23,15 +25,17 @@ impl TodoService {
         conn: &Connection,
         text: &str,
         category_id: Option<i64>,
-        repeat_type: &RepeatType,
-        repeat_detail: Option<&str>,
+        repeat: RepeatSchedule,
         track_streak: bool,
         reminder_at: Option<&str>,
-    ) -> Result<TodoItem, rusqlite::Error> {
+    ) -> Result<TodoItem, String> {
+        RepeatService::validate_schedule(&repeat)?;
+        let today = TimeZoneService::today(conn).map_err(|e| e.to_string())?;
+        let repeat = RepeatService::normalize_schedule(repeat, today);
+
         // Calculate initial next_due_at for repeating items
-        let next_due_at = if *repeat_type != RepeatType::None {
-            let today = chrono::Local::now().date_naive();
-            RepeatService::calculate_next_due(repeat_type, repeat_detail, today)
+        let next_due_at = if repeat.repeat_type != RepeatType::None {
+            RepeatService::calculate_next_due(&repeat, today)
         } else {
             None
         };
@@ -40,12 +44,12 @@ impl TodoService {
             conn,
             text,
             category_id,
-            repeat_type,
-            repeat_detail,
+            &repeat,
             next_due_at.as_deref(),
             track_streak,
             reminder_at,
         )
+        .map_err(|e| e.to_string())
     }
 
     pub fn update_reminder(
@@ -60,16 +64,10 @@ impl TodoService {
         RepeatService::toggle_with_repeat(conn, id)
     }
 
+    /// Move an item to the trash; sub-items go with their parent
     pub fn delete_item(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
-        // Check if item has been synced (has sync_id)
-        if let Some(item) = TodoRepository::get_by_id(conn, id)? {
-            if item.sync_id.is_some() {
-                // Item was synced - mark as deleted so it can be synced to server
-                TodoRepository::mark_deleted(conn, id)
-            } else {
-                // Item was never synced - delete immediately
-                TodoRepository::delete(conn, id)
-            }
+        if TodoRepository::get_by_id(conn, id)?.is_some() {
+            TrashService::trash_item(conn, id)
         } else {
             Ok(())
         }
@@ -95,24 +93,342 @@ impl TodoService {
         TodoRepository::update_linked_app(conn, id, linked_app)
     }
 
-    pub fn update_repeat(
+    pub fn update_repeat(conn: &Connection, id: i64, repeat: RepeatSchedule) -> Result<(), String> {
+        RepeatService::validate_schedule(&repeat)?;
+        Self::store_repeat(conn, id, repeat).map_err(|e| e.to_string())
+    }
+
+    fn store_repeat(
         conn: &Connection,
         id: i64,
-        repeat_type: &RepeatType,
-        repeat_detail: Option<&str>,
+        repeat: RepeatSchedule,
     ) -> Result<(), rusqlite::Error> {
+        let today = TimeZoneService::today(conn)?;
+        let Some(item) = TodoRepository::get_by_id(conn, id)? else {
+            return Ok(());
+        };
+        // Changing only the end or the exclusions keeps the date the
+        // intervals count from
+        let mut repeat = repeat;
+        if repeat.repeat_start.is_none()
+            && RepeatService::same_cadence(&RepeatSchedule::from_item(&item), &repeat)
+        {
+            repeat.repeat_start = item.repeat_start.clone();
+        }
+        let mut repeat = RepeatService::normalize_schedule(repeat, today);
+        if let Some(calendar_id) = item.exclusion_calendar_id {
+            repeat = repeat.with_exclusions(ExclusionRepository::get_ranges(conn, calendar_id)?);
+        }
+        Self::record_schedule_version(conn, &item, &repeat)?;
+
         // Calculate next_due_at for the new repeat settings
-        let next_due_at = if *repeat_type != RepeatType::None {
-            let today = chrono::Local::now().date_naive();
-            RepeatService::calculate_next_due(repeat_type, repeat_detail, today)
+        let next_due_at = if repeat.repeat_type != RepeatType::None {
+            RepeatService::calculate_next_due(&repeat, today)
         } else {
             None
         };
 
-        TodoRepository::update_repeat(conn, id, repeat_type, repeat_detail, next_due_at.as_deref())
+        TodoRepository::update_repeat(conn, id, &repeat, next_due_at.as_deref())?;
+        RepeatService::realign_due(conn, id)
+    }
+
+    /// Keep the schedule history streaks are judged by. The first change
+    /// also records the schedule the item followed until now.
+    fn record_schedule_version(
+        conn: &Connection,
+        item: &TodoItem,
+        repeat: &RepeatSchedule,
+    ) -> Result<(), rusqlite::Error> {
+        let today = TimeZoneService::logical_today(conn)?
+            .format("%Y-%m-%d")
+            .to_string();
+        if ScheduleVersionRepository::get_for_item(conn, item.id)?.is_empty() {
+            let started = item
+                .created_at
+                .as_deref()
+                .and_then(|s| s.get(..10))
+                .filter(|started| *started < today.as_str())
+                .unwrap_or("0001-01-01");
+            ScheduleVersionRepository::record(
+                conn,
+                item.id,
+                started,
+                &RepeatSchedule::from_item(item),
+            )?;
+        }
+        ScheduleVersionRepository::record(conn, item.id, &today, repeat)
+    }
+
+    pub fn get_schedule_versions(
+        conn: &Connection,
+        id: i64,
+    ) -> Result<Vec<ScheduleVersion>, rusqlite::Error> {
+        ScheduleVersionRepository::get_for_item(conn, id)
+    }
+
+    pub fn update_due(
+        conn: &Connection,
+        id: i64,
+        due_at: Option<&str>,
+        due_times: &[String],
+    ) -> Result<Option<TodoItem>, String> {
+        RepeatService::update_due(conn, id, due_at, due_times)
+    }
+
+    /// Set how many completions an item needs before it counts as done.
+    /// 0 turns it back into a plain item.
+    pub fn update_quantity_target(
+        conn: &Connection,
+        id: i64,
+        quantity_target: i32,
+    ) -> Result<(), String> {
+        if !(0..=1000).contains(&quantity_target) {
+            return Err("Quantity target must be between 0 and 1000".to_string());
+        }
+        TodoRepository::update_quantity_target(conn, id, quantity_target).map_err(|e| e.to_string())
+    }
+
+    pub fn increment_progress(
+        conn: &Connection,
+        id: i64,
+    ) -> Result<Option<TodoItem>, rusqlite::Error> {
+        RepeatService::increment_progress(conn, id, OccurrenceSource::App)
+    }
+
+    /// Attach an exclusion calendar to an item (or detach it with None).
+    /// A pending due date that now falls on an excluded day moves on.
+    pub fn update_exclusion_calendar(
+        conn: &Connection,
+        id: i64,
+        calendar_id: Option<i64>,
+    ) -> Result<(), rusqlite::Error> {
+        let Some(mut item) = TodoRepository::get_by_id(conn, id)? else {
+            return Ok(());
+        };
+        item.exclusion_calendar_id = calendar_id;
+
+        let schedule = RepeatService::schedule_for(conn, &item)?;
+        let next_due_at = match item.next_due_at.as_deref() {
+            Some(due) if schedule.is_excluded(due) => {
+                chrono::NaiveDate::parse_from_str(due, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|date| RepeatService::calculate_next_due(&schedule, date))
+            }
+            _ => item.next_due_at.clone(),
+        };
+
+        TodoRepository::update_exclusion_calendar(conn, id, calendar_id, next_due_at.as_deref())
+    }
+
+    /// Add a sub-item under `parent_id`, in the parent's category
+    pub fn create_subtask(
+        conn: &Connection,
+        parent_id: i64,
+        text: &str,
+    ) -> Result<TodoItem, String> {
+        let parent = Self::subtask_parent(conn, parent_id)?;
+        let mut item = Self::create_item(
+            conn,
+            text,
+            parent.category_id,
+            RepeatSchedule::new(RepeatType::None, None),
+            false,
+            None,
+        )?;
+        TodoRepository::set_parent(conn, item.id, Some(parent_id), parent.category_id)
+            .map_err(|e| e.to_string())?;
+        item.parent_id = Some(parent_id);
+        Ok(item)
+    }
+
+    /// Move an item under a parent, or back to the top level with None.
+    /// Sub-items are one level deep, so an item with sub-items of its own
+    /// can't be nested.
+    pub fn set_parent(conn: &Connection, id: i64, parent_id: Option<i64>) -> Result<(), String> {
+        let Some(item) = TodoRepository::get_by_id(conn, id).map_err(|e| e.to_string())? else {
+            return Ok(());
+        };
+        let Some(parent_id) = parent_id else {
+            return TodoRepository::set_parent(conn, id, None, item.category_id)
+                .map_err(|e| e.to_string());
+        };
+
+        if parent_id == id {
+            return Err("An item can't be its own sub-item".to_string());
+        }
+        let parent = Self::subtask_parent(conn, parent_id)?;
+        let has_children = !TodoRepository::get_children(conn, id)
+            .map_err(|e| e.to_string())?
+            .is_empty();
+        if has_children {
+            return Err("Items with sub-items can't be nested".to_string());
+        }
+
+        TodoRepository::set_parent(conn, id, Some(parent_id), parent.category_id)
+            .map_err(|e| e.to_string())
+    }
+
+    /// A top-level item that sub-items may be added to
+    fn subtask_parent(conn: &Connection, parent_id: i64) -> Result<TodoItem, String> {
+        let parent = TodoRepository::get_by_id(conn, parent_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Parent item not found".to_string())?;
+        if parent.parent_id.is_some() {
+            return Err("Sub-items can't have sub-items".to_string());
+        }
+        Ok(parent)
+    }
+
+    pub fn get_subtasks(
+        conn: &Connection,
+        parent_id: i64,
+    ) -> Result<Vec<TodoItem>, rusqlite::Error> {
+        TodoRepository::get_children(conn, parent_id)
+    }
+
+    pub fn update_auto_complete(
+        conn: &Connection,
+        id: i64,
+        auto_complete: bool,
+    ) -> Result<(), rusqlite::Error> {
+        TodoRepository::update_auto_complete(conn, id, auto_complete)
+    }
+
+    /// Move items to the end of `category_id`, in the given order. Sub-items
+    /// go with their parent; a sub-item moved without its parent becomes a
+    /// top-level item. Nothing moves if any item fails.
+    pub fn move_items(
+        conn: &Connection,
+        item_ids: &[i64],
+        category_id: Option<i64>,
+    ) -> Result<(), String> {
+        if let Some(category_id) = category_id {
+            if CategoryRepository::get_by_id(conn, category_id)
+                .map_err(|e| e.to_string())?
+                .is_none()
+            {
+                return Err("Category not found".to_string());
+            }
+        }
+
+        conn.execute("BEGIN TRANSACTION", [])
+            .map_err(|e| e.to_string())?;
+        match Self::move_items_to(conn, item_ids, category_id) {
+            Ok(()) => {
+                conn.execute("COMMIT", []).map_err(|e| e.to_string())?;
+                Ok(())
+            }
+            Err(e) => {
+                let _ = conn.execute("ROLLBACK", []);
+                Err(e.to_string())
+            }
+        }
+    }
+
+    fn move_items_to(
+        conn: &Connection,
+        item_ids: &[i64],
+        category_id: Option<i64>,
+    ) -> Result<(), rusqlite::Error> {
+        for &id in item_ids {
+            let Some(item) = TodoRepository::get_by_id(conn, id)? else {
+                continue;
+            };
+            let parent_moves = item
+                .parent_id
+                .is_some_and(|parent| item_ids.contains(&parent));
+            if parent_moves || item.category_id == category_id {
+                continue;
+            }
+
+            let order = TodoRepository::next_display_order(conn, category_id);
+            TodoRepository::move_to_category(conn, id, category_id, None, order)?;
+            for child in TodoRepository::get_children(conn, id)? {
+                let order = TodoRepository::next_display_order(conn, category_id);
+                TodoRepository::move_to_category(conn, child.id, category_id, Some(id), order)?;
+            }
+        }
+        Ok(())
     }
 
     pub fn reorder_items(conn: &Connection, item_ids: &[i64]) -> Result<(), rusqlite::Error> {
         TodoRepository::reorder(conn, item_ids)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::ResetService;
+
+    fn item(conn: &Connection, text: &str, category_id: Option<i64>) -> TodoItem {
+        TodoService::create_item(
+            conn,
+            text,
+            category_id,
+            RepeatSchedule::new(RepeatType::None, None),
+            false,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn is_done(conn: &Connection, id: i64) -> bool {
+        TodoRepository::get_by_id(conn, id).unwrap().unwrap().done
+    }
+
+    #[test]
+    fn sub_items_come_right_after_their_parent() {
+        let conn = crate::repository::open_in_memory();
+        let bag = item(&conn, "Pack for the gym", None);
+        let laundry = item(&conn, "Laundry", None);
+        let towel = TodoService::create_subtask(&conn, bag.id, "Towel").unwrap();
+        let shoes = TodoService::create_subtask(&conn, bag.id, "Shoes").unwrap();
+
+        let ids: Vec<i64> = TodoService::get_items(&conn, None)
+            .unwrap()
+            .iter()
+            .map(|item| item.id)
+            .collect();
+
+        assert_eq!(ids, vec![bag.id, towel.id, shoes.id, laundry.id]);
+    }
+
+    #[test]
+    fn unchecking_a_sub_item_reopens_its_parent() {
+        let conn = crate::repository::open_in_memory();
+        let bag = item(&conn, "Pack for the gym", None);
+        TodoService::update_auto_complete(&conn, bag.id, true).unwrap();
+        let towel = TodoService::create_subtask(&conn, bag.id, "Towel").unwrap();
+        let shoes = TodoService::create_subtask(&conn, bag.id, "Shoes").unwrap();
+
+        TodoService::toggle_item(&conn, towel.id).unwrap();
+        assert!(!is_done(&conn, bag.id));
+        TodoService::toggle_item(&conn, shoes.id).unwrap();
+        assert!(is_done(&conn, bag.id));
+
+        TodoService::toggle_item(&conn, shoes.id).unwrap();
+        assert!(!is_done(&conn, bag.id));
+        assert!(is_done(&conn, towel.id));
+    }
+
+    #[test]
+    fn category_reset_covers_sub_items_of_its_parents_only() {
+        let conn = crate::repository::open_in_memory();
+        let category_id = CategoryRepository::get_all(&conn).unwrap()[0].id;
+        let bag = item(&conn, "Pack for the gym", Some(category_id));
+        let towel = TodoService::create_subtask(&conn, bag.id, "Towel").unwrap();
+        let errands = item(&conn, "Errands", None);
+        let stamps = TodoService::create_subtask(&conn, errands.id, "Stamps").unwrap();
+        for id in [towel.id, bag.id, stamps.id, errands.id] {
+            TodoService::toggle_item(&conn, id).unwrap();
+        }
+
+        ResetService::reset_items(&conn, Some(category_id)).unwrap();
+
+        assert!(!is_done(&conn, bag.id));
+        assert!(!is_done(&conn, towel.id));
+        assert!(is_done(&conn, errands.id));
+        assert!(is_done(&conn, stamps.id));
+    }
+}
@@ -0,0 +1,342 @@
+use chrono::{DateTime, Duration, Utc};
+use rusqlite::Connection;
+
+use crate::models::{SyncStatus, TrashEntry, TrashKind};
+use crate::repository::{CategoryRepository, TagRepository, TodoRepository, TrashRepository};
+
+/// How long deleted items, categories and tags stay restorable
+const RETENTION_DAYS: i64 = 30;
+
+pub struct TrashService;
+
+impl TrashService {
+    pub fn get_trash(conn: &Connection) -> Result<Vec<TrashEntry>, rusqlite::Error> {
+        let mut entries = TrashRepository::get_entries(conn)?;
+        for entry in &mut entries {
+            entry.purge_at = Self::purge_at(&entry.deleted_at);
+        }
+        Ok(entries)
+    }
+
+    /// Move an item and its sub-items to the trash
+    pub fn trash_item(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        let now = Self::now();
+        for child in TodoRepository::get_children(conn, id)? {
+            TrashRepository::trash(conn, TrashKind::Item, child.id, &now)?;
+        }
+        TrashRepository::trash(conn, TrashKind::Item, id, &now)
+    }
+
+    /// Move a category and the items in it to the trash
+    pub fn trash_category(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        let now = Self::now();
+        for item in TodoRepository::get_by_category(conn, Some(id))? {
+            TrashRepository::trash(conn, TrashKind::Item, item.id, &now)?;
+        }
+        TrashRepository::trash(conn, TrashKind::Category, id, &now)
+    }
+
+    /// Move a tag to the trash. Its item links stay in place, hidden with it.
+    pub fn trash_tag(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+        TrashRepository::trash(conn, TrashKind::Tag, id, &Self::now())
+    }
+
+    /// Restore an item with the sub-items trashed along with it. An item
+    /// whose category or parent is gone comes back at the top level of the
+    /// uncategorized items.
+    pub fn restore_item(conn: &Connection, id: i64) -> Result<(), String> {
+        let deleted_at = Self::trashed_at(conn, TrashKind::Item, id)?;
+        let item = TodoRepository::get_by_id(conn, id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Item not found".to_string())?;
+
+        let category_id = match item.category_id {
+            Some(category_id) if Self::is_live(conn, TrashKind::Category, category_id)? => {
+                Some(category_id)
+            }
+            _ => None,
+        };
+        let parent_id = match item.parent_id {
+            Some(parent_id) if Self::is_live(conn, TrashKind::Item, parent_id)? => Some(parent_id),
+            _ => None,
+        };
+
+        TrashRepository::restore(conn, TrashKind::Item, id).map_err(|e| e.to_string())?;
+        TodoRepository::set_parent(conn, id, parent_id, category_id).map_err(|e| e.to_string())?;
+        for child_id in
+            TrashRepository::trashed_children(conn, id, &deleted_at).map_err(|e| e.to_string())?
+        {
+            TrashRepository::restore(conn, TrashKind::Item, child_id).map_err(|e| e.to_string())?;
+            TodoRepository::set_parent(conn, child_id, Some(id), category_id)
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Restore a category with the items trashed along with it
+    pub fn restore_category(conn: &Connection, id: i64) -> Result<(), String> {
+        let deleted_at = Self::trashed_at(conn, TrashKind::Category, id)?;
+        TrashRepository::restore(conn, TrashKind::Category, id).map_err(|e| e.to_string())?;
+        for item_id in TrashRepository::trashed_category_items(conn, id, &deleted_at)
+            .map_err(|e| e.to_string())?
+        {
+            TrashRepository::restore(conn, TrashKind::Item, item_id).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// Restore a tag; it shows on its items again
+    pub fn restore_tag(conn: &Connection, id: i64) -> Result<(), String> {
+        Self::trashed_at(conn, TrashKind::Tag, id)?;
+        TrashRepository::restore(conn, TrashKind::Tag, id).map_err(|e| e.to_string())
+    }
+
+    /// Delete everything that has been in the trash longer than the
+    /// retention period. Returns how many entries were purged.
+    pub fn purge_expired(conn: &Connection) -> Result<usize, rusqlite::Error> {
+        let cutoff = (Utc::now() - Duration::days(RETENTION_DAYS))
+            .format("%Y-%m-%dT%H:%M:%SZ")
+            .to_string();
+        Self::purge(conn, Some(&cutoff))
+    }
+
+    pub fn empty_trash(conn: &Connection) -> Result<usize, rusqlite::Error> {
+        Self::purge(conn, None)
+    }
+
+    /// Synced rows are handed to the next sync, which sends the remote
+    /// delete and then removes them; the rest are deleted right away
+    fn purge(conn: &Connection, before: Option<&str>) -> Result<usize, rusqlite::Error> {
+        let expired = TrashRepository::get_expired(conn, before)?;
+
+        conn.execute("BEGIN TRANSACTION", [])?;
+        for &(kind, id) in &expired {
+            if let Err(e) = Self::purge_entry(conn, kind, id) {
+                let _ = conn.execute("ROLLBACK", []);
+                return Err(e);
+            }
+        }
+        conn.execute("COMMIT", [])?;
+
+        Ok(expired.len())
+    }
+
+    fn purge_entry(conn: &Connection, kind: TrashKind, id: i64) -> Result<(), rusqlite::Error> {
+        match kind {
+            TrashKind::Item => match TodoRepository::get_by_id(conn, id)? {
+                Some(item) if item.sync_id.is_some() => TodoRepository::mark_deleted(conn, id),
+                Some(_) => TodoRepository::delete(conn, id),
+                None => Ok(()),
+            },
+            TrashKind::Category => match CategoryRepository::get_by_id(conn, id)? {
+                Some(category) if category.sync_id.is_some() => {
+                    CategoryRepository::mark_deleted(conn, id)
+                }
+                Some(_) => CategoryRepository::delete(conn, id),
+                None => Ok(()),
+            },
+            TrashKind::Tag => match TagRepository::get_by_id(conn, id)? {
+                Some(tag) if tag.sync_id.is_some() => TagRepository::mark_deleted(conn, id),
+                Some(_) => TagRepository::delete(conn, id),
+                None => Ok(()),
+            },
+        }
+    }
+
+    fn trashed_at(conn: &Connection, kind: TrashKind, id: i64) -> Result<String, String> {
+        TrashRepository::deleted_at(conn, kind, id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Not in the trash".to_string())
+    }
+
+    /// Whether the row exists and is neither trashed nor waiting for its
+    /// remote delete
+    fn is_live(conn: &Connection, kind: TrashKind, id: i64) -> Result<bool, String> {
+        let status = match kind {
+            TrashKind::Item => {
+                TodoRepository::get_by_id(conn, id).map(|r| r.map(|t| t.sync_status))
+            }
+            TrashKind::Category => {
+                CategoryRepository::get_by_id(conn, id).map(|r| r.map(|c| c.sync_status))
+            }
+            TrashKind::Tag => TagRepository::get_by_id(conn, id).map(|r| r.map(|t| t.sync_status)),
+        }
+        .map_err(|e| e.to_string())?;
+        Ok(status.is_some_and(|status| status != SyncStatus::Deleted))
+    }
+
+    fn now() -> String {
+        Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    }
+
+    /// When an entry trashed at `deleted_at` is purged
+    fn purge_at(deleted_at: &str) -> String {
+        DateTime::parse_from_rfc3339(deleted_at)
+            .map(|at| {
+                (at.with_timezone(&Utc) + Duration::days(RETENTION_DAYS))
+                    .format("%Y-%m-%dT%H:%M:%SZ")
+                    .to_string()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RepeatSchedule, RepeatType, TodoItem};
+    use crate::repository::{CompletionLogRepository, TodoTagRepository};
+    use crate::service::{CategoryService, TagService, TodoService};
+
+    #[test]
+    fn purge_at_adds_retention_period() {
+        assert_eq!(
+            TrashService::purge_at("2026-01-15T08:30:00Z"),
+            "2026-02-14T08:30:00Z"
+        );
+        assert_eq!(TrashService::purge_at("not a date"), "");
+    }
+
+    fn item(conn: &Connection, text: &str, category_id: Option<i64>) -> TodoItem {
+        TodoService::create_item(
+            conn,
+            text,
+            category_id,
+            RepeatSchedule::new(RepeatType::None, None),
+            false,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn is_live(conn: &Connection, id: i64) -> bool {
+        TodoRepository::get_by_id(conn, id)
+            .unwrap()
+            .is_some_and(|item| item.sync_status != SyncStatus::Deleted)
+    }
+
+    fn tag_names(conn: &Connection, item_id: i64) -> Vec<String> {
+        TodoTagRepository::get_tags_for_item(conn, item_id)
+            .unwrap()
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect()
+    }
+
+    #[test]
+    fn restored_item_keeps_its_tags_and_logs() {
+        let conn = crate::repository::open_in_memory();
+        let run = item(&conn, "Run", None);
+        TagService::add_tag_to_item(&conn, run.id, "sport").unwrap();
+        CompletionLogRepository::increment(&conn, run.id, "2026-03-04", None).unwrap();
+
+        TodoService::delete_item(&conn, run.id).unwrap();
+        assert!(!is_live(&conn, run.id));
+        assert_eq!(TrashService::get_trash(&conn).unwrap().len(), 1);
+
+        TrashService::restore_item(&conn, run.id).unwrap();
+        assert!(is_live(&conn, run.id));
+        assert!(TrashService::get_trash(&conn).unwrap().is_empty());
+        assert_eq!(tag_names(&conn, run.id), vec!["sport".to_string()]);
+        let logs = CompletionLogRepository::get_all_logs_for_item(&conn, run.id).unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].completed_on, "2026-03-04");
+    }
+
+    #[test]
+    fn restored_category_brings_its_items_back() {
+        let conn = crate::repository::open_in_memory();
+        let category = CategoryService::create(&conn, "Trip").unwrap();
+        let tickets = item(&conn, "Tickets", Some(category.id));
+        // Trashed on its own before the category; stays in the trash
+        let visa = item(&conn, "Visa", Some(category.id));
+        TodoService::delete_item(&conn, visa.id).unwrap();
+        // Trash timestamps have second precision
+        conn.execute(
+            "UPDATE todos SET deleted_at = '2026-03-01T08:00:00Z' WHERE id = ?1",
+            rusqlite::params![visa.id],
+        )
+        .unwrap();
+
+        CategoryService::delete(&conn, category.id).unwrap();
+        assert!(CategoryRepository::get_by_id(&conn, category.id)
+            .unwrap()
+            .is_some_and(|category| category.sync_status == SyncStatus::Deleted));
+        assert!(!is_live(&conn, tickets.id));
+
+        TrashService::restore_category(&conn, category.id).unwrap();
+        assert!(CategoryRepository::get_all(&conn)
+            .unwrap()
+            .iter()
+            .any(|c| c.id == category.id));
+        assert!(is_live(&conn, tickets.id));
+        assert!(!is_live(&conn, visa.id));
+    }
+
+    #[test]
+    fn sub_items_go_to_the_trash_and_come_back_with_their_parent() {
+        let conn = crate::repository::open_in_memory();
+        let bag = item(&conn, "Pack the bag", None);
+        let towel = TodoService::create_subtask(&conn, bag.id, "Towel").unwrap();
+
+        TodoService::delete_item(&conn, bag.id).unwrap();
+        assert!(!is_live(&conn, towel.id));
+
+        TrashService::restore_item(&conn, bag.id).unwrap();
+        let towel = TodoRepository::get_by_id(&conn, towel.id).unwrap().unwrap();
+        assert_eq!(towel.sync_status, SyncStatus::Pending);
+        assert_eq!(towel.parent_id, Some(bag.id));
+    }
+
+    #[test]
+    fn purge_deletes_unsynced_rows_and_hands_synced_ones_to_sync() {
+        let conn = crate::repository::open_in_memory();
+        let local = item(&conn, "Local", None);
+        let synced = item(&conn, "Synced", None);
+        TodoRepository::update_sync_id(&conn, synced.id, "remote-id").unwrap();
+        TodoService::delete_item(&conn, local.id).unwrap();
+        TodoService::delete_item(&conn, synced.id).unwrap();
+
+        assert_eq!(TrashService::empty_trash(&conn).unwrap(), 2);
+
+        assert!(TodoRepository::get_by_id(&conn, local.id)
+            .unwrap()
+            .is_none());
+        // Kept as deleted, out of the trash, until its remote delete is pushed
+        assert!(TrashService::get_trash(&conn).unwrap().is_empty());
+        assert!(TodoRepository::get_pending_sync(&conn)
+            .unwrap()
+            .iter()
+            .any(|item| item.id == synced.id && item.sync_status == SyncStatus::Deleted));
+    }
+
+    #[test]
+    fn new_tag_with_a_trashed_name_starts_without_links() {
+        let conn = crate::repository::open_in_memory();
+        let run = item(&conn, "Run", None);
+        let swim = item(&conn, "Swim", None);
+        let tag = TagService::add_tag_to_item(&conn, run.id, "sport").unwrap();
+        TagService::delete_tag(&conn, tag.id).unwrap();
+
+        TagService::add_tag_to_item(&conn, swim.id, "sport").unwrap();
+
+        assert!(tag_names(&conn, run.id).is_empty());
+        assert_eq!(tag_names(&conn, swim.id), vec!["sport".to_string()]);
+
+        // The old link can be made again
+        TagService::add_tag_to_item(&conn, run.id, "sport").unwrap();
+        assert_eq!(tag_names(&conn, run.id), vec!["sport".to_string()]);
+    }
+
+    #[test]
+    fn restored_tag_shows_on_its_items_again() {
+        let conn = crate::repository::open_in_memory();
+        let run = item(&conn, "Run", None);
+        let tag = TagService::add_tag_to_item(&conn, run.id, "sport").unwrap();
+        TagService::delete_tag(&conn, tag.id).unwrap();
+        assert!(tag_names(&conn, run.id).is_empty());
+
+        TrashService::restore_tag(&conn, tag.id).unwrap();
+        assert_eq!(tag_names(&conn, run.id), vec!["sport".to_string()]);
+    }
+}
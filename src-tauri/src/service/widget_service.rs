@@ -7,7 +7,8 @@ use serde::Deserialize;
 use tauri::{AppHandle, Manager};
 
 use crate::models::{
-    WidgetCategoryPendingItem, WidgetCategorySummary, WidgetSnapshot, WidgetTheme, WidgetTodoItem,
+    OccurrenceSource, WidgetCategoryPendingItem, WidgetCategorySummary, WidgetSnapshot,
+    WidgetTheme, WidgetTodoItem,
 };
 use crate::repository::{
     CategoryRepository, SettingsRepository, TodoRepository, TodoTagRepository,
@@ -32,6 +33,8 @@ mod snapshot;
 struct WidgetToggleAction {
     #[serde(alias = "itemId")]
     item_id: i64,
+    #[serde(default, alias = "queuedAt")]
+    queued_at: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -102,7 +105,8 @@ impl WidgetService {
         id: i64,
         max_items: Option<usize>,
     ) -> Result<WidgetSnapshot, String> {
-        crate::service::RepeatService::complete_with_repeat(conn, id).map_err(|e| e.to_string())?;
+        crate::service::RepeatService::increment_progress(conn, id, OccurrenceSource::Widget)
+            .map_err(|e| e.to_string())?;
         Self::refresh_cache(conn, app, max_items)
     }
 
@@ -118,14 +122,21 @@ impl WidgetService {
             return Ok(0);
         }
 
+        // Each tap on a quantity item counts, so actions are told apart by
+        // their queue time rather than by item. An entry read twice is still
+        // applied once, and taps on an item that is already done do nothing.
         let mut processed = 0usize;
-        let mut seen_item_ids = HashSet::new();
+        let mut seen_actions = HashSet::new();
         for action in actions {
-            if !seen_item_ids.insert(action.item_id) {
+            if !seen_actions.insert((action.item_id, action.queued_at.clone())) {
                 continue;
             }
 
-            match crate::service::RepeatService::complete_with_repeat(conn, action.item_id) {
+            match crate::service::RepeatService::increment_progress(
+                conn,
+                action.item_id,
+                OccurrenceSource::Widget,
+            ) {
                 Ok(Some(_)) => processed += 1,
                 Ok(None) => {}
                 Err(error) => {
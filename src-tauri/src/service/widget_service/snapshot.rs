@@ -53,6 +53,8 @@ impl WidgetService {
                         text: todo.text.clone(),
                         display_order: todo.display_order,
                         tags,
+                        quantity_target: todo.quantity_target,
+                        quantity_progress: todo.quantity_progress,
                     },
                 );
             }
@@ -74,6 +76,8 @@ impl WidgetService {
                     .and_then(|category_id| category_name_map.get(&category_id).cloned()),
                 display_order: item.display_order,
                 reminder_at: item.reminder_at,
+                quantity_target: item.quantity_target,
+                quantity_progress: item.quantity_progress,
                 updated_at: item.updated_at,
             })
             .collect();
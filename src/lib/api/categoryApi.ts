@@ -1,5 +1,5 @@
 import { invoke } from './client';
-import type { Category } from '../../types';
+import type { Category, ResetPolicy } from '../../types';
 
 export async function getCategories(): Promise<Category[]> {
   return invoke<Category[]>('get_categories');
@@ -13,6 +13,15 @@ export async function editCategory(id: number, name: string): Promise<void> {
   return invoke<void>('edit_category', { id, name });
 }
 
+export async function updateCategoryResetPolicy(
+  id: number,
+  resetPolicy: ResetPolicy,
+  resetTimes?: string[],
+  resetWeekdays?: number[]
+): Promise<void> {
+  return invoke<void>('update_category_reset_policy', { id, resetPolicy, resetTimes, resetWeekdays });
+}
+
 export async function deleteCategory(id: number): Promise<void> {
   return invoke<void>('delete_category', { id });
 }
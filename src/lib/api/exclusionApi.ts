@@ -0,0 +1,42 @@
+import { invoke } from './client';
+import type { ExclusionCalendar, ExclusionRange } from '../../types';
+
+export async function getExclusionCalendars(): Promise<ExclusionCalendar[]> {
+  return invoke<ExclusionCalendar[]>('get_exclusion_calendars');
+}
+
+export async function addExclusionCalendar(name: string): Promise<ExclusionCalendar> {
+  return invoke<ExclusionCalendar>('add_exclusion_calendar', { name });
+}
+
+export async function deleteExclusionCalendar(id: number): Promise<void> {
+  return invoke<void>('delete_exclusion_calendar', { id });
+}
+
+export async function getExclusionRanges(calendarId: number): Promise<ExclusionRange[]> {
+  return invoke<ExclusionRange[]>('get_exclusion_ranges', { calendarId });
+}
+
+export async function addExclusionRange(
+  calendarId: number,
+  startDate: string,
+  endDate?: string | null,
+  label?: string | null
+): Promise<ExclusionRange> {
+  return invoke<ExclusionRange>('add_exclusion_range', { calendarId, startDate, endDate, label });
+}
+
+export async function deleteExclusionRange(id: number): Promise<void> {
+  return invoke<void>('delete_exclusion_range', { id });
+}
+
+export async function importExclusionIcs(calendarId: number, path: string): Promise<number> {
+  return invoke<number>('import_exclusion_ics', { calendarId, path });
+}
+
+export async function updateItemExclusionCalendar(
+  id: number,
+  calendarId: number | null
+): Promise<void> {
+  return invoke<void>('update_item_exclusion_calendar', { id, calendarId });
+}
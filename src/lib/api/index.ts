@@ -1,12 +1,17 @@
 // Re-export all API modules
 export * from './authApi';
 export * from './categoryApi';
+export * from './exclusionApi';
 export * from './graphApi';
+export * from './journalApi';
 export * from './realtimeApi';
 export * from './settingsApi';
+export * from './statsApi';
 export * from './streakApi';
 export * from './syncApi';
 export * from './tagApi';
+export * from './templateApi';
+export * from './trashApi';
 export * from './todoApi';
 export * from './widgetApi';
 export { invoke } from './client';
@@ -0,0 +1,14 @@
+import { invoke } from './client';
+import type { UndoState } from '../../types';
+
+export async function undo(): Promise<UndoState> {
+  return invoke<UndoState>('undo');
+}
+
+export async function redo(): Promise<UndoState> {
+  return invoke<UndoState>('redo');
+}
+
+export async function getUndoState(): Promise<UndoState> {
+  return invoke<UndoState>('get_undo_state');
+}
@@ -1,4 +1,5 @@
 import { invoke } from './client';
+import type { TimeZoneStatus } from '../../types';
 
 export async function getSetting(key: string): Promise<string | null> {
   return invoke<string | null>('get_setting', { key });
@@ -7,3 +8,11 @@ export async function getSetting(key: string): Promise<string | null> {
 export async function setSetting(key: string, value: string): Promise<void> {
   return invoke<void>('set_setting', { key, value });
 }
+
+export async function getTimeZoneStatus(): Promise<TimeZoneStatus> {
+  return invoke<TimeZoneStatus>('get_time_zone_status');
+}
+
+export async function setTimeZone(timeZone: string): Promise<void> {
+  return invoke<void>('set_time_zone', { timeZone });
+}
@@ -0,0 +1,33 @@
+import type { CategoryTrend, CompletionHourStats, MissedItem, WeekdayStats } from '../../types';
+import { invoke } from './client';
+
+/**
+ * Get the completion rate per weekday over the last `days` days, or all time
+ */
+export async function getWeekdayStats(days?: number): Promise<WeekdayStats[]> {
+  return invoke<WeekdayStats[]>('get_weekday_stats', { days: days ?? null });
+}
+
+/**
+ * Get completions per hour of day and the typical completion hour
+ */
+export async function getCompletionHourStats(days?: number): Promise<CompletionHourStats> {
+  return invoke<CompletionHourStats>('get_completion_hour_stats', { days: days ?? null });
+}
+
+/**
+ * Get the 30- and 90-day completion trend of each category
+ */
+export async function getCategoryTrends(): Promise<CategoryTrend[]> {
+  return invoke<CategoryTrend[]>('get_category_trends');
+}
+
+/**
+ * Get the items with the most missed occurrences
+ */
+export async function getMostMissedItems(days?: number, limit?: number): Promise<MissedItem[]> {
+  return invoke<MissedItem[]>('get_most_missed_items', {
+    days: days ?? null,
+    limit: limit ?? null
+  });
+}
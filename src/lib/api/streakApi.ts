@@ -1,4 +1,11 @@
-import type { HeatmapData, TrackedItem } from '../../types';
+import type {
+  AggregateHeatmapData,
+  GoalPeriod,
+  HeatmapData,
+  MeasurementSeries,
+  Occurrence,
+  TrackedItem
+} from '../../types';
 import { invoke } from './client';
 
 /**
@@ -15,9 +22,91 @@ export async function getItemHeatmapData(itemId: number): Promise<HeatmapData |
   return invoke<HeatmapData | null>('get_item_heatmap_data', { itemId });
 }
 
+/**
+ * Get the aggregate heatmap of the tracked items in a category
+ */
+export async function getCategoryHeatmapData(categoryId: number): Promise<AggregateHeatmapData> {
+  return invoke<AggregateHeatmapData>('get_category_heatmap_data', { categoryId });
+}
+
+/**
+ * Get the aggregate heatmap of the tracked items carrying a tag
+ */
+export async function getTagHeatmapData(tagId: number): Promise<AggregateHeatmapData> {
+  return invoke<AggregateHeatmapData>('get_tag_heatmap_data', { tagId });
+}
+
+/**
+ * Get the aggregate heatmap of every tracked item
+ */
+export async function getAllHeatmapData(): Promise<AggregateHeatmapData> {
+  return invoke<AggregateHeatmapData>('get_all_heatmap_data');
+}
+
+/**
+ * Add a forgotten completion on a past date (last 30 days, scheduled days only).
+ * Returns the recalculated heatmap.
+ */
+export async function backfillCompletion(
+  itemId: number,
+  date: string
+): Promise<HeatmapData | null> {
+  return invoke<HeatmapData | null>('backfill_completion', { itemId, date });
+}
+
+/**
+ * Remove one completion from a past date. Returns the recalculated heatmap.
+ */
+export async function removePastCompletion(
+  itemId: number,
+  date: string
+): Promise<HeatmapData | null> {
+  return invoke<HeatmapData | null>('remove_past_completion', { itemId, date });
+}
+
+/**
+ * Get the recorded values of an item with min/max/avg and trend.
+ * Covers the last `days` days, or all time when omitted.
+ */
+export async function getMeasurementSeries(
+  itemId: number,
+  days?: number
+): Promise<MeasurementSeries> {
+  return invoke<MeasurementSeries>('get_measurement_series', { itemId, days: days ?? null });
+}
+
+/**
+ * Get the occurrence ledger (completed / missed / skipped slots) for an item
+ */
+export async function getItemOccurrences(itemId: number, days?: number): Promise<Occurrence[]> {
+  return invoke<Occurrence[]>('get_item_occurrences', { itemId, days: days ?? null });
+}
+
 /**
  * Update track_streak setting for an item
  */
 export async function updateTrackStreak(id: number, trackStreak: boolean): Promise<void> {
   return invoke<void>('update_track_streak', { id, trackStreak });
 }
+
+/**
+ * Update the streak freezes per month and grace days per week of an item
+ */
+export async function updateStreakGrace(
+  id: number,
+  freezesPerMonth: number,
+  gracePerWeek: number
+): Promise<void> {
+  return invoke<void>('update_streak_grace', { id, freezesPerMonth, gracePerWeek });
+}
+
+/**
+ * Set the frequency goal of an item ('none' clears it)
+ */
+export async function updateGoal(
+  id: number,
+  goalPeriod: GoalPeriod,
+  goalTarget: number
+): Promise<void> {
+  return invoke<void>('update_goal', { id, goalPeriod, goalTarget });
+}
@@ -0,0 +1,26 @@
+import { invoke } from './client';
+import type { ChecklistTemplate } from '../../types';
+
+export async function getTemplates(): Promise<ChecklistTemplate[]> {
+  return invoke<ChecklistTemplate[]>('get_templates');
+}
+
+export async function createTemplateFromCategory(
+  categoryId: number,
+  name: string
+): Promise<ChecklistTemplate> {
+  return invoke<ChecklistTemplate>('create_template_from_category', { categoryId, name });
+}
+
+// Returns the id of the category the items were created in
+export async function instantiateTemplate(
+  templateId: number,
+  categoryId: number | null,
+  newCategoryName: string | null
+): Promise<number> {
+  return invoke<number>('instantiate_template', { templateId, categoryId, newCategoryName });
+}
+
+export async function deleteTemplate(id: number): Promise<void> {
+  return invoke<void>('delete_template', { id });
+}
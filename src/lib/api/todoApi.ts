@@ -1,5 +1,5 @@
 import { invoke } from './client';
-import type { TodoItem, RepeatType } from '../../types';
+import type { TodoItem, RepeatType, ScheduleVersion } from '../../types';
 
 export async function getItems(categoryId: number | null): Promise<TodoItem[]> {
   return invoke<TodoItem[]>('get_items', { categoryId });
@@ -11,13 +11,19 @@ export async function addItem(
   repeatType?: RepeatType,
   repeatDetail?: string | null,
   trackStreak?: boolean,
-  reminderAt?: string | null
+  reminderAt?: string | null,
+  repeatInterval?: number,
+  repeatUntil?: string | null,
+  repeatCount?: number | null
 ): Promise<TodoItem> {
   return invoke<TodoItem>('add_item', {
     text,
     categoryId,
     repeatType: repeatType || null,
     repeatDetail: repeatDetail || null,
+    repeatInterval: repeatInterval || null,
+    repeatUntil: repeatUntil || null,
+    repeatCount: repeatCount || null,
     trackStreak: trackStreak || false,
     reminderAt: reminderAt || null,
   });
@@ -30,6 +36,15 @@ export async function updateItemReminder(
   return invoke<void>('update_item_reminder', { id, reminderAt });
 }
 
+// Repeating items take dueTimes ("HH:MM" slots); one-off items take dueAt
+export async function updateItemDue(
+  id: number,
+  dueAt: string | null,
+  dueTimes: string[] | null
+): Promise<TodoItem | null> {
+  return invoke<TodoItem | null>('update_item_due', { id, dueAt, dueTimes });
+}
+
 export async function updateItemLinkedApp(
   id: number,
   linkedApp: string | null
@@ -41,6 +56,58 @@ export async function toggleItem(id: number): Promise<TodoItem | null> {
   return invoke<TodoItem | null>('toggle_item', { id });
 }
 
+// Counts one step towards the quantity target; completes the item once reached
+export async function incrementItemProgress(id: number): Promise<TodoItem | null> {
+  return invoke<TodoItem | null>('increment_item_progress', { id });
+}
+
+// Adds a sub-item under a parent, in the parent's category
+export async function createSubtask(parentId: number, text: string): Promise<TodoItem> {
+  return invoke<TodoItem>('create_subtask', { parentId, text });
+}
+
+export async function getSubtasks(parentId: number): Promise<TodoItem[]> {
+  return invoke<TodoItem[]>('get_subtasks', { parentId });
+}
+
+// Moves an item under a parent, or back to the top level with null
+export async function setItemParent(id: number, parentId: number | null): Promise<void> {
+  return invoke('set_item_parent', { id, parentId });
+}
+
+export async function updateItemAutoComplete(id: number, autoComplete: boolean): Promise<void> {
+  return invoke('update_item_auto_complete', { id, autoComplete });
+}
+
+// Completes the item and records a value with the completion (72.4 kg)
+export async function completeItemWithValue(
+  id: number,
+  value: number,
+  unit: string | null
+): Promise<TodoItem | null> {
+  return invoke<TodoItem | null>('complete_item_with_value', { id, value, unit });
+}
+
+export async function updateItemQuantityTarget(
+  id: number,
+  quantityTarget: number
+): Promise<void> {
+  return invoke<void>('update_item_quantity_target', { id, quantityTarget });
+}
+
+// Schedule history of an item, oldest first; empty until its repeat changes
+export async function getItemScheduleVersions(id: number): Promise<ScheduleVersion[]> {
+  return invoke<ScheduleVersion[]>('get_item_schedule_versions', { id });
+}
+
+export async function skipItemOccurrence(id: number): Promise<TodoItem | null> {
+  return invoke<TodoItem | null>('skip_item_occurrence', { id });
+}
+
+export async function snoozeItem(id: number, until: string): Promise<TodoItem | null> {
+  return invoke<TodoItem | null>('snooze_item', { id, until });
+}
+
 export async function deleteItem(id: number): Promise<void> {
   return invoke<void>('delete_item', { id });
 }
@@ -56,15 +123,60 @@ export async function updateItemMemo(id: number, memo: string | null): Promise<v
 export async function updateItemRepeat(
   id: number,
   repeatType: RepeatType,
-  repeatDetail: string | null
+  repeatDetail: string | null,
+  repeatInterval?: number,
+  repeatUntil?: string | null,
+  repeatCount?: number | null
 ): Promise<void> {
-  return invoke<void>('update_item_repeat', { id, repeatType, repeatDetail });
+  return invoke<void>('update_item_repeat', {
+    id,
+    repeatType,
+    repeatDetail,
+    repeatInterval: repeatInterval || null,
+    repeatUntil: repeatUntil || null,
+    repeatCount: repeatCount || null,
+  });
+}
+
+export interface RepeatPreviewOptions {
+  repeatInterval?: number;
+  repeatUntil?: string | null;
+  repeatCount?: number | null;
+  startDate?: string | null;
+  count?: number;
+  exclusionCalendarId?: number | null;
+}
+
+// Next due dates for unsaved repeat settings; rejects with a validation message
+export async function previewRepeatOccurrences(
+  repeatType: RepeatType,
+  repeatDetail: string | null,
+  options: RepeatPreviewOptions = {}
+): Promise<string[]> {
+  return invoke<string[]>('preview_repeat_occurrences', {
+    repeatType,
+    repeatDetail,
+    repeatInterval: options.repeatInterval || null,
+    repeatUntil: options.repeatUntil || null,
+    repeatCount: options.repeatCount || null,
+    startDate: options.startDate || null,
+    count: options.count ?? null,
+    exclusionCalendarId: options.exclusionCalendarId ?? null,
+  });
 }
 
 export async function reorderItems(itemIds: number[]): Promise<void> {
   return invoke<void>('reorder_items', { itemIds });
 }
 
+export async function moveItem(id: number, categoryId: number | null): Promise<void> {
+  return invoke<void>('move_item', { id, categoryId });
+}
+
+export async function moveItems(itemIds: number[], categoryId: number | null): Promise<void> {
+  return invoke<void>('move_items', { itemIds, categoryId });
+}
+
 export async function resetAllItems(categoryId: number | null): Promise<void> {
   return invoke<void>('reset_all_items', { categoryId });
 }
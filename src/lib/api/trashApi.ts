@@ -0,0 +1,23 @@
+import { invoke } from './client';
+import type { TrashEntry } from '../../types';
+
+export async function getTrash(): Promise<TrashEntry[]> {
+  return invoke<TrashEntry[]>('get_trash');
+}
+
+export async function restoreItem(id: number): Promise<void> {
+  return invoke<void>('restore_item', { id });
+}
+
+export async function restoreCategory(id: number): Promise<void> {
+  return invoke<void>('restore_category', { id });
+}
+
+export async function restoreTag(id: number): Promise<void> {
+  return invoke<void>('restore_tag', { id });
+}
+
+// Deletes everything in the trash now; returns how many entries went
+export async function emptyTrash(): Promise<number> {
+  return invoke<number>('empty_trash');
+}
@@ -10,7 +10,7 @@ interface HomeBootstrapDeps {
   loadAllTags: () => Promise<void>;
   loadTagsForItems: (itemList: TodoItem[]) => Promise<void>;
   getItems: () => TodoItem[];
-  scheduleResetTimer: () => Promise<void>;
+  startSchedulerListener: () => Promise<void>;
   rescheduleAll: (itemList: TodoItem[]) => Promise<void>;
 }
 
@@ -27,7 +27,7 @@ export function createHomeBootstrap(deps: HomeBootstrapDeps) {
     await deps.loadAllTags();
     await deps.loadTagsForItems(deps.getItems());
 
-    await deps.scheduleResetTimer();
+    await deps.startSchedulerListener();
     await deps.rescheduleAll(deps.getItems());
   }
 
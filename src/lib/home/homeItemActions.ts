@@ -13,6 +13,7 @@ interface HomeItemActionDeps {
   ) => Promise<void>;
   updateReminder: (id: number, reminderAt: string | null) => Promise<void>;
   toggleItem: (id: number) => Promise<void>;
+  incrementProgress: (id: number) => Promise<void>;
   deleteItem: (id: number) => Promise<void>;
   getItems: () => TodoItem[];
   scheduleReminder: (itemId: number, itemText: string, reminderAt: string) => Promise<void>;
@@ -65,8 +66,22 @@ export function createHomeItemActions(deps: HomeItemActionDeps) {
   async function handleToggleItem(id: number): Promise<void> {
     const item = deps.getItems().find((currentItem) => currentItem.id === id);
     const wasDone = item?.done;
+    // A tap on an open quantity item counts one step towards its target
+    const counts = !wasDone && (item?.quantity_target ?? 0) > 0;
 
-    await deps.toggleItem(id);
+    if (counts) {
+      await deps.incrementProgress(id);
+    } else {
+      await deps.toggleItem(id);
+    }
+
+    // Items with due slots move on to their next slot instead of going quiet
+    const updated = deps.getItems().find((currentItem) => currentItem.id === id);
+    if (counts && !updated?.done) return;
+    if (updated?.due_at) {
+      await deps.scheduleReminder(id, updated.text, updated.due_at.slice(11, 16));
+      return;
+    }
 
     if (!wasDone && item?.reminder_at) {
       await deps.cancelReminder(id);
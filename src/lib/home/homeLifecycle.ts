@@ -1,13 +1,11 @@
 import type { TodoItem } from '../../types';
-import { createHomeResetScheduler } from './homeResetScheduler';
+import { createHomeScheduler } from './homeScheduler';
 
 interface HomeLifecycleDeps {
   loadItems: () => Promise<void>;
   loadTagsForItems: (itemList: TodoItem[]) => Promise<void>;
   getItems: () => TodoItem[];
   processRepeats: () => Promise<number>;
-  checkAndAutoReset: () => Promise<boolean>;
-  getResetTime: () => Promise<string | null>;
   rescheduleAll: (itemList: TodoItem[]) => Promise<void>;
 }
 
@@ -32,20 +30,18 @@ export function createHomeLifecycle(deps: HomeLifecycleDeps) {
     }
   }
 
-  async function checkAndPerformAutoReset(): Promise<void> {
+  async function reloadAfterSchedulerTick(): Promise<void> {
     try {
-      const didReset = await deps.checkAndAutoReset();
-      if (didReset) {
-        await deps.loadItems();
-      }
+      await deps.loadItems();
+      await deps.loadTagsForItems(deps.getItems());
+      await deps.rescheduleAll(deps.getItems());
     } catch (error) {
-      console.error('Failed to check auto-reset:', error);
+      console.error('Failed to reload after scheduler run:', error);
     }
   }
 
-  const { scheduleResetTimer, clearResetTimer } = createHomeResetScheduler({
-    getResetTime: deps.getResetTime,
-    onReset: checkAndPerformAutoReset
+  const { startSchedulerListener, stopSchedulerListener } = createHomeScheduler({
+    onTick: reloadAfterSchedulerTick
   });
 
   async function handleVisibilityChange(): Promise<void> {
@@ -63,8 +59,8 @@ export function createHomeLifecycle(deps: HomeLifecycleDeps) {
 
   return {
     processRepeatsAndReload,
-    scheduleResetTimer,
-    clearResetTimer,
+    startSchedulerListener,
+    stopSchedulerListener,
     handleVisibilityChange,
   };
 }
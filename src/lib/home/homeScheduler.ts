@@ -0,0 +1,38 @@
+import { listen } from '@tauri-apps/api/event';
+import { SCHEDULER_EVENT, type SchedulerEvent } from '../../types';
+
+interface HomeSchedulerDeps {
+  onTick: (event: SchedulerEvent) => Promise<void>;
+}
+
+// The backend scheduler runs resets, repeats and reminders on its own and
+// emits SCHEDULER_EVENT whenever it changed something
+export function createHomeScheduler(deps: HomeSchedulerDeps) {
+  let unlisten: (() => void) | null = null;
+
+  function stopSchedulerListener(): void {
+    if (!unlisten) {
+      return;
+    }
+
+    unlisten();
+    unlisten = null;
+  }
+
+  async function startSchedulerListener(): Promise<void> {
+    stopSchedulerListener();
+
+    try {
+      unlisten = await listen<SchedulerEvent>(SCHEDULER_EVENT, async (event) => {
+        await deps.onTick(event.payload);
+      });
+    } catch (error) {
+      console.error('Failed to listen for scheduler events:', error);
+    }
+  }
+
+  return {
+    startSchedulerListener,
+    stopSchedulerListener
+  };
+}
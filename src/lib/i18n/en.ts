@@ -14,6 +14,9 @@ export const en: Translations = {
   addItem: 'Add Item',
   resetCheck: 'Reset Checks',
   menu: 'Menu',
+  undo: 'Undo',
+  redo: 'Redo',
+  undoDiscarded: 'That change was edited since and can no longer be undone or redone.',
 
   // AddItemModal
   addItemTitle: 'Add Item',
@@ -118,6 +121,12 @@ export const en: Translations = {
   repeatDaily: 'Daily',
   repeatWeekly: 'Weekly',
   repeatMonthly: 'Monthly',
+  repeatYearly: 'Yearly',
+  repeatRule: 'Custom',
+  repeatAfterCompletion: (days: number) => `${days} day${days === 1 ? '' : 's'} after done`,
+  repeatEveryInterval: (interval: number) => `every ${interval}`,
+  repeatUntil: (date: string) => `until ${date}`,
+  repeatCountProgress: (done: number, total: number) => `${done}/${total} times`,
   repeatDaysLabel: 'Repeat Days',
   repeatDatesLabel: 'Repeat Dates',
   sun: 'Sun',
@@ -144,6 +153,7 @@ export const en: Translations = {
   noCompletedDates: 'No completed dates yet.',
   loading: 'Loading...',
   trackStreak: 'Track Streak',
+  quantityTarget: 'Taps to complete (0 = one)',
   trackingStreak: 'Tracking streak',
   noTrackedItems: 'No tracked items yet',
   addStreakHint: 'Enable streak tracking in item details!',
@@ -152,6 +162,10 @@ export const en: Translations = {
   resetTimeChange: 'Reset Time',
   resetTimeTitle: 'Reset Time',
   resetTimeDescription: 'Time when daily tasks reset',
+  timeZoneLabel: 'Time zone',
+  timeZoneDeviceDiffers: (zone: string) => `This device is set to ${zone}`,
+  timeZoneDeviceChanged: 'The device time zone changed. Dates follow the zone above until you switch.',
+  useDeviceTimeZone: 'Use device time zone',
 
   // Account & Sync settings
   syncTitle: 'Sync',
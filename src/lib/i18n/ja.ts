@@ -14,6 +14,9 @@ export const ja: Translations = {
   addItem: '項目を追加',
   resetCheck: 'チェックをリセット',
   menu: 'メニュー',
+  undo: '元に戻す',
+  redo: 'やり直す',
+  undoDiscarded: 'その後に変更されたため、この操作は元に戻す・やり直すことができません。',
 
   // AddItemModal
   addItemTitle: '項目を追加',
@@ -118,6 +121,12 @@ export const ja: Translations = {
   repeatDaily: '毎日',
   repeatWeekly: '毎週',
   repeatMonthly: '毎月',
+  repeatYearly: '毎年',
+  repeatRule: 'カスタム',
+  repeatAfterCompletion: (days: number) => `完了から${days}日後`,
+  repeatEveryInterval: (interval: number) => `${interval}回ごと`,
+  repeatUntil: (date: string) => `${date}まで`,
+  repeatCountProgress: (done: number, total: number) => `${done}/${total}回`,
   repeatDaysLabel: '繰り返す曜日',
   repeatDatesLabel: '繰り返す日付',
   sun: '日',
@@ -144,6 +153,7 @@ export const ja: Translations = {
   noCompletedDates: '達成日がありません。',
   loading: '読み込み中...',
   trackStreak: 'ストリーク追跡',
+  quantityTarget: '完了までのタップ数（0 = 1回）',
   trackingStreak: 'ストリーク追跡中',
   noTrackedItems: '追跡中の項目がありません',
   addStreakHint: '項目の詳細でストリーク追跡を有効にしましょう！',
@@ -152,6 +162,10 @@ export const ja: Translations = {
   resetTimeChange: 'リセット時間',
   resetTimeTitle: 'リセット時間',
   resetTimeDescription: '毎日のタスクがリセットされる時間',
+  timeZoneLabel: 'タイムゾーン',
+  timeZoneDeviceDiffers: (zone: string) => `この端末のタイムゾーンは${zone}です`,
+  timeZoneDeviceChanged: '端末のタイムゾーンが変わりました。切り替えるまで日付は上のタイムゾーンに従います。',
+  useDeviceTimeZone: '端末のタイムゾーンを使う',
 
   // Account & Sync settings
   syncTitle: '同期',
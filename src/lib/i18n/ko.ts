@@ -12,6 +12,9 @@ export const ko = {
   addItem: '항목 추가',
   resetCheck: '체크 초기화',
   menu: '메뉴',
+  undo: '실행 취소',
+  redo: '다시 실행',
+  undoDiscarded: '이후에 변경되어 이 작업은 더 이상 실행 취소하거나 다시 실행할 수 없습니다.',
 
   // AddItemModal
   addItemTitle: '항목 추가',
@@ -116,6 +119,12 @@ export const ko = {
   repeatDaily: '매일',
   repeatWeekly: '매주',
   repeatMonthly: '매월',
+  repeatYearly: '매년',
+  repeatRule: '사용자 지정',
+  repeatAfterCompletion: (days: number) => `완료 후 ${days}일마다`,
+  repeatEveryInterval: (interval: number) => `${interval}회마다`,
+  repeatUntil: (date: string) => `${date}까지`,
+  repeatCountProgress: (done: number, total: number) => `${done}/${total}회`,
   repeatDaysLabel: '반복 요일',
   repeatDatesLabel: '반복 날짜',
   sun: '일',
@@ -142,6 +151,7 @@ export const ko = {
   noCompletedDates: '완료 날짜가 없습니다.',
   loading: '로딩 중...',
   trackStreak: '스트릭 추적',
+  quantityTarget: '완료까지 탭 횟수 (0 = 한 번)',
   trackingStreak: '스트릭 추적 중',
   noTrackedItems: '추적 중인 항목이 없습니다',
   addStreakHint: '항목의 메모에서 스트릭 추적을 켜보세요!',
@@ -150,6 +160,10 @@ export const ko = {
   resetTimeChange: '초기화 시간',
   resetTimeTitle: '초기화 시간',
   resetTimeDescription: '매일 할 일이 초기화되는 시간',
+  timeZoneLabel: '시간대',
+  timeZoneDeviceDiffers: (zone: string) => `이 기기의 시간대는 ${zone}입니다`,
+  timeZoneDeviceChanged: '기기 시간대가 바뀌었습니다. 전환하기 전까지 날짜는 위 시간대를 따릅니다.',
+  useDeviceTimeZone: '기기 시간대 사용',
 
   // Account & Sync settings
   syncTitle: '동기화',
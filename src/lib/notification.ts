@@ -125,6 +125,15 @@ export async function cancelReminder(itemId: number): Promise<void> {
   }
 }
 
+/**
+ * Reminder time ("HH:MM") for an item: the time of its due slot when it has
+ * one, otherwise its fixed reminder time.
+ */
+export function reminderTime(item: TodoItem): string | null {
+  if (item.due_at) return item.due_at.slice(11, 16);
+  return item.reminder_at;
+}
+
 /**
  * Reschedule all reminders on app startup.
  */
@@ -134,8 +143,10 @@ export async function rescheduleAll(items: TodoItem[]): Promise<void> {
     if (!granted) return;
 
     for (const item of items) {
-      if (item.reminder_at && !item.done) {
-        await scheduleReminder(item.id, item.text, item.reminder_at);
+      // A done item with a due slot is already waiting for its next slot
+      const time = reminderTime(item);
+      if (time && (item.due_at || !item.done)) {
+        await scheduleReminder(item.id, item.text, time);
       }
     }
   } catch (e) {
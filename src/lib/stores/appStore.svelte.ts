@@ -1,9 +1,11 @@
-import type { TodoItem, Category, RepeatType, Tag } from '../../types';
+import type { TodoItem, Category, RepeatType, Tag, TrashEntry, UndoState } from '../../types';
 import { createCategoryActions } from './appStoreCategoryActions';
 import { createItemActions } from './appStoreItemActions';
 import { createTagActions } from './appStoreTagActions';
 import { syncStore } from './syncStore.svelte';
+import * as journalApi from '../api/journalApi';
 import * as todoApi from '../api/todoApi';
+import * as trashApi from '../api/trashApi';
 import * as widgetApi from '../api/widgetApi';
 
 // Core app state
@@ -17,8 +19,12 @@ let activeTagFilter = $state<number | null>(null);
 let filteredItems = $state<TodoItem[]>([]);
 let itemTagsMap = $state<Record<number, Tag[]>>({});
 
+// What undo and redo would act on next
+let undoState = $state<UndoState>({ undo_label: null, redo_label: null, discarded_label: null });
+
 async function finalizeMutation(): Promise<void> {
   await refreshWidgetCache();
+  await loadUndoState();
   syncStore.scheduleSync();
 }
 
@@ -98,6 +104,60 @@ async function refreshAll(): Promise<void> {
 
   await loadItems();
   await tagActions.loadAllTags();
+  // Sync and auto-reset may have cleared the journal
+  await loadUndoState();
+}
+
+async function loadUndoState(): Promise<void> {
+  try {
+    undoState = await journalApi.getUndoState();
+  } catch (error) {
+    console.error('Failed to load undo state:', error);
+  }
+}
+
+// Restored entries can bring back categories, items and tags at once
+async function restoreFromTrash(entry: TrashEntry): Promise<void> {
+  try {
+    if (entry.kind === 'item') {
+      await trashApi.restoreItem(entry.id);
+    } else if (entry.kind === 'category') {
+      await trashApi.restoreCategory(entry.id);
+    } else {
+      await trashApi.restoreTag(entry.id);
+    }
+    await refreshAll();
+    await finalizeMutation();
+  } catch (error) {
+    console.error('Failed to restore from trash:', error);
+  }
+}
+
+// Undo and redo rewrite whole rows, so everything is reloaded
+async function undo(): Promise<UndoState | null> {
+  try {
+    const state = await journalApi.undo();
+    await refreshAll();
+    await finalizeMutation();
+    undoState = state;
+    return state;
+  } catch (error) {
+    console.error('Failed to undo:', error);
+    return null;
+  }
+}
+
+async function redo(): Promise<UndoState | null> {
+  try {
+    const state = await journalApi.redo();
+    await refreshAll();
+    await finalizeMutation();
+    undoState = state;
+    return state;
+  } catch (error) {
+    console.error('Failed to redo:', error);
+    return null;
+  }
 }
 
 const itemActions = createItemActions({
@@ -128,17 +188,23 @@ export const appStore = {
   get activeTagFilter() { return activeTagFilter; },
   get filteredItems() { return filteredItems; },
   get itemTagsMap() { return itemTagsMap; },
+  get undoState() { return undoState; },
 
   // Data loading
   loadCategories: categoryActions.loadCategories,
   loadItems,
   refreshAll,
   processWidgetActions,
+  restoreFromTrash,
+  undo,
+  redo,
 
   // Category actions
   selectCategory: categoryActions.selectCategory,
   addCategory: categoryActions.addCategory,
+  instantiateTemplate: categoryActions.instantiateTemplate,
   editCategory: categoryActions.editCategory,
+  updateCategoryResetPolicy: categoryActions.updateResetPolicy,
   deleteCategory: categoryActions.deleteCategory,
   setCategories: categoryActions.setCategories,
   goToFirstCategory: categoryActions.goToFirstCategory,
@@ -146,14 +212,27 @@ export const appStore = {
   // Item actions
   addItem: itemActions.addItem,
   toggleItem: itemActions.toggleItem,
+  incrementProgress: itemActions.incrementProgress,
+  completeWithValue: itemActions.completeWithValue,
+  updateQuantityTarget: itemActions.updateQuantityTarget,
+  addSubtask: itemActions.addSubtask,
+  setItemParent: itemActions.setItemParent,
+  updateAutoComplete: itemActions.updateAutoComplete,
+  skipOccurrence: itemActions.skipOccurrence,
+  snoozeItem: itemActions.snoozeItem,
   toggleItemFromWidget: itemActions.toggleItemFromWidget,
   deleteItem: itemActions.deleteItem,
+  moveItems: itemActions.moveItems,
   editItem: itemActions.editItem,
   updateMemo: itemActions.updateMemo,
   updateRepeat: itemActions.updateRepeat,
   updateTrackStreak: itemActions.updateTrackStreak,
+  updateStreakGrace: itemActions.updateStreakGrace,
+  updateGoal: itemActions.updateGoal,
   updateLinkedApp: itemActions.updateLinkedApp,
   updateReminder: itemActions.updateReminder,
+  updateDue: itemActions.updateDue,
+  updateExclusionCalendar: itemActions.updateExclusionCalendar,
   resetAllItems: itemActions.resetAllItems,
   setItems: itemActions.setItems,
 
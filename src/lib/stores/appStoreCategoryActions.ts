@@ -1,5 +1,6 @@
-import type { Category, TodoItem } from '../../types';
+import type { Category, ResetPolicy, TodoItem } from '../../types';
 import * as categoryApi from '../api/categoryApi';
+import * as templateApi from '../api/templateApi';
 
 interface CategoryActionsContext {
   getCategories: () => Category[];
@@ -43,6 +44,22 @@ export function createCategoryActions(context: CategoryActionsContext) {
     }
   }
 
+  // Creates a template's items in an existing category, or in a new one when a name is given
+  async function instantiateTemplate(
+    templateId: number,
+    categoryId: number | null,
+    newCategoryName: string | null = null
+  ): Promise<void> {
+    try {
+      const targetId = await templateApi.instantiateTemplate(templateId, categoryId, newCategoryName);
+      await loadCategories();
+      await selectCategory(targetId);
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to instantiate template:', error);
+    }
+  }
+
   async function editCategory(id: number, name: string): Promise<void> {
     try {
       await categoryApi.editCategory(id, name);
@@ -57,6 +74,32 @@ export function createCategoryActions(context: CategoryActionsContext) {
     }
   }
 
+  async function updateResetPolicy(
+    id: number,
+    resetPolicy: ResetPolicy,
+    resetTimes: string[] = [],
+    resetWeekdays: number[] = []
+  ): Promise<void> {
+    try {
+      await categoryApi.updateCategoryResetPolicy(id, resetPolicy, resetTimes, resetWeekdays);
+      context.setCategories(
+        context.getCategories().map((category) =>
+          category.id === id
+            ? {
+                ...category,
+                reset_policy: resetPolicy,
+                reset_times: resetTimes.length > 0 ? JSON.stringify(resetTimes) : null,
+                reset_weekdays: resetWeekdays.length > 0 ? JSON.stringify(resetWeekdays) : null
+              }
+            : category
+        )
+      );
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to update reset policy:', error);
+    }
+  }
+
   async function deleteCategory(id: number): Promise<boolean> {
     const currentCategories = context.getCategories();
     if (currentCategories.length <= 1) {
@@ -98,7 +141,9 @@ export function createCategoryActions(context: CategoryActionsContext) {
     loadCategories,
     selectCategory,
     addCategory,
+    instantiateTemplate,
     editCategory,
+    updateResetPolicy,
     deleteCategory,
     setCategories,
     goToFirstCategory
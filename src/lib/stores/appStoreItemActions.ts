@@ -1,4 +1,5 @@
-import type { RepeatType, Tag, TodoItem } from '../../types';
+import type { GoalPeriod, RepeatType, Tag, TodoItem } from '../../types';
+import * as exclusionApi from '../api/exclusionApi';
 import * as streakApi from '../api/streakApi';
 import * as tagApi from '../api/tagApi';
 import * as todoApi from '../api/todoApi';
@@ -32,6 +33,14 @@ function patchItem(itemList: TodoItem[], id: number, patch: Partial<TodoItem>):
 }
 
 export function createItemActions(context: ItemActionsContext) {
+  // A finished sub-item may have completed its parent, so reload in that case
+  async function refreshIfParentCompleted(updatedItem: TodoItem): Promise<void> {
+    const parent = context.getItems().find((item) => item.id === updatedItem.parent_id);
+    if (updatedItem.done && parent?.auto_complete && !parent.done) {
+      await context.refreshAll();
+    }
+  }
+
   async function addItem(
     text: string,
     memo: string | null = null,
@@ -95,12 +104,136 @@ export function createItemActions(context: ItemActionsContext) {
         .getItems()
         .map((item) => (item.id === id ? updatedItem : item));
       context.setItems(sortItemsByDoneAndOrder(nextItems));
+      await refreshIfParentCompleted(updatedItem);
       await context.finalizeMutation();
     } catch (error) {
       console.error('Failed to toggle item:', error);
     }
   }
 
+  async function incrementProgress(id: number): Promise<void> {
+    try {
+      const updatedItem = await todoApi.incrementItemProgress(id);
+      if (!updatedItem) {
+        return;
+      }
+
+      const nextItems = context
+        .getItems()
+        .map((item) => (item.id === id ? updatedItem : item));
+      context.setItems(sortItemsByDoneAndOrder(nextItems));
+      await refreshIfParentCompleted(updatedItem);
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to increment progress:', error);
+    }
+  }
+
+  async function completeWithValue(id: number, value: number, unit: string | null): Promise<void> {
+    try {
+      const updatedItem = await todoApi.completeItemWithValue(id, value, unit);
+      if (!updatedItem) {
+        return;
+      }
+
+      const nextItems = context
+        .getItems()
+        .map((item) => (item.id === id ? updatedItem : item));
+      context.setItems(sortItemsByDoneAndOrder(nextItems));
+      await refreshIfParentCompleted(updatedItem);
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to complete item with value:', error);
+    }
+  }
+
+  async function updateQuantityTarget(id: number, quantityTarget: number): Promise<void> {
+    try {
+      await todoApi.updateItemQuantityTarget(id, quantityTarget);
+      const item = context.getItems().find((currentItem) => currentItem.id === id);
+      context.setItems(
+        patchItem(context.getItems(), id, {
+          quantity_target: quantityTarget,
+          quantity_progress: Math.min(item?.quantity_progress ?? 0, quantityTarget)
+        })
+      );
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to update quantity target:', error);
+    }
+  }
+
+  async function addSubtask(parentId: number, text: string): Promise<void> {
+    try {
+      const newItem = await todoApi.createSubtask(parentId, text);
+      context.setItems([...context.getItems(), newItem]);
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to add subtask:', error);
+    }
+  }
+
+  async function setItemParent(id: number, parentId: number | null): Promise<void> {
+    try {
+      await todoApi.setItemParent(id, parentId);
+      // Sub-items move to their parent's category
+      const parent = context.getItems().find((item) => item.id === parentId);
+      context.setItems(
+        patchItem(context.getItems(), id, {
+          parent_id: parentId,
+          ...(parent ? { category_id: parent.category_id } : {})
+        })
+      );
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to set item parent:', error);
+    }
+  }
+
+  async function updateAutoComplete(id: number, autoComplete: boolean): Promise<void> {
+    try {
+      await todoApi.updateItemAutoComplete(id, autoComplete);
+      context.setItems(patchItem(context.getItems(), id, { auto_complete: autoComplete }));
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to update auto-complete:', error);
+    }
+  }
+
+  async function skipOccurrence(id: number): Promise<void> {
+    try {
+      const updatedItem = await todoApi.skipItemOccurrence(id);
+      if (!updatedItem) {
+        return;
+      }
+
+      const nextItems = context
+        .getItems()
+        .map((item) => (item.id === id ? updatedItem : item));
+      context.setItems(sortItemsByDoneAndOrder(nextItems));
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to skip occurrence:', error);
+    }
+  }
+
+  async function snoozeItem(id: number, until: string): Promise<void> {
+    try {
+      const updatedItem = await todoApi.snoozeItem(id, until);
+      if (!updatedItem) {
+        return;
+      }
+
+      const nextItems = context
+        .getItems()
+        .map((item) => (item.id === id ? updatedItem : item));
+      context.setItems(sortItemsByDoneAndOrder(nextItems));
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to snooze item:', error);
+    }
+  }
+
   async function toggleItemFromWidget(id: number): Promise<void> {
     try {
       await widgetApi.toggleItemFromWidget(id);
@@ -114,13 +247,27 @@ export function createItemActions(context: ItemActionsContext) {
   async function deleteItem(id: number): Promise<void> {
     try {
       await todoApi.deleteItem(id);
-      context.setItems(context.getItems().filter((item) => item.id !== id));
+      // Sub-items are deleted with their parent
+      context.setItems(
+        context.getItems().filter((item) => item.id !== id && item.parent_id !== id)
+      );
       await context.finalizeMutation();
     } catch (error) {
       console.error('Failed to delete item:', error);
     }
   }
 
+  // Sub-items go with their parent, so the list is reloaded rather than patched
+  async function moveItems(itemIds: number[], categoryId: number | null): Promise<void> {
+    try {
+      await todoApi.moveItems(itemIds, categoryId);
+      await context.refreshAll();
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to move items:', error);
+    }
+  }
+
   async function editItem(id: number, text: string): Promise<void> {
     try {
       await todoApi.editItem(id, text);
@@ -147,11 +294,26 @@ export function createItemActions(context: ItemActionsContext) {
     repeatDetail: string | null
   ): Promise<void> {
     try {
-      await todoApi.updateItemRepeat(id, repeatType, repeatDetail);
+      // Keep an existing "every N" interval unless the repeat type changes
+      const current = context.getItems().find((item) => item.id === id);
+      const repeatInterval =
+        current && current.repeat_type === repeatType ? current.repeat_interval : 1;
+      const repeatUntil = current?.repeat_until ?? null;
+      const repeatCount = current?.repeat_count ?? null;
+      await todoApi.updateItemRepeat(
+        id,
+        repeatType,
+        repeatDetail,
+        repeatInterval,
+        repeatUntil,
+        repeatCount
+      );
       context.setItems(
         patchItem(context.getItems(), id, {
           repeat_type: repeatType,
-          repeat_detail: repeatDetail
+          repeat_detail: repeatDetail,
+          repeat_interval: repeatInterval,
+          repeat_completed: 0
         })
       );
       await context.finalizeMutation();
@@ -170,6 +332,40 @@ export function createItemActions(context: ItemActionsContext) {
     }
   }
 
+  async function updateStreakGrace(
+    id: number,
+    freezesPerMonth: number,
+    gracePerWeek: number
+  ): Promise<void> {
+    try {
+      await streakApi.updateStreakGrace(id, freezesPerMonth, gracePerWeek);
+      context.setItems(
+        patchItem(context.getItems(), id, {
+          streak_freezes_per_month: freezesPerMonth,
+          streak_grace_per_week: gracePerWeek
+        })
+      );
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to update streak grace:', error);
+    }
+  }
+
+  async function updateGoal(id: number, goalPeriod: GoalPeriod, goalTarget: number): Promise<void> {
+    try {
+      await streakApi.updateGoal(id, goalPeriod, goalTarget);
+      context.setItems(
+        patchItem(context.getItems(), id, {
+          goal_period: goalPeriod,
+          goal_target: goalPeriod === 'none' ? 0 : goalTarget
+        })
+      );
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to update goal:', error);
+    }
+  }
+
   async function updateLinkedApp(id: number, linkedApp: string | null): Promise<void> {
     try {
       await todoApi.updateItemLinkedApp(id, linkedApp);
@@ -190,10 +386,42 @@ export function createItemActions(context: ItemActionsContext) {
     }
   }
 
+  async function updateDue(
+    id: number,
+    dueAt: string | null,
+    dueTimes: string[] | null
+  ): Promise<void> {
+    try {
+      const updated = await todoApi.updateItemDue(id, dueAt, dueTimes);
+      if (!updated) return;
+      context.setItems(
+        patchItem(context.getItems(), id, {
+          due_at: updated.due_at,
+          due_times: updated.due_times
+        })
+      );
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to update due time:', error);
+    }
+  }
+
+  async function updateExclusionCalendar(id: number, calendarId: number | null): Promise<void> {
+    try {
+      await exclusionApi.updateItemExclusionCalendar(id, calendarId);
+      // next_due_at may have moved off an excluded day
+      await context.refreshAll();
+      await context.finalizeMutation();
+    } catch (error) {
+      console.error('Failed to update exclusion calendar:', error);
+    }
+  }
+
   async function resetAllItems(): Promise<void> {
     try {
       await todoApi.resetAllItems(context.getSelectedCategoryId());
-      context.setItems(context.getItems().map((item) => ({ ...item, done: false })));
+      // The backend decides which items a reset brings back
+      await context.refreshAll();
       await context.finalizeMutation();
     } catch (error) {
       console.error('Failed to reset items:', error);
@@ -207,14 +435,27 @@ export function createItemActions(context: ItemActionsContext) {
   return {
     addItem,
     toggleItem,
+    incrementProgress,
+    completeWithValue,
+    updateQuantityTarget,
+    addSubtask,
+    setItemParent,
+    updateAutoComplete,
+    skipOccurrence,
+    snoozeItem,
     toggleItemFromWidget,
     deleteItem,
+    moveItems,
     editItem,
     updateMemo,
     updateRepeat,
     updateTrackStreak,
+    updateStreakGrace,
+    updateGoal,
     updateLinkedApp,
     updateReminder,
+    updateDue,
+    updateExclusionCalendar,
     resetAllItems,
     setItems
   };
@@ -1,9 +1,15 @@
 export type SyncStatus = 'pending' | 'synced' | 'deleted';
 
+export type ResetPolicy = 'global' | 'daily' | 'weekdays' | 'never';
+
 export interface Category {
   id: number;
   name: string;
   display_order: number;
+  reset_policy?: ResetPolicy;
+  reset_times?: string | null;  // JSON array of "HH:MM"
+  reset_weekdays?: string | null;  // JSON array of weekdays, 0=Sunday
+  last_reset_at?: string | null;
   // Sync fields (optional for dnd library compatibility)
   sync_id?: string | null;
   created_at?: string | null;
@@ -11,7 +17,9 @@ export interface Category {
   sync_status?: SyncStatus;
 }
 
-export type RepeatType = 'none' | 'daily' | 'weekly' | 'monthly';
+export type GoalPeriod = 'none' | 'week' | 'month';
+
+export type RepeatType = 'none' | 'daily' | 'weekly' | 'monthly' | 'yearly' | 'rule' | 'after_completion';
 
 export interface TodoItem {
   id: number;
@@ -22,7 +30,32 @@ export interface TodoItem {
   memo: string | null;
   repeat_type: RepeatType;
   repeat_detail: string | null;
+  repeat_interval: number;
+  repeat_start: string | null;
+  repeat_until: string | null;
+  repeat_count: number | null;
+  repeat_completed: number;
+  // The series ran out of occurrences; the item stays done
+  repeat_ended: boolean;
+  exclusion_calendar_id: number | null;
   next_due_at: string | null;
+  // "YYYY-MM-DDTHH:MM"; for repeating items the current or upcoming slot
+  due_at: string | null;
+  // JSON array of "HH:MM" slots per scheduled day (repeating items only)
+  due_times: string | null;
+  // Missed slots per month a freeze may cover / misses tolerated per week
+  streak_freezes_per_month: number;
+  streak_grace_per_week: number;
+  // Frequency goal: completions per week (from the week_start setting) or month
+  goal_period: GoalPeriod;
+  goal_target: number;
+  // Completions needed before the item counts as done (0 = plain item)
+  quantity_target: number;
+  quantity_progress: number;
+  // Item this one is a sub-item of
+  parent_id: number | null;
+  // Completes itself once all its sub-items are done
+  auto_complete: boolean;
   last_completed_at: string | null;
   track_streak: boolean;
   reminder_at: string | null;
@@ -34,6 +67,18 @@ export interface TodoItem {
   sync_status?: SyncStatus;
 }
 
+// Repeat settings an item followed from effective_from until the next version
+export interface ScheduleVersion {
+  item_id: number;
+  effective_from: string;
+  repeat_type: RepeatType;
+  repeat_detail: string | null;
+  repeat_interval: number;
+  repeat_start: string | null;
+  repeat_until: string | null;
+  repeat_count: number | null;
+}
+
 export interface WidgetTodoItem {
   id: number;
   text: string;
@@ -42,6 +87,8 @@ export interface WidgetTodoItem {
   category_name: string | null;
   display_order: number;
   reminder_at: string | null;
+  quantity_target: number;
+  quantity_progress: number;
   updated_at: string | null;
 }
 
@@ -60,6 +107,8 @@ export interface WidgetCategoryPendingItem {
   text: string;
   display_order: number;
   tags: string[];
+  quantity_target: number;
+  quantity_progress: number;
 }
 
 export interface WidgetTheme {
@@ -90,6 +139,57 @@ export interface Tag {
   sync_status?: SyncStatus;
 }
 
+export type TrashKind = 'item' | 'category' | 'tag';
+
+// Deleted item, category or tag, restorable until purge_at
+export interface TrashEntry {
+  kind: TrashKind;
+  id: number;
+  // Item text, or category or tag name
+  name: string;
+  category_id: number | null;
+  deleted_at: string;
+  purge_at: string;
+}
+
+// Labels of the operations undo and redo would act on next
+export interface UndoState {
+  undo_label: string | null;
+  redo_label: string | null;
+  // Operation dropped instead of undone or redone, because its rows
+  // changed since it was recorded
+  discarded_label: string | null;
+}
+
+// Saved snapshot of a category's items
+export interface ChecklistTemplate {
+  id: number;
+  name: string;
+  items: TemplateItem[];
+  sync_id?: string | null;
+  created_at?: string | null;
+  updated_at?: string | null;
+  sync_status?: SyncStatus;
+}
+
+export interface TemplateItem {
+  text: string;
+  memo: string | null;
+  repeat_type: RepeatType;
+  repeat_detail: string | null;
+  repeat_interval: number;
+  repeat_start: string | null;
+  repeat_until: string | null;
+  repeat_count: number | null;
+  // JSON array of "HH:MM" slots, as on the item
+  due_times: string | null;
+  reminder_at: string | null;
+  // Tag names, created on instantiation when missing
+  tags: string[];
+  // Position of the parent item in the template, for sub-items
+  parent_position: number | null;
+}
+
 export interface ThemeColors {
   paper: string;
   canvas: string;
@@ -130,13 +230,74 @@ export interface CompletionLog {
   item_id: number;
   completed_on: string;
   completed_count: number;
+  // Local time of the day's latest completion, if known
+  completed_at: string | null;
+}
+
+export interface MeasurementPoint {
+  date: string;
+  value: number;
+  unit: string | null;
+}
+
+// Stats are null while an item has no recorded values
+export interface MeasurementSeries {
+  item_id: number;
+  unit: string | null;
+  points: MeasurementPoint[];
+  min: number | null;
+  max: number | null;
+  avg: number | null;
+  latest: number | null;
+  // Least-squares slope in units per day
+  trend_per_day: number | null;
+}
+
+// Completion analytics
+export interface WeekdayStats {
+  // 0 = Sunday
+  weekday: number;
+  completed: number;
+  missed: number;
+  rate: number | null;
+}
+
+export interface CompletionHourStats {
+  // Completions per hour of day, index 0 to 23
+  hour_counts: number[];
+  total: number;
+  typical_hour: number | null;
+}
+
+export interface CategoryTrend {
+  category_id: number | null;
+  category_name: string | null;
+  last_30_days: number;
+  previous_30_days: number;
+  // Relative change, 0.25 = 25% more
+  change_30_days: number | null;
+  last_90_days: number;
+  previous_90_days: number;
+  change_90_days: number | null;
+}
+
+export interface MissedItem {
+  item_id: number;
+  text: string;
+  category_id: number | null;
+  missed: number;
+  completed: number;
+  miss_rate: number;
 }
 
 export interface HeatmapIntensity {
   completed_on: string;
+  // 1-10 combo level, or FROZEN_LEVEL for a day a freeze covered
   level: number;
 }
 
+export const FROZEN_LEVEL = -1;
+
 export interface HeatmapData {
   item_id: number;
   item_text: string;
@@ -147,6 +308,50 @@ export interface HeatmapData {
   longest_streak: number;
   current_streak_dates: string[];
   longest_streak_dates: string[];
+  skipped_dates: string[];
+  frozen_dates: string[];
+  // With a goal, streaks count consecutive periods that met it
+  goal_period: GoalPeriod;
+  goal_target: number;
+  goal_progress: GoalPeriodProgress[];
+}
+
+export interface GoalPeriodProgress {
+  period_start: string;
+  period_end: string;
+  completed: number;
+  target: number;
+  met: boolean;
+}
+
+// Completed share of the scheduled slots on one day, across several items
+export interface HeatmapDayRatio {
+  date: string;
+  completed: number;
+  scheduled: number;
+  ratio: number;
+}
+
+// Heatmap over a category, a tag or every tracked item; streaks count
+// perfect days (every scheduled slot completed)
+export interface AggregateHeatmapData {
+  item_ids: number[];
+  days: HeatmapDayRatio[];
+  current_streak: number;
+  longest_streak: number;
+  current_streak_dates: string[];
+  longest_streak_dates: string[];
+}
+
+export type OccurrenceStatus = 'completed' | 'missed' | 'skipped';
+
+export interface Occurrence {
+  id: number;
+  item_id: number;
+  scheduled_on: string;
+  status: OccurrenceStatus;
+  completed_at: string | null;
+  source: 'app' | 'widget' | null;
 }
 
 export interface TrackedItem {
@@ -188,6 +393,16 @@ export interface SyncStatusInfo {
   last_synced_at: string | null;
 }
 
+// Sent by the backend scheduler when it changed data or a reminder came due
+export interface SchedulerEvent {
+  reset: boolean;
+  reactivated: number;
+  due_item_ids: number[];
+}
+
+// Must match SCHEDULER_EVENT in src-tauri/src/service/scheduler_service.rs
+export const SCHEDULER_EVENT = 'scheduler-tick';
+
 // Realtime types
 export type RealtimeConnectionState =
   | 'disconnected'
@@ -215,3 +430,23 @@ export interface DataChangedEvent {
   change_type: DataChangeType;
   sync_id: string | null;
 }
+
+export interface TimeZoneStatus {
+  time_zone: string;
+  device_time_zone: string;
+  device_changed: boolean;
+}
+
+export interface ExclusionCalendar {
+  id: number;
+  name: string;
+  created_at: string | null;
+}
+
+export interface ExclusionRange {
+  id: number;
+  calendar_id: number;
+  start_date: string;  // YYYY-MM-DD
+  end_date: string;  // YYYY-MM-DD, inclusive
+  label: string | null;
+}